@@ -18,3 +18,17 @@ cargo clippy --no-default-features --features default,ssr -- --deny=warnings
 cargo clippy --no-default-features --features csr,default,ssr -- --deny=warnings
 cargo clippy --no-default-features --features hydration,ssr -- --deny=warnings
 cargo clippy --no-default-features --features default,hydration,ssr -- --deny=warnings
+cargo clippy --no-default-features --features test-util -- --deny=warnings
+cargo clippy --no-default-features --features csr,test-util -- --deny=warnings
+cargo clippy --no-default-features --features hydration,test-util -- --deny=warnings
+cargo clippy --no-default-features --features debug-tooling -- --deny=warnings
+cargo clippy --no-default-features --features debug-tooling,ssr -- --deny=warnings
+cargo clippy --no-default-features --features debug-tooling,hydration -- --deny=warnings
+cargo clippy --no-default-features --features a11y-lints -- --deny=warnings
+cargo clippy --no-default-features --features a11y-lints,csr -- --deny=warnings
+cargo clippy --no-default-features --features a11y-lints,hydration -- --deny=warnings
+cargo clippy --no-default-features --features tracing -- --deny=warnings
+cargo clippy --no-default-features --features csr,tracing -- --deny=warnings
+cargo clippy --no-default-features --features ssr,tracing -- --deny=warnings
+cargo clippy --no-default-features --features hydration,ssr,tracing -- --deny=warnings
+cargo clippy --no-default-features --features a11y-lints,debug-tooling,hydration,test-util,tracing -- --deny=warnings
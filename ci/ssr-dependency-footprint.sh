@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+set -xe
+
+# Asserts that an SSR-only build for a native target does not link crates that are only useful
+# in a browser.
+#
+# `web-sys` and `wasm-bindgen` are still part of the tree as their types are part of Yew's public
+# API (e.g.: `VNode::VRef`, events), they only compile to stubs on non-wasm targets.
+
+# You need to run this script in packages/yew
+
+HOST_TARGET=$(rustc -vV | sed -n 's/^host: //p')
+
+DEPS=$(cargo tree --no-default-features --features ssr \
+    --edges normal --target "$HOST_TARGET" --prefix none --format '{p}')
+
+for dep in gloo console_error_panic_hook wasm-bindgen-futures; do
+    if echo "$DEPS" | grep -q "^$dep v"; then
+        echo "$dep should not be a dependency of an ssr-only build."
+        exit 1
+    fi
+done
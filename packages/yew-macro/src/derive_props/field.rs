@@ -7,7 +7,7 @@ use syn::parse::Result;
 use syn::spanned::Spanned;
 use syn::{
     parse_quote, Attribute, Error, Expr, Field, GenericParam, Generics, Path, Type, TypePath,
-    Visibility,
+    Visibility, WherePredicate,
 };
 
 use super::should_preserve_attr;
@@ -28,6 +28,7 @@ pub struct PropField {
     ty: Type,
     name: Ident,
     attr: PropAttr,
+    skip_eq: bool,
     extra_attrs: Vec<Attribute>,
 }
 
@@ -37,6 +38,25 @@ impl PropField {
         matches!(self.attr, PropAttr::Required { .. })
     }
 
+    /// Fields marked with `#[prop(skip_eq)]` are not compared by the generated `PartialEq`
+    pub fn is_skip_eq(&self) -> bool {
+        self.skip_eq
+    }
+
+    /// Used to compare the field in the generated `PartialEq` implementation
+    pub fn to_eq_comparison(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        quote! {
+            ::std::cmp::PartialEq::eq(&self.#name, &other.#name)
+        }
+    }
+
+    /// Bound on the type of the field for the generated `PartialEq` implementation
+    pub fn to_eq_bound(&self) -> WherePredicate {
+        let ty = &self.ty;
+        parse_quote! { #ty: ::std::cmp::PartialEq }
+    }
+
     /// This check name is descriptive to help a developer realize they missed a required prop
     fn to_check_name(&self, props_name: &Ident) -> Ident {
         format_ident!("Has{}{}", props_name, self.name, span = Span::mixed_site())
@@ -198,6 +218,25 @@ impl PropField {
         }
     }
 
+    // Detect `#[prop(skip_eq)]`
+    fn skip_eq(named_field: &Field) -> Result<bool> {
+        let mut skip_eq = false;
+
+        for attr in named_field.attrs.iter().filter(|m| m.path.is_ident("prop")) {
+            let option: Ident = attr.parse_args()?;
+            if option == "skip_eq" {
+                skip_eq = true;
+            } else {
+                return Err(Error::new_spanned(
+                    option,
+                    "unknown prop option (hint: the supported option is `skip_eq`)",
+                ));
+            }
+        }
+
+        Ok(skip_eq)
+    }
+
     // Detect Properties 2.0 attributes
     fn attribute(named_field: &Field) -> Result<PropAttr> {
         let attr = named_field.attrs.iter().find(|attr| {
@@ -327,6 +366,15 @@ impl TryFrom<Field> for PropField {
     type Error = Error;
 
     fn try_from(field: Field) -> Result<Self> {
+        // `ssr_fallback` is an attribute of components in the `html!` macro.
+        if let Some(name) = field.ident.as_ref().filter(|m| **m == "ssr_fallback") {
+            return Err(Error::new_spanned(
+                name,
+                "`ssr_fallback` is reserved for the content rendered instead of the component on \
+                 the server side (hint: rename the prop)",
+            ));
+        }
+
         let extra_attrs = field
             .attrs
             .iter()
@@ -336,6 +384,7 @@ impl TryFrom<Field> for PropField {
 
         Ok(PropField {
             attr: Self::attribute(&field)?,
+            skip_eq: Self::skip_eq(&field)?,
             extra_attrs,
             ty: field.ty,
             name: field.ident.unwrap(),
@@ -120,5 +120,28 @@ impl ToTokens for DerivePropsInput {
             }
         };
         tokens.extend(properties);
+
+        // Fields marked with `#[prop(skip_eq)]` are excluded from a generated `PartialEq`
+        if self.prop_fields.iter().any(PropField::is_skip_eq) {
+            let compared_fields = self.prop_fields.iter().filter(|m| !m.is_skip_eq());
+            let comparisons = compared_fields.clone().map(PropField::to_eq_comparison);
+
+            let mut generics = generics.clone();
+            if !generics.params.is_empty() {
+                let where_clause = generics.make_where_clause();
+                where_clause
+                    .predicates
+                    .extend(compared_fields.map(PropField::to_eq_bound));
+            }
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            tokens.extend(quote! {
+                impl #impl_generics ::std::cmp::PartialEq for #props_name #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        true #(&& #comparisons)*
+                    }
+                }
+            });
+        }
     }
 }
@@ -2,14 +2,18 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::{Comma, Fn};
+use syn::token::{Async, Comma, Fn};
 use syn::{
-    parse_quote, parse_quote_spanned, visit_mut, Attribute, Block, FnArg, Generics, Ident, Item,
-    ItemFn, LitStr, ReturnType, Type, Visibility,
+    parse_quote, parse_quote_spanned, visit_mut, Attribute, Block, Expr, FnArg, Generics, Ident,
+    Item, ItemFn, LitStr, ReturnType, Token, Type, Visibility,
 };
 
 use crate::hook::BodyRewriter;
 
+mod kw {
+    syn::custom_keyword!(fallback);
+}
+
 #[derive(Clone)]
 pub struct FunctionComponent {
     block: Box<Block>,
@@ -21,8 +25,10 @@ pub struct FunctionComponent {
     name: Ident,
     return_type: Box<Type>,
     fn_token: Fn,
+    asyncness: Option<Async>,
 
     component_name: Option<Ident>,
+    fallback: Option<Expr>,
 }
 
 impl Parse for FunctionComponent {
@@ -54,13 +60,6 @@ impl Parse for FunctionComponent {
             ));
         }
 
-        if sig.asyncness.is_some() {
-            return Err(syn::Error::new_spanned(
-                sig.asyncness,
-                "function components can't be async",
-            ));
-        }
-
         if sig.constness.is_some() {
             return Err(syn::Error::new_spanned(
                 sig.constness,
@@ -147,7 +146,9 @@ impl Parse for FunctionComponent {
             name: sig.ident,
             return_type,
             fn_token: sig.fn_token,
+            asyncness: sig.asyncness,
             component_name: None,
+            fallback: None,
         })
     }
 }
@@ -200,7 +201,17 @@ impl FunctionComponent {
             }
         }
 
+        if let Some((ref kw, _)) = name.fallback {
+            if self.asyncness.is_none() {
+                return Err(syn::Error::new_spanned(
+                    kw,
+                    "a fallback can only be specified for async function components",
+                ));
+            }
+        }
+
         self.component_name = name.component_name;
+        self.fallback = name.fallback.map(|(_, m)| m);
 
         Ok(())
     }
@@ -270,6 +281,31 @@ impl FunctionComponent {
         }
     }
 
+    /// Prints the impl fn of an async function component.
+    ///
+    /// Hooks can't be used across await points, so the body is not rewritten.
+    fn print_async_inner_fn(&self) -> TokenStream {
+        let name = self.inner_fn_ident();
+        let FunctionComponent {
+            ref fn_token,
+            ref asyncness,
+            ref attrs,
+            ref block,
+            ref return_type,
+            ref generics,
+            ref arg,
+            ..
+        } = self;
+        let (impl_generics, _ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #(#attrs)*
+            #asyncness #fn_token #name #impl_generics (#arg) -> #return_type
+            #where_clause
+            #block
+        }
+    }
+
     fn print_base_component_impl(&self) -> TokenStream {
         let component_name = self.component_name();
         let props_type = &self.props_type;
@@ -372,6 +408,57 @@ impl FunctionComponent {
         }
     }
 
+    /// Prints the providers of an async function component.
+    ///
+    /// The component renders a suspense with the fallback, whose child awaits the body of the
+    /// function.
+    fn print_async_fn_provider_impl(&self) -> TokenStream {
+        let func = self.print_async_inner_fn();
+        let component_impl_attrs = self.filter_attrs_for_component_impl();
+        let component_name = self.component_name();
+        let fn_name = self.inner_fn_ident();
+        let (_impl_generics, ty_generics, _where_clause) = self.generics.split_for_impl();
+        let static_comp_generics = self.create_static_component_generics();
+        let (impl_generics, _ty_generics, where_clause) = static_comp_generics.split_for_impl();
+        let props_type = &self.props_type;
+        let fn_generics = ty_generics.as_turbofish();
+        let fallback = match self.fallback {
+            Some(ref m) => m.to_token_stream(),
+            None => quote! { ::std::default::Default::default() },
+        };
+
+        let component_props = Ident::new("props", Span::mixed_site());
+        let ctx_ident = Ident::new("_ctx", Span::mixed_site());
+
+        quote! {
+            #(#component_impl_attrs)*
+            impl #impl_generics ::yew::functional::FunctionProvider for #component_name #ty_generics #where_clause {
+                type Properties = #props_type;
+
+                fn run(#ctx_ident: &mut ::yew::functional::HookContext, #component_props: &Self::Properties) -> ::yew::html::HtmlResult {
+                    ::std::result::Result::Ok(::yew::functional::render_async_component::<Self>(#component_props))
+                }
+            }
+
+            #(#component_impl_attrs)*
+            impl #impl_generics ::yew::functional::AsyncFunctionProvider for #component_name #ty_generics #where_clause {
+                type Properties = #props_type;
+
+                fn fallback() -> ::yew::html::Html {
+                    #fallback
+                }
+
+                fn run(#component_props: ::std::rc::Rc<Self::Properties>) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = ::yew::html::Html>>> {
+                    #func
+
+                    ::std::boxed::Box::pin(async move {
+                        ::std::convert::Into::<::yew::html::Html>::into(#fn_name #fn_generics (&*#component_props).await)
+                    })
+                }
+            }
+        }
+    }
+
     fn print_struct_def(&self) -> TokenStream {
         let component_attrs = self.filter_attrs_for_component_struct();
         let component_name = self.component_name();
@@ -394,6 +481,7 @@ impl FunctionComponent {
 
 pub struct FunctionComponentName {
     component_name: Option<Ident>,
+    fallback: Option<(kw::fallback, Expr)>,
 }
 
 impl Parse for FunctionComponentName {
@@ -401,13 +489,33 @@ impl Parse for FunctionComponentName {
         if input.is_empty() {
             return Ok(Self {
                 component_name: None,
+                fallback: None,
             });
         }
 
-        let component_name = input.parse()?;
+        // `fallback` is only a keyword if it is followed by `=`, so it remains a valid name.
+        let component_name = if input.peek(kw::fallback) && input.peek2(Token![=]) {
+            None
+        } else {
+            let component_name = input.parse()?;
+            if !(input.peek(Token![,]) && input.peek2(kw::fallback)) {
+                return Ok(Self {
+                    component_name: Some(component_name),
+                    fallback: None,
+                });
+            }
+            input.parse::<Token![,]>()?;
+
+            Some(component_name)
+        };
+
+        let kw = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let fallback = input.parse()?;
 
         Ok(Self {
-            component_name: Some(component_name),
+            component_name,
+            fallback: Some((kw, fallback)),
         })
     }
 }
@@ -420,7 +528,11 @@ pub fn function_component_impl(
 
     let base_comp_impl = component.print_base_component_impl();
     let debug_impl = component.print_debug_impl();
-    let provider_fn_impl = component.print_fn_provider_impl();
+    let provider_fn_impl = if component.asyncness.is_some() {
+        component.print_async_fn_provider_impl()
+    } else {
+        component.print_fn_provider_impl()
+    };
     let struct_def = component.print_struct_def();
 
     let quoted = quote! {
@@ -5,9 +5,32 @@ use syn::spanned::Spanned;
 use syn::visit_mut::VisitMut;
 use syn::{
     parse_quote_spanned, visit_mut, Expr, ExprCall, ExprClosure, ExprForLoop, ExprIf, ExprLoop,
-    ExprMatch, ExprWhile, Ident, Item,
+    ExprMatch, ExprWhile, Ident, Item, Local, Path,
 };
 
+/// Returns the hook that transfers the state of the hook at `path`, if `path` unambiguously refers
+/// to `use_state` or `use_reducer`.
+///
+/// Only the names of the hooks and their paths in `yew` are accepted, as aliases and other hooks
+/// with the same name cannot be told apart from them.
+fn transferred_hook(path: &Path) -> Option<&'static str> {
+    let mut segments = path.segments.iter().map(|m| m.ident.to_string());
+    let name = segments.next_back()?;
+    let prefix = segments.collect::<Vec<_>>();
+
+    let in_yew = match prefix.iter().map(|m| m.as_str()).collect::<Vec<_>>()[..] {
+        [] => path.leading_colon.is_none(),
+        ["yew"] | ["yew", "functional"] | ["yew", "prelude"] => true,
+        _ => false,
+    };
+
+    match name.as_str() {
+        "use_state" if in_yew => Some("use_transferred_state"),
+        "use_reducer" if in_yew => Some("use_transferred_reducer"),
+        _ => None,
+    }
+}
+
 #[derive(Debug)]
 pub struct BodyRewriter {
     branch_lock: Arc<Mutex<()>>,
@@ -89,6 +112,39 @@ impl VisitMut for BodyRewriter {
         }
     }
 
+    fn visit_local_mut(&mut self, i: &mut Local) {
+        // `#[transfer] let state = use_state(..);` is rewritten to the hook that transfers the
+        // state from server-side rendering to hydration.
+        if let Some(pos) = i.attrs.iter().position(|m| m.path.is_ident("transfer")) {
+            let attr = i.attrs.remove(pos);
+
+            let path = i.init.as_mut().and_then(|(_, m)| match &mut **m {
+                Expr::Call(ExprCall { func, .. }) => match &mut **func {
+                    Expr::Path(m) if m.qself.is_none() => Some(&mut m.path),
+                    _ => None,
+                },
+                _ => None,
+            });
+
+            match path.and_then(|m| transferred_hook(m).map(|name| (m, name))) {
+                Some((path, name)) => {
+                    // The prefix is kept, so the transferring hook is resolved like the hook the
+                    // user wrote, e.g.: a local `use_state` fails to find `use_transferred_state`
+                    // instead of being replaced by the one in `yew`.
+                    let segment = path.segments.last_mut().expect("a hook name");
+                    segment.ident = Ident::new(name, segment.ident.span());
+                }
+                None => emit_error!(
+                    attr,
+                    "`#[transfer]` can only be applied to the result of `use_state` or \
+                     `use_reducer`, called by their name or their path in `yew`."
+                ),
+            }
+        }
+
+        visit_mut::visit_local_mut(self, i);
+    }
+
     fn visit_expr_closure_mut(&mut self, i: &mut ExprClosure) {
         self.with_branch(move |m| visit_mut::visit_expr_closure_mut(m, i))
     }
@@ -142,11 +142,41 @@ impl ToTokens for HtmlComponent {
             })
             .unwrap_or_default();
 
+        // the component is wrapped in a `SsrFallback` that renders the fallback on the server side.
+        let vchild = match props.ssr_fallback() {
+            Some(fallback) => {
+                let value = &fallback.value;
+                let fallback = quote_spanned! {value.span().resolved_at(Span::call_site())=>
+                    ::std::convert::Into::<::yew::virtual_dom::VNode>::into(#value)
+                };
+
+                quote_spanned! {ty_span=>
+                    ::yew::virtual_dom::VChild::<::yew::html::SsrFallback>::new(
+                        ::yew::html::SsrFallbackProps {
+                            fallback: #fallback,
+                            children: ::yew::html::ChildrenRenderer::new(::std::vec![
+                                ::std::convert::Into::<::yew::virtual_dom::VNode>::into(
+                                    ::yew::virtual_dom::VChild::<#ty>::new(
+                                        __yew_props,
+                                        ::std::option::Option::None,
+                                    ),
+                                ),
+                            ]),
+                        },
+                        #key,
+                    )
+                }
+            }
+            None => quote_spanned! {ty_span=>
+                ::yew::virtual_dom::VChild::<#ty>::new(__yew_props, #key)
+            },
+        };
+
         tokens.extend(quote_spanned! {ty_span=>
             {
                 #use_close_tag
                 let __yew_props = #build_props;
-                ::yew::virtual_dom::VChild::<#ty>::new(__yew_props, #key)
+                #vchild
             }
         });
     }
@@ -309,6 +309,7 @@ impl ToTokens for HtmlElement {
 
         // TODO: if none of the children have possibly None expressions or literals as keys, we can
         // compute `VList.fully_keyed` at compile time.
+        let children = children.to_build_vnode_vec_token_stream();
         let child_list = quote! {
             ::yew::virtual_dom::VList::with_children(
                 #children,
@@ -319,7 +320,12 @@ impl ToTokens for HtmlElement {
         tokens.extend(match &name {
             TagName::Lit(dashedname) => {
                 let name_span = dashedname.span();
-                let name = dashedname.to_ascii_lowercase_string();
+                let name = dashedname.to_string();
+                let name = if is_case_sensitive_element_name(&name) {
+                    name
+                } else {
+                    dashedname.to_ascii_lowercase_string()
+                };
                 if name != dashedname.to_string() {
                     emit_warning!(
                         dashedname.span(),
@@ -417,9 +423,9 @@ impl ToTokens for HtmlElement {
                     // e.g. html!{<@{"div"}/>} will set `#expr` to `{"div"}`
                     // (note the extra braces). Hence the need for the `allow`.
                     // Anyways to remove the braces?
-                    let mut #vtag_name = ::std::convert::Into::<
-                        ::std::borrow::Cow::<'static, ::std::primitive::str>
-                    >::into(#expr);
+                    let mut #vtag_name = ::yew::virtual_dom::VTag::__dyn_tag_name(
+                        ::std::convert::Into::<::yew::virtual_dom::AttrValue>::into(#expr)
+                    );
                     ::std::debug_assert!(
                         #vtag_name.is_ascii(),
                         "a dynamic tag returned a tag name containing non ASCII characters: `{}`",
@@ -429,8 +435,9 @@ impl ToTokens for HtmlElement {
                     #[allow(clippy::redundant_clone, unused_braces, clippy::let_and_return)]
                     let mut #vtag = match () {
                         _ if "input".eq_ignore_ascii_case(::std::convert::AsRef::<::std::primitive::str>::as_ref(&#vtag_name)) => {
-                            ::yew::virtual_dom::VTag::__new_textarea(
+                            ::yew::virtual_dom::VTag::__new_input(
                                 #value,
+                                #checked,
                                 #node_ref,
                                 #key,
                                 #attributes,
@@ -484,6 +491,53 @@ impl ToTokens for HtmlElement {
     }
 }
 
+/// Returns whether `name` is the camelCase name of an SVG element.
+///
+/// The names of foreign elements are case-sensitive, so these are kept as written instead of being
+/// normalized to lowercase.
+fn is_case_sensitive_element_name(name: &str) -> bool {
+    matches!(
+        name,
+        "altGlyph"
+            | "altGlyphDef"
+            | "altGlyphItem"
+            | "animateColor"
+            | "animateMotion"
+            | "animateTransform"
+            | "clipPath"
+            | "feBlend"
+            | "feColorMatrix"
+            | "feComponentTransfer"
+            | "feComposite"
+            | "feConvolveMatrix"
+            | "feDiffuseLighting"
+            | "feDisplacementMap"
+            | "feDistantLight"
+            | "feDropShadow"
+            | "feFlood"
+            | "feFuncA"
+            | "feFuncB"
+            | "feFuncG"
+            | "feFuncR"
+            | "feGaussianBlur"
+            | "feImage"
+            | "feMerge"
+            | "feMergeNode"
+            | "feMorphology"
+            | "feOffset"
+            | "fePointLight"
+            | "feSpecularLighting"
+            | "feSpotLight"
+            | "feTile"
+            | "feTurbulence"
+            | "foreignObject"
+            | "glyphRef"
+            | "linearGradient"
+            | "radialGradient"
+            | "textPath"
+    )
+}
+
 fn wrap_attr_value<T: ToTokens>(value: T) -> TokenStream {
     quote_spanned! {value.span()=>
         ::yew::html::IntoPropValue::<
@@ -66,6 +66,7 @@ impl ToTokens for HtmlList {
             close,
         } = &self;
 
+        let children = children.to_build_vnode_vec_token_stream();
         let key = if let Some(key) = &open.props.key {
             quote_spanned! {key.span()=> ::std::option::Option::Some(::std::convert::Into::<::yew::virtual_dom::Key>::into(#key))}
         } else {
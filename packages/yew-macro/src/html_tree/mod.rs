@@ -19,7 +19,7 @@ mod html_node;
 mod lint;
 mod tag;
 
-use html_block::HtmlBlock;
+use html_block::{BlockContent, HtmlBlock};
 use html_component::HtmlComponent;
 pub use html_dashed_name::HtmlDashedName;
 use html_element::HtmlElement;
@@ -180,6 +180,23 @@ impl Parse for HtmlRootVNode {
 
 impl ToTokens for HtmlRootVNode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        // a single expression block is converted like a child block so that it can evaluate to
+        // optional or fallible nodes as well.
+        if let HtmlRoot::Tree(HtmlTree::Block(block)) = &self.0 {
+            if let BlockContent::Node(_) = block.content {
+                if let Some(new_tokens) = block.to_node_iterator_stream() {
+                    tokens.extend(
+                        quote_spanned! {self.0.span().resolved_at(Span::mixed_site())=> {
+                            <::yew::virtual_dom::VNode as ::std::convert::From<
+                                ::yew::utils::NodeSeq<_, ::yew::virtual_dom::VNode>,
+                            >>::from(#new_tokens)
+                        }},
+                    );
+                    return;
+                }
+            }
+        }
+
         let new_tokens = self.0.to_token_stream();
         tokens.extend(
             quote_spanned! {self.0.span().resolved_at(Span::mixed_site())=> {
@@ -270,6 +287,28 @@ impl HtmlChildrenTree {
         }
     }
 
+    // Check if any two adjacent children are blocks, which may both evaluate to text.
+    fn has_adjacent_blocks(&self) -> bool {
+        self.0
+            .windows(2)
+            .any(|m| matches!(m, [HtmlTree::Block(_), HtmlTree::Block(_)]))
+    }
+
+    /// Same as [`Self::to_build_vec_token_stream`], but adjacent text nodes produced by blocks
+    /// are concatenated into a single text node. This can only be used for children of type
+    /// `VNode`.
+    pub fn to_build_vnode_vec_token_stream(&self) -> TokenStream {
+        let children = self.to_build_vec_token_stream();
+
+        if self.has_adjacent_blocks() {
+            quote! {
+                ::yew::virtual_dom::VList::__concat_adjacent_text(#children)
+            }
+        } else {
+            children
+        }
+    }
+
     fn parse_delimited(input: ParseStream) -> syn::Result<Self> {
         let mut children = HtmlChildrenTree::new();
 
@@ -311,6 +350,7 @@ impl Parse for HtmlRootBraced {
 impl ToTokens for HtmlRootBraced {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Self { brace, children } = self;
+        let children = children.to_build_vnode_vec_token_stream();
 
         tokens.extend(quote_spanned! {brace.span.span()=>
             {
@@ -98,7 +98,7 @@ fn join_errors(mut it: impl Iterator<Item = syn::Error>) -> syn::Result<()> {
     })
 }
 
-#[proc_macro_derive(Properties, attributes(prop_or, prop_or_else, prop_or_default))]
+#[proc_macro_derive(Properties, attributes(prop_or, prop_or_else, prop_or_default, prop))]
 pub fn derive_props(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DerivePropsInput);
     TokenStream::from(input.into_token_stream())
@@ -37,13 +37,21 @@ impl ToTokens for BaseExpr {
 pub struct ComponentProps {
     props: Props,
     base_expr: Option<Expr>,
+    ssr_fallback: Option<Prop>,
 }
 impl ComponentProps {
+    const SSR_FALLBACK_LABEL: &'static str = "ssr_fallback";
+
     /// Get the special props supported by both variants
     pub fn special(&self) -> &SpecialProps {
         &self.props.special
     }
 
+    /// Get the content that is rendered instead of the component on the server side.
+    pub fn ssr_fallback(&self) -> Option<&Prop> {
+        self.ssr_fallback.as_ref()
+    }
+
     // check if the `children` prop is given explicitly
     pub fn children(&self) -> Option<&Prop> {
         self.props.get_by_label(CHILDREN_LABEL)
@@ -131,7 +139,7 @@ impl ComponentProps {
                 });
                 let set_children = children_renderer.map(|children| {
                     quote_spanned! {props_ty.span()=>
-                        #ident.children = #children;
+                        #ident.children = ::yew::html::IntoPropValue::into_prop_value(#children);
                     }
                 });
                 let init_base = quote_spanned! {expr.span().resolved_at(Span::call_site())=>
@@ -158,7 +166,8 @@ impl ComponentProps {
 
 impl Parse for ComponentProps {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let props = validate(input.parse()?)?;
+        let mut props = validate(input.parse()?)?;
+        let ssr_fallback = props.pop_unique(Self::SSR_FALLBACK_LABEL)?;
         let base_expr = if input.is_empty() {
             None
         } else {
@@ -167,7 +176,11 @@ impl Parse for ComponentProps {
 
         if input.is_empty() {
             let base_expr = base_expr.map(|base| base.expr);
-            Ok(Self { props, base_expr })
+            Ok(Self {
+                props,
+                base_expr,
+                ssr_fallback,
+            })
         } else {
             Err(syn::Error::new_spanned(
                 base_expr,
@@ -184,6 +197,7 @@ impl TryFrom<Props> for ComponentProps {
         Ok(Self {
             props: validate(props)?,
             base_expr: None,
+            ssr_fallback: None,
         })
     }
 }
@@ -252,4 +252,36 @@ mod raw_field_names {
 
 }
 
+mod skip_eq {
+    #[derive(::yew::Properties)]
+    pub struct Props {
+        value: ::std::primitive::u32,
+        #[prop(skip_eq)]
+        onchange: ::yew::Callback<::std::primitive::u32>,
+    }
+
+    #[derive(::yew::Properties)]
+    pub struct GenericProps<T: ::std::clone::Clone + ::std::cmp::PartialEq> {
+        value: T,
+        #[prop(skip_eq)]
+        data: ::std::rc::Rc<::std::vec::Vec<T>>,
+    }
+
+    fn skip_eq_fields_are_not_compared() {
+        use ::std::{assert, vec};
+
+        let a = ::yew::props! { Props { value: 1, onchange: ::yew::Callback::noop() } };
+        let b = ::yew::props! { Props { value: 1, onchange: ::yew::Callback::noop() } };
+        let c = ::yew::props! { Props { value: 2, onchange: ::yew::Callback::noop() } };
+        assert!(a == b);
+        assert!(a != c);
+
+        let data = ::std::rc::Rc::new(vec![1]);
+        let a = ::yew::props! { GenericProps::<::std::primitive::u32> { value: 1, data } };
+        let data = ::std::rc::Rc::new(vec![2]);
+        let b = ::yew::props! { GenericProps::<::std::primitive::u32> { value: 1, data } };
+        assert!(a == b);
+    }
+}
+
 fn main() {}
@@ -0,0 +1,9 @@
+use yew::prelude::*;
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    // ERROR: `ssr_fallback` is reserved for the attribute of the same name
+    ssr_fallback: Html,
+}
+
+fn main() {}
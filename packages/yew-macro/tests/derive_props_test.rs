@@ -4,4 +4,5 @@ fn derive_props() {
     let t = trybuild::TestCases::new();
     t.pass("tests/derive_props/pass.rs");
     t.compile_fail("tests/derive_props/fail.rs");
+    t.compile_fail("tests/derive_props/ssr_fallback-fail.rs");
 }
@@ -0,0 +1,45 @@
+#![no_implicit_prelude]
+
+#[derive(
+    ::std::clone::Clone,
+    ::yew::prelude::Properties,
+    ::std::prelude::rust_2021::PartialEq,
+)]
+struct Props {
+    a: usize,
+}
+
+#[::yew::prelude::function_component]
+async fn Comp(props: &Props) -> ::yew::prelude::Html {
+    ::yew::prelude::html! {
+        <p>
+            { props.a }
+        </p>
+    }
+}
+
+#[::yew::prelude::function_component(fallback = ::yew::prelude::html! { { "loading" } })]
+async fn CompWithFallback(props: &Props) -> ::yew::prelude::Html {
+    ::yew::prelude::html! {
+        <p>
+            { props.a }
+        </p>
+    }
+}
+
+#[::yew::prelude::function_component(NamedComp, fallback = ::yew::prelude::html! { { "loading" } })]
+async fn named_comp() -> ::yew::prelude::Html {
+    ::yew::prelude::html! {
+        <p>{ "named" }</p>
+    }
+}
+
+fn main() {
+    let _ = ::yew::prelude::html! {
+        <>
+            <Comp a={0} />
+            <CompWithFallback a={0} />
+            <NamedComp />
+        </>
+    };
+}
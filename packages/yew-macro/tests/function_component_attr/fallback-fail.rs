@@ -5,8 +5,8 @@ struct Props {
     a: usize,
 }
 
-#[function_component(Comp)]
-async fn comp(props: &Props) -> Html {
+#[function_component(fallback = html! { "loading" })]
+fn Comp(props: &Props) -> Html {
     html! {
         <p>
             { props.a }
@@ -14,5 +14,4 @@ async fn comp(props: &Props) -> Html {
     }
 }
 
-
 fn main() {}
@@ -0,0 +1,21 @@
+#![no_implicit_prelude]
+
+#[::yew::functional::function_component]
+fn Comp() -> ::yew::Html {
+    #[transfer]
+    let counter = ::yew::functional::use_state(|| 0_u32);
+
+    ::yew::html! {
+        <div>{ *counter }</div>
+    }
+}
+
+#[::yew::functional::hook]
+fn use_counter() -> ::yew::functional::UseStateHandle<::std::string::String> {
+    #[transfer]
+    let name = ::yew::functional::use_state(::std::string::String::new);
+
+    name
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use yew::prelude::*;
+
+mod hooks {
+    pub use yew::functional::use_state;
+}
+
+#[function_component]
+fn Comp() -> Html {
+    #[transfer]
+    let id = use_id();
+
+    html! {
+        <div {id}></div>
+    }
+}
+
+#[function_component]
+fn Aliased() -> Html {
+    use yew::use_state as state;
+
+    #[transfer]
+    let counter = state(|| 0_u32);
+
+    html! {
+        <div>{ *counter }</div>
+    }
+}
+
+#[function_component]
+fn Reexported() -> Html {
+    #[transfer]
+    let counter = hooks::use_state(|| 0_u32);
+
+    html! {
+        <div>{ *counter }</div>
+    }
+}
+
+fn main() {}
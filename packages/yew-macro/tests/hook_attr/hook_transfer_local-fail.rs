@@ -0,0 +1,22 @@
+use yew::{function_component, hook, html, Html, UseStateHandle};
+
+#[hook]
+fn use_state<T, F>(init_fn: F) -> UseStateHandle<T>
+where
+    T: 'static,
+    F: FnOnce() -> T,
+{
+    ::yew::functional::use_state(init_fn)
+}
+
+#[function_component]
+fn Comp() -> Html {
+    #[transfer]
+    let counter = use_state(|| 0_u32);
+
+    html! {
+        <div>{ *counter }</div>
+    }
+}
+
+fn main() {}
@@ -372,6 +372,16 @@ fn compile_pass() {
         </>
     };
 
+    ::yew::html! {
+        <>
+            <Child int=1 ssr_fallback={::yew::html! { "loading" }} />
+            <Child int=1 key="child" ssr_fallback="loading" />
+            <Container int=1 ssr_fallback={::yew::html! {}}>
+                <Child int=2 />
+            </Container>
+        </>
+    };
+
     ::yew::html_nested! { 1 };
 }
 
@@ -5,8 +5,11 @@ use std::rc::Rc;
 
 use web_sys::Element;
 
+use crate::callback::Callback;
 use crate::dom_bundle::BSubtree;
 use crate::html::{BaseComponent, NodeRef, Scope, Scoped};
+use crate::renderer::MountError;
+use crate::scheduler;
 
 /// An instance of an application.
 #[cfg(feature = "csr")]
@@ -24,16 +27,26 @@ where
     /// similarly to the `program` function in Elm. You should provide an initial model, `update`
     /// function which will update the state of the model and a `view` function which
     /// will render the model to a virtual DOM tree.
+    ///
+    /// `on_first_rendered` is notified once after the first `rendered` lifecycle of the root
+    /// component.
+    ///
+    /// Returns an error without touching `host` if it cannot be the root of an application.
     #[tracing::instrument(
         level = tracing::Level::DEBUG,
         name = "mount",
-        skip(props),
+        skip(props, scope, on_first_rendered),
     )]
-    pub(crate) fn mount_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+    pub(crate) fn mount_with_props(
+        host: Element,
+        props: Rc<COMP::Properties>,
+        scope: Scope<COMP>,
+        on_first_rendered: Option<Callback<()>>,
+    ) -> Result<Self, MountError> {
+        check_root(&host)?;
+
         clear_element(&host);
-        let app = Self {
-            scope: Scope::new(None),
-        };
+        let app = Self { scope };
         let hosting_root = BSubtree::create_root(&host);
         app.scope.mount_in_place(
             hosting_root,
@@ -41,9 +54,10 @@ where
             NodeRef::default(),
             NodeRef::default(),
             props,
+            on_first_rendered,
         );
 
-        app
+        Ok(app)
     }
 
     /// Update the properties of the app's root component.
@@ -67,6 +81,7 @@ where
         skip_all,
     )]
     pub fn destroy(self) {
+        scheduler::clear_time_budget(self.scope.id);
         self.scope.destroy(false)
     }
 }
@@ -82,6 +97,54 @@ where
     }
 }
 
+#[cfg(feature = "debug-tooling")]
+mod feat_debug_tooling {
+    use super::*;
+    use crate::virtual_dom::Key;
+
+    /// A snapshot of a component scope and the scopes of its descendants, see
+    /// [`AppHandle::scope_tree`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ScopeInfo {
+        /// The type name of the component, as returned by [`std::any::type_name`].
+        pub type_name: &'static str,
+        /// The key the component is rendered with, if any.
+        pub key: Option<Key>,
+        /// The scopes of the components rendered by this component, in document order.
+        pub children: Vec<ScopeInfo>,
+    }
+
+    impl<COMP> AppHandle<COMP>
+    where
+        COMP: BaseComponent,
+    {
+        /// Returns a snapshot of the scope tree of the application, e.g.: to inspect it in
+        /// development tools.
+        ///
+        /// The snapshot reflects the components that are rendered at the time of the call. It is
+        /// not updated afterwards, call this method again after a render to observe changes.
+        /// Components whose first render has not completed yet, e.g.: because they are still
+        /// being hydrated, are listed without children. The children of a suspended
+        /// `<Suspense />` are listed before the components of its fallback.
+        pub fn scope_tree(&self) -> ScopeInfo {
+            self.scope.scope_info(None)
+        }
+    }
+}
+#[cfg(feature = "debug-tooling")]
+pub use feat_debug_tooling::ScopeInfo;
+
+/// Checks that `host` belongs to a document that is displayed in a window.
+///
+/// The root does not have to be attached to its document, but an element of a document without a
+/// window, e.g.: one created by a `DOMParser`, is never displayed and never receives events.
+fn check_root(host: &Element) -> Result<(), MountError> {
+    match host.owner_document().and_then(|m| m.default_view()) {
+        Some(_) => Ok(()),
+        None => Err(MountError::RootNotInDocument),
+    }
+}
+
 /// Removes anything from the given element.
 fn clear_element(host: &Element) {
     while let Some(child) = host.last_child() {
@@ -91,25 +154,101 @@ fn clear_element(host: &Element) {
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
+    use std::time::Duration;
+
+    use web_sys::Node;
+
     use super::*;
     use crate::dom_bundle::Fragment;
+    use crate::html::HydrationData;
+    use crate::renderer::HydrationReport;
+    use crate::virtual_dom::{Collectable, ROOT_CLOSE_MARK, ROOT_OPEN_MARK};
+
+    /// Returns whether `node` is a comment with the text `mark`.
+    fn is_mark(node: &Node, mark: &str) -> bool {
+        node.node_type() == Node::COMMENT_NODE && node.text_content().map_or(false, |m| m == mark)
+    }
+
+    /// Removes the nodes up to and including the opening marker of the root boundary from the front
+    /// of `fragment`, if it contains the marker.
+    ///
+    /// Returns whether the marker was found.
+    fn skip_to_root(fragment: &mut Fragment) -> bool {
+        if !fragment.iter().any(|m| is_mark(m, ROOT_OPEN_MARK)) {
+            return false;
+        }
+
+        while let Some(m) = fragment.pop_front() {
+            if is_mark(&m, ROOT_OPEN_MARK) {
+                break;
+            }
+        }
+
+        true
+    }
 
     impl<COMP> AppHandle<COMP>
     where
         COMP: BaseComponent,
     {
+        /// Returns whether the first node in `host`, ignoring text nodes, is the opening hydration
+        /// marker of a component.
+        ///
+        /// If `host` contains the opening marker of the root boundary, the first node after it is
+        /// checked instead.
+        pub(crate) fn has_hydration_markers(host: &Element) -> bool {
+            let collectable = Collectable::for_component::<COMP>();
+
+            let mut fragment = Fragment::collect_children(host);
+            skip_to_root(&mut fragment);
+
+            fragment
+                .iter()
+                .find(|m| m.node_type() != Node::TEXT_NODE)
+                .filter(|m| m.node_type() == Node::COMMENT_NODE)
+                .and_then(|m| m.text_content())
+                .map_or(false, |m| {
+                    m.starts_with(collectable.open_start_mark())
+                        && m.ends_with(collectable.end_mark())
+                })
+        }
+
         #[tracing::instrument(
             level = tracing::Level::DEBUG,
             name = "hydrate",
-            skip(props),
+            skip(props, scope, hydration_data, on_report),
         )]
-        pub(crate) fn hydrate_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
-            let app = Self {
-                scope: Scope::new(None),
-            };
+        pub(crate) fn hydrate_with_props(
+            host: Element,
+            props: Rc<COMP::Properties>,
+            scope: Scope<COMP>,
+            hydration_data: Option<HydrationData>,
+            on_report: Option<Callback<HydrationReport>>,
+        ) -> Result<Self, MountError> {
+            check_root(&host)?;
+
+            let app = Self { scope };
 
             let mut fragment = Fragment::collect_children(&host);
+            // Nodes around the root boundary belong to the surrounding page.
+            let has_root_marker = skip_to_root(&mut fragment);
             let hosting_root = BSubtree::create_root(&host);
+            if let Some(m) = hydration_data {
+                hosting_root.set_hydration_data(m);
+            }
+
+            let on_first_rendered = on_report.map(|on_report| {
+                let hosting_root = hosting_root.clone();
+                let start = js_sys::Date::now();
+
+                Callback::from(move |_| {
+                    let elapsed = (js_sys::Date::now() - start).max(0.0);
+
+                    let mut report = hosting_root.take_hydration_report();
+                    report.duration = Duration::from_secs_f64(elapsed / 1000.0);
+                    on_report.emit(report);
+                })
+            });
 
             app.scope.hydrate_in_place(
                 hosting_root,
@@ -117,7 +256,24 @@ mod feat_hydration {
                 &mut fragment,
                 NodeRef::default(),
                 Rc::clone(&props),
+                on_first_rendered,
             );
+
+            if has_root_marker {
+                // The application is followed by the closing marker of the root boundary, which
+                // is kept so the surrounding page stays untouched.
+                let next_sibling = NodeRef::default();
+                next_sibling.set(
+                    fragment
+                        .front()
+                        .filter(|m| is_mark(m, ROOT_CLOSE_MARK))
+                        .cloned(),
+                );
+                app.scope.reuse(props, next_sibling);
+
+                return Ok(app);
+            }
+
             #[cfg(debug_assertions)] // Fix trapped next_sibling at the root
             app.scope.reuse(props, NodeRef::default());
 
@@ -127,7 +283,61 @@ mod feat_hydration {
                 host.remove_child(node).unwrap();
             }
 
-            app
+            Ok(app)
+        }
+
+        /// Hydrates the first component of type `COMP` whose hydration markers are children of
+        /// `host`, leaving all other children of `host` untouched.
+        ///
+        /// In release builds, the markers do not contain the names of components and the first
+        /// component is hydrated.
+        #[tracing::instrument(level = tracing::Level::DEBUG, name = "hydrate_island", skip_all)]
+        pub(crate) fn hydrate_island_with_props(
+            host: Element,
+            props: Rc<COMP::Properties>,
+            scope: Scope<COMP>,
+        ) -> Result<Self, MountError> {
+            check_root(&host)?;
+
+            let app = Self { scope };
+
+            let collectable = Collectable::for_component::<COMP>();
+            let is_open_tag = |node: &Node| {
+                node.node_type() == Node::COMMENT_NODE
+                    && node
+                        .text_content()
+                        .map_or(false, |m| collectable.is_component_open_tag(&m))
+            };
+
+            // Nodes before the opening marker of the island are skipped.
+            let mut fragment = Fragment::collect_children(&host);
+            while let Some(m) = fragment.front() {
+                if is_open_tag(m) {
+                    break;
+                }
+                fragment.pop_front();
+            }
+            assert!(
+                !fragment.is_empty(),
+                "expected the hydration markers of {} in the island.",
+                collectable.name()
+            );
+
+            app.scope.hydrate_in_place(
+                BSubtree::create_root(&host),
+                host.clone(),
+                &mut fragment,
+                NodeRef::default(),
+                Rc::clone(&props),
+                None,
+            );
+
+            // The island is followed by the node after its closing marker, if any.
+            let next_sibling = NodeRef::default();
+            next_sibling.set(fragment.front().cloned());
+            app.scope.reuse(props, next_sibling);
+
+            Ok(app)
         }
     }
 }
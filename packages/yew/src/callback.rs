@@ -5,7 +5,7 @@
 //! - [Timer](https://github.com/yewstack/yew/tree/master/examples/timer)
 
 use std::fmt;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use crate::html::ImplicitClone;
 
@@ -50,6 +50,63 @@ impl<IN, OUT> Callback<IN, OUT> {
     pub fn emit(&self, value: IN) -> OUT {
         (*self.cb)(value)
     }
+
+    /// Creates a [`WeakCallback`] that refers to the function of this callback without keeping
+    /// it alive.
+    ///
+    /// A callback keeps everything it captures alive, e.g.: the state handles of a component.
+    /// Registering a weak callback, e.g.: with an external event bus, instead of the callback
+    /// itself does not keep the component's state alive after it is unmounted and breaks
+    /// reference cycles between a callback and the state it is stored in.
+    pub fn downgrade(&self) -> WeakCallback<IN, OUT> {
+        WeakCallback {
+            cb: Rc::downgrade(&self.cb),
+        }
+    }
+}
+
+/// A weak reference to the function of a [`Callback`], created with [`Callback::downgrade`].
+///
+/// The function is dropped once all callbacks referring to it are dropped, regardless of the weak
+/// callbacks referring to it. Afterwards, [`upgrade`](Self::upgrade) returns `None` and
+/// [`emit`](Self::emit) is a no-op.
+pub struct WeakCallback<IN, OUT = ()> {
+    cb: Weak<dyn Fn(IN) -> OUT>,
+}
+
+impl<IN, OUT> WeakCallback<IN, OUT> {
+    /// Returns the callback this weak callback refers to, or `None` if it has been dropped.
+    pub fn upgrade(&self) -> Option<Callback<IN, OUT>> {
+        self.cb.upgrade().map(|cb| Callback { cb })
+    }
+
+    /// Calls the function of the callback if it has not been dropped.
+    ///
+    /// Returns `None` without calling the function if it has been dropped.
+    pub fn emit(&self, value: IN) -> Option<OUT> {
+        self.upgrade().map(|cb| cb.emit(value))
+    }
+}
+
+impl<IN, OUT> Clone for WeakCallback<IN, OUT> {
+    fn clone(&self) -> Self {
+        Self {
+            cb: self.cb.clone(),
+        }
+    }
+}
+
+#[allow(clippy::vtable_address_comparisons)]
+impl<IN, OUT> PartialEq for WeakCallback<IN, OUT> {
+    fn eq(&self, other: &WeakCallback<IN, OUT>) -> bool {
+        Weak::ptr_eq(&self.cb, &other.cb)
+    }
+}
+
+impl<IN, OUT> fmt::Debug for WeakCallback<IN, OUT> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WeakCallback<_>")
+    }
 }
 
 impl<IN> Callback<IN> {
@@ -92,12 +149,51 @@ impl<IN: 'static, OUT: 'static> Callback<IN, OUT> {
         let func = move |input| func(input).map(|output| this.emit(output));
         Callback::from(func)
     }
+
+    /// Creates a new callback from another callback and a function.
+    /// When emitted will call the function first and then emit its result to the original
+    /// callback.
+    ///
+    /// This is the same as [`reform`](Self::reform).
+    pub fn map<F, T>(&self, func: F) -> Callback<T, OUT>
+    where
+        F: Fn(T) -> IN + 'static,
+    {
+        self.reform(func)
+    }
+}
+
+impl<IN: 'static> Callback<IN> {
+    /// Creates a new callback from another callback and a predicate.
+    /// When emitted will call the predicate with the value and, only if it returns `true`, will
+    /// emit the value to the original callback. Otherwise, the value is dropped.
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// # let onclick = Callback::<MouseEvent>::noop();
+    /// let onclick = onclick.filter(|e: &MouseEvent| !e.shift_key());
+    /// ```
+    pub fn filter<F>(&self, predicate: F) -> Callback<IN>
+    where
+        F: Fn(&IN) -> bool + 'static,
+    {
+        let this = self.clone();
+        let func = move |input| {
+            if predicate(&input) {
+                this.emit(input);
+            }
+        };
+        Callback::from(func)
+    }
 }
 
 impl<IN, OUT> ImplicitClone for Callback<IN, OUT> {}
 
+impl<IN, OUT> ImplicitClone for WeakCallback<IN, OUT> {}
+
 #[cfg(test)]
 mod test {
+    use std::cell::Cell;
     use std::sync::Mutex;
 
     use super::*;
@@ -144,4 +240,61 @@ mod test {
             vec![true, false]
         );
     }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(*emit([1, 2, 3], |cb| cb.map(|v: i32| v * 2)), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_filter() {
+        assert_eq!(
+            *emit([1, 2, 3, 4], |cb| cb.filter(|v: &i32| v % 2 == 0)),
+            vec![2, 4]
+        );
+    }
+
+    #[test]
+    fn test_weak_callback() {
+        let count = Rc::new(Cell::new(0));
+        let cb = {
+            let count = count.clone();
+            Callback::from(move |n: usize| {
+                count.set(count.get() + n);
+                count.get()
+            })
+        };
+
+        let weak = cb.downgrade();
+        assert_eq!(weak.emit(1), Some(1));
+        assert_eq!(weak.upgrade(), Some(cb.clone()));
+        assert_eq!(weak, weak.clone());
+
+        // the captured values are dropped with the last callback.
+        drop(cb);
+        assert_eq!(Rc::strong_count(&count), 1);
+        assert_eq!(weak.upgrade(), None);
+        assert_eq!(weak.emit(1), None);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn test_return_value() {
+        let validate = Callback::from(|value: String| {
+            if value.is_empty() {
+                Err("value must not be empty".to_string())
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(validate.emit("yew".to_string()), Ok(()));
+        assert_eq!(
+            validate.emit(String::new()),
+            Err("value must not be empty".to_string())
+        );
+
+        let validate = validate.reform(|value: &'static str| value.trim().to_owned());
+        assert!(validate.emit("   ").is_err());
+    }
 }
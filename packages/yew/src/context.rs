@@ -21,6 +21,9 @@ pub struct ContextProviderProps<T: Clone + PartialEq> {
 /// Every child (direct or indirect) of this component may access the context value.
 /// In order to consume contexts, [`Scope::context`][Scope::context] method is used,
 /// In function components the `use_context` hook is used.
+///
+/// Contexts are resolved in the same way during server-side rendering, so consumers render the
+/// value provided by their closest `ContextProvider` ancestor on both the server and the client.
 #[derive(Debug)]
 pub struct ContextProvider<T: Clone + PartialEq + 'static> {
     context: T,
@@ -106,3 +109,86 @@ impl<T: Clone + PartialEq + 'static> Component for ContextProvider<T> {
         html! { <>{ ctx.props().children.clone() }</> }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Theme {
+        color: String,
+    }
+
+    #[test]
+    async fn test_context_in_ssr() {
+        #[function_component]
+        fn Label() -> Html {
+            let theme = use_context::<Theme>().expect("no theme provided");
+
+            html! { <span>{theme.color}</span> }
+        }
+
+        struct StructLabel;
+
+        impl Component for StructLabel {
+            type Message = ();
+            type Properties = ();
+
+            fn create(_ctx: &Context<Self>) -> Self {
+                Self
+            }
+
+            fn view(&self, ctx: &Context<Self>) -> Html {
+                let (theme, _) = ctx
+                    .link()
+                    .context::<Theme>(Callback::noop())
+                    .expect("no theme provided");
+
+                html! { <b>{theme.color}</b> }
+            }
+        }
+
+        #[derive(Properties, PartialEq)]
+        struct WrapperProps {
+            children: Children,
+        }
+
+        #[function_component]
+        fn Wrapper(props: &WrapperProps) -> Html {
+            html! { <div>{props.children.clone()}</div> }
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            let theme = Theme {
+                color: "crimson".to_string(),
+            };
+
+            html! {
+                <ContextProvider<Theme> context={theme}>
+                    <Wrapper>
+                        <Wrapper>
+                            <Label />
+                            <StructLabel />
+                        </Wrapper>
+                    </Wrapper>
+                </ContextProvider<Theme>>
+            }
+        }
+
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<div><div><span>crimson</span><b>crimson</b></div></div>"
+        );
+    }
+}
@@ -26,6 +26,12 @@ impl BComp {
     pub fn key(&self) -> Option<&Key> {
         self.key.as_ref()
     }
+
+    /// Get information about the scope of the component and its descendants
+    #[cfg(feature = "debug-tooling")]
+    pub(super) fn scope_info(&self) -> crate::ScopeInfo {
+        self.scope.scope_info(self.key.as_ref())
+    }
 }
 
 impl fmt::Debug for BComp {
@@ -165,6 +171,9 @@ mod feat_hydration {
 #[cfg(target_arch = "wasm32")]
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use gloo::utils::document;
     use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
     use web_sys::Element;
@@ -297,6 +306,68 @@ mod tests {
         assert_ne!(vchild2, vchild3);
     }
 
+    #[test]
+    fn same_props_rc_skips_rerender() {
+        thread_local! {
+            static RENDERS: Cell<usize> = Cell::new(0);
+        }
+
+        #[derive(Properties)]
+        struct UncomparableProps {
+            on_render: Rc<dyn Fn()>,
+        }
+
+        // closures cannot be compared.
+        impl PartialEq for UncomparableProps {
+            fn eq(&self, _other: &Self) -> bool {
+                false
+            }
+        }
+
+        struct Uncomparable;
+
+        impl Component for Uncomparable {
+            type Message = ();
+            type Properties = UncomparableProps;
+
+            fn create(_: &Context<Self>) -> Self {
+                Uncomparable
+            }
+
+            fn view(&self, ctx: &Context<Self>) -> Html {
+                (ctx.props().on_render)();
+                html! { <div/> }
+            }
+        }
+
+        let create_props = || {
+            Rc::new(UncomparableProps {
+                on_render: Rc::new(|| RENDERS.with(|m| m.set(m.get() + 1))),
+            })
+        };
+        let (root, scope, parent) = setup_parent();
+        let props = create_props();
+
+        let vchild = VChild::<Uncomparable>::from_rc(props.clone(), None);
+        assert!(vchild.ptr_eq(&VChild::from_rc(props.clone(), None)));
+        assert_ne!(vchild, VChild::from_rc(create_props(), None));
+
+        let (_, mut bundle) =
+            VNode::from(vchild).attach(&root, &scope, &parent, NodeRef::default());
+        scheduler::start_now();
+        assert_eq!(RENDERS.with(|m| m.get()), 1);
+
+        let node = VNode::from(VChild::<Uncomparable>::from_rc(props, None));
+        node.reconcile_node(&root, &scope, &parent, NodeRef::default(), &mut bundle);
+        scheduler::start_now();
+        assert_eq!(RENDERS.with(|m| m.get()), 1);
+
+        let node = VNode::from(VChild::<Uncomparable>::from_rc(create_props(), None));
+        node.reconcile_node(&root, &scope, &parent, NodeRef::default(), &mut bundle);
+        scheduler::start_now();
+        assert_eq!(RENDERS.with(|m| m.get()), 2);
+    }
+
     #[derive(Clone, Properties, PartialEq)]
     pub struct ListProps {
         pub children: Children,
@@ -445,7 +445,7 @@ impl Reconcilable for VList {
         }
 
         let fully_keyed = self.fully_keyed();
-        let lefts = self.children;
+        let lefts = self.children.take();
         let rights = &mut blist.rev_children;
         test_log!("lefts: {:?}", lefts);
         test_log!("rights: {:?}", rights);
@@ -472,7 +472,7 @@ mod feat_hydration {
 
     impl Hydratable for VList {
         fn hydrate(
-            self,
+            mut self,
             root: &BSubtree,
             parent_scope: &AnyScope,
             parent: &Element,
@@ -480,11 +480,38 @@ mod feat_hydration {
         ) -> (NodeRef, Self::Bundle) {
             let node_ref = NodeRef::default();
             let fully_keyed = self.fully_keyed();
-            let vchildren = self.children;
+            let vchildren = self.children.take();
             let mut children = Vec::with_capacity(vchildren.len());
 
+            // Keyed components are hydrated from the nodes the server rendered for the same key,
+            // which are moved into the order of the list on the client side.
+            let mut keyed_fragments = None;
+            if fully_keyed && vchildren.iter().all(|m| matches!(m, VNode::VComp(_))) {
+                let keys = vchildren
+                    .iter()
+                    .filter_map(|m| m.key().cloned())
+                    .collect::<Vec<_>>();
+                keyed_fragments = fragment.collect_keyed_components(&keys, parent);
+            }
+            let next_sibling = fragment
+                .front()
+                .cloned()
+                .map(NodeRef::new)
+                .unwrap_or_default();
+
             for (index, child) in vchildren.into_iter().enumerate() {
-                let (child_node_ref, child) = child.hydrate(root, parent_scope, parent, fragment);
+                let keyed_fragment = keyed_fragments
+                    .as_mut()
+                    .zip(child.key())
+                    .and_then(|(fragments, key)| fragments.remove(key));
+
+                let (child_node_ref, child) = match keyed_fragment {
+                    Some(mut m) => {
+                        m.shift(parent, next_sibling.clone());
+                        child.hydrate(root, parent_scope, parent, &mut m)
+                    }
+                    None => child.hydrate(root, parent_scope, parent, fragment),
+                };
 
                 if index == 0 {
                     node_ref.link(child_node_ref);
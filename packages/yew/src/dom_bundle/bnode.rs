@@ -5,6 +5,8 @@ use std::fmt;
 use web_sys::{Element, Node};
 
 use super::{BComp, BList, BPortal, BRaw, BSubtree, BSuspense, BTag, BText};
+#[cfg(feature = "hydration")]
+use crate::dom_bundle::Fragment;
 use crate::dom_bundle::{Reconcilable, ReconcileTarget};
 use crate::html::{AnyScope, NodeRef};
 use crate::virtual_dom::{Key, VNode};
@@ -27,6 +29,9 @@ pub(super) enum BNode {
     Suspense(Box<BSuspense>),
     /// A raw HTML string, represented by [`AttrValue`](crate::AttrValue).
     Raw(BRaw),
+    /// Server-rendered nodes that are adopted without being hydrated.
+    #[cfg(feature = "hydration")]
+    Fragment(Fragment),
 }
 
 impl BNode {
@@ -41,6 +46,38 @@ impl BNode {
             Self::Portal(bportal) => bportal.key(),
             Self::Suspense(bsusp) => bsusp.key(),
             Self::Raw(_) => None,
+            #[cfg(feature = "hydration")]
+            Self::Fragment(_) => None,
+        }
+    }
+
+    /// Collects information about the scopes of the components in this node, in document order
+    #[cfg(feature = "debug-tooling")]
+    pub(super) fn collect_scope_infos(&self, infos: &mut Vec<crate::ScopeInfo>) {
+        match self {
+            Self::Comp(bcomp) => infos.push(bcomp.scope_info()),
+            Self::List(blist) => {
+                // children of a list are stored in reverse order
+                for node in blist.iter().rev() {
+                    node.collect_scope_infos(infos);
+                }
+            }
+            Self::Tag(btag) => {
+                for node in btag.children().iter().rev() {
+                    node.collect_scope_infos(infos);
+                }
+            }
+            Self::Portal(bportal) => bportal.node().collect_scope_infos(infos),
+            Self::Suspense(bsusp) => {
+                let (children, fallback) = bsusp.bundles();
+                children.collect_scope_infos(infos);
+                if let Some(fallback) = fallback {
+                    fallback.collect_scope_infos(infos);
+                }
+            }
+            Self::Ref(_) | Self::Text(_) | Self::Raw(_) => {}
+            #[cfg(feature = "hydration")]
+            Self::Fragment(_) => {}
         }
     }
 }
@@ -62,6 +99,8 @@ impl ReconcileTarget for BNode {
             Self::Portal(bportal) => bportal.detach(root, parent, parent_to_detach),
             Self::Suspense(bsusp) => bsusp.detach(root, parent, parent_to_detach),
             Self::Raw(raw) => raw.detach(root, parent, parent_to_detach),
+            #[cfg(feature = "hydration")]
+            Self::Fragment(fragment) => fragment.detach(root, parent, parent_to_detach),
         }
     }
 
@@ -81,6 +120,8 @@ impl ReconcileTarget for BNode {
             Self::Portal(ref vportal) => vportal.shift(next_parent, next_sibling),
             Self::Suspense(ref vsuspense) => vsuspense.shift(next_parent, next_sibling),
             Self::Raw(ref braw) => braw.shift(next_parent, next_sibling),
+            #[cfg(feature = "hydration")]
+            Self::Fragment(ref fragment) => fragment.shift(next_parent, next_sibling),
         }
     }
 }
@@ -252,6 +293,8 @@ impl fmt::Debug for BNode {
             Self::Portal(ref vportal) => vportal.fmt(f),
             Self::Suspense(ref bsusp) => bsusp.fmt(f),
             Self::Raw(ref braw) => braw.fmt(f),
+            #[cfg(feature = "hydration")]
+            Self::Fragment(ref fragment) => fragment.fmt(f),
         }
     }
 }
@@ -123,6 +123,12 @@ impl BPortal {
     pub fn key(&self) -> Option<&Key> {
         self.node.key()
     }
+
+    /// Get the inserted node
+    #[cfg(feature = "debug-tooling")]
+    pub(super) fn node(&self) -> &BNode {
+        &self.node
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
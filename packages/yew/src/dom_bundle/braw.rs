@@ -3,7 +3,7 @@ use web_sys::Element;
 
 use crate::dom_bundle::bnode::BNode;
 use crate::dom_bundle::traits::{Reconcilable, ReconcileTarget};
-use crate::dom_bundle::utils::insert_node;
+use crate::dom_bundle::utils::{insert_node, owner_document};
 use crate::dom_bundle::BSubtree;
 use crate::html::AnyScope;
 use crate::virtual_dom::VRaw;
@@ -17,8 +17,8 @@ pub struct BRaw {
 }
 
 impl BRaw {
-    fn create_elements(html: &str) -> Vec<Element> {
-        let div = gloo::utils::document().create_element("div").unwrap();
+    fn create_elements(html: &str, parent: &Element) -> Vec<Element> {
+        let div = owner_document(parent).create_element("div").unwrap();
         div.set_inner_html(html);
         let children = div.children();
         let children = js_sys::Array::from(&children);
@@ -81,7 +81,7 @@ impl Reconcilable for VRaw {
         parent: &Element,
         next_sibling: NodeRef,
     ) -> (NodeRef, Self::Bundle) {
-        let elements = BRaw::create_elements(&self.html);
+        let elements = BRaw::create_elements(&self.html, parent);
         if elements.is_empty() {
             return (
                 next_sibling.clone(),
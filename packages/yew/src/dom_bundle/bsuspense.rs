@@ -1,11 +1,10 @@
 //! This module contains the bundle version of a supsense [BSuspense]
 
-use gloo::utils::document;
 use web_sys::Element;
 
 #[cfg(feature = "hydration")]
 use super::Fragment;
-use super::{BNode, BSubtree, Reconcilable, ReconcileTarget};
+use super::{owner_document, BNode, BSubtree, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::{Key, VSuspense};
 use crate::NodeRef;
@@ -34,6 +33,17 @@ impl BSuspense {
     pub fn key(&self) -> Option<&Key> {
         self.key.as_ref()
     }
+
+    /// Get the bundle of the children and the bundle of the fallback, if it is rendered
+    #[cfg(feature = "debug-tooling")]
+    pub(super) fn bundles(&self) -> (&BNode, Option<&BNode>) {
+        let fallback = match self.fallback {
+            Some(Fallback::Bundle(ref m)) => Some(m),
+            _ => None,
+        };
+
+        (&self.children_bundle, fallback)
+    }
 }
 
 impl ReconcileTarget for BSuspense {
@@ -86,7 +96,7 @@ impl Reconcilable for VSuspense {
             suspended,
             key,
         } = self;
-        let detached_parent = document()
+        let detached_parent = owner_document(parent)
             .create_element("div")
             .expect("failed to create detached element");
 
@@ -227,9 +237,11 @@ impl Reconcilable for VSuspense {
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
+    use web_sys::Node;
+
     use super::*;
     use crate::dom_bundle::{Fragment, Hydratable};
-    use crate::virtual_dom::Collectable;
+    use crate::virtual_dom::{Collectable, SuspenseMark};
 
     impl Hydratable for VSuspense {
         fn hydrate(
@@ -239,29 +251,52 @@ mod feat_hydration {
             parent: &Element,
             fragment: &mut Fragment,
         ) -> (NodeRef, Self::Bundle) {
-            let detached_parent = document()
+            let detached_parent = owner_document(parent)
                 .create_element("div")
                 .expect("failed to create detached element");
 
             let collectable = Collectable::Suspense;
+
+            // A deferred suspense only contains the rendered fallback, its children were not
+            // rendered on the server side. Refer to VSuspense::render_into_stream
+            fragment.trim_start_text_nodes(parent);
+            let mark = fragment
+                .front()
+                .filter(|m| m.node_type() == Node::COMMENT_NODE)
+                .and_then(|m| m.text_content())
+                .and_then(|m| SuspenseMark::from_open_tag(&m));
+
             let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
 
-            let mut nodes = fallback_fragment.deep_clone();
+            let children_bundle = if mark == Some(SuspenseMark::Deferred) {
+                // The rendered fallback is kept as placeholder while the children are rendered.
+                let (_, children_bundle) =
+                    self.children
+                        .attach(root, parent_scope, &detached_parent, NodeRef::default());
 
-            for node in nodes.iter() {
-                detached_parent.append_child(node).unwrap();
-            }
+                children_bundle
+            } else {
+                let mut nodes = fallback_fragment.deep_clone();
+
+                for node in nodes.iter() {
+                    detached_parent.append_child(node).unwrap();
+                }
+
+                // The children were resolved on the server side, they correspond to the first
+                // non-suspended content and are hydrated with the states prepared on the server
+                // side instead of suspending again. Refer to VSuspense::render_into_stream
+                let (_, children_bundle) =
+                    self.children
+                        .hydrate(root, parent_scope, &detached_parent, &mut nodes);
 
-            // Even if initially suspended, these children correspond to the first non-suspended
-            // content Refer to VSuspense::render_to_string
-            let (_, children_bundle) =
-                self.children
-                    .hydrate(root, parent_scope, &detached_parent, &mut nodes);
+                // We trim all leading text nodes before checking as it's likely these are
+                // whitespaces.
+                nodes.trim_start_text_nodes(&detached_parent);
 
-            // We trim all leading text nodes before checking as it's likely these are whitespaces.
-            nodes.trim_start_text_nodes(&detached_parent);
+                assert!(nodes.is_empty(), "expected end of suspense, found node.");
 
-            assert!(nodes.is_empty(), "expected end of suspense, found node.");
+                children_bundle
+            };
 
             let node_ref = fallback_fragment
                 .front()
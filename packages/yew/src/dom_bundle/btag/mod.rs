@@ -7,15 +7,14 @@ use std::borrow::Cow;
 use std::hint::unreachable_unchecked;
 use std::ops::DerefMut;
 
-use gloo::utils::document;
 use listeners::ListenerRegistration;
 pub use listeners::Registry;
 use wasm_bindgen::JsCast;
 use web_sys::{Element, HtmlTextAreaElement as TextAreaElement};
 
-use super::{insert_node, BList, BNode, BSubtree, Reconcilable, ReconcileTarget};
+use super::{insert_node, owner_document, BList, BNode, BSubtree, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
-use crate::virtual_dom::vtag::{InputFields, VTagInner, Value, SVG_NAMESPACE};
+use crate::virtual_dom::vtag::{InputFields, VTagInner, Value, MATHML_NAMESPACE, SVG_NAMESPACE};
 use crate::virtual_dom::{Attributes, Key, VTag};
 use crate::NodeRef;
 
@@ -241,19 +240,28 @@ impl Reconcilable for VTag {
 impl VTag {
     fn create_element(&self, parent: &Element) -> Element {
         let tag = self.tag();
-        if tag == "svg"
-            || parent
-                .namespace_uri()
-                .map_or(false, |ns| ns == SVG_NAMESPACE)
-        {
-            let namespace = Some(SVG_NAMESPACE);
-            document()
-                .create_element_ns(namespace, tag)
-                .expect("can't create namespaced element for vtag")
-        } else {
-            document()
+        let namespace = match tag {
+            "svg" => Some(SVG_NAMESPACE),
+            "math" => Some(MATHML_NAMESPACE),
+            // Elements inherit the namespace of foreign content, except for the children of a
+            // `<foreignObject>`, which are HTML elements.
+            _ => match parent.namespace_uri() {
+                Some(ns) if ns == SVG_NAMESPACE && parent.local_name() != "foreignObject" => {
+                    Some(SVG_NAMESPACE)
+                }
+                Some(ns) if ns == MATHML_NAMESPACE => Some(MATHML_NAMESPACE),
+                _ => None,
+            },
+        };
+
+        let document = owner_document(parent);
+        match namespace {
+            Some(namespace) => document
+                .create_element_ns(Some(namespace), tag)
+                .expect("can't create namespaced element for vtag"),
+            None => document
                 .create_element(tag)
-                .expect("can't create element for vtag")
+                .expect("can't create element for vtag"),
         }
     }
 }
@@ -270,9 +278,8 @@ impl BTag {
         &self.reference
     }
 
-    #[cfg(target_arch = "wasm32")]
-    #[cfg(test)]
-    fn children(&self) -> &[BNode] {
+    #[cfg(any(all(target_arch = "wasm32", test), feature = "debug-tooling"))]
+    pub(super) fn children(&self) -> &[BNode] {
         match &self.inner {
             BTagInner::Other { child_bundle, .. } => child_bundle,
             _ => &[],
@@ -328,7 +335,8 @@ mod feat_hydration {
                 "expected element, found node type {}.",
                 node_type_str(&node),
             );
-            let el = node.dyn_into::<Element>().expect("expected an element.");
+            let el = node.unchecked_into::<Element>();
+            root.record_hydrated_node(false);
 
             assert_eq!(
                 el.tag_name().to_lowercase(),
@@ -392,7 +400,7 @@ mod tests {
     use super::*;
     use crate::dom_bundle::utils::setup_parent;
     use crate::dom_bundle::{BNode, Reconcilable, ReconcileTarget};
-    use crate::virtual_dom::vtag::{HTML_NAMESPACE, SVG_NAMESPACE};
+    use crate::virtual_dom::vtag::{HTML_NAMESPACE, MATHML_NAMESPACE, SVG_NAMESPACE};
     use crate::virtual_dom::{AttrValue, VNode, VTag};
     use crate::{html, Html, NodeRef};
 
@@ -600,6 +608,48 @@ mod tests {
         assert_namespace(&g_tag, SVG_NAMESPACE);
     }
 
+    #[test]
+    fn supports_svg_foreign_object() {
+        let (root, scope, parent) = setup_parent();
+
+        let svg_node = html! {
+            <svg>
+                <foreignObject>
+                    <div>{"text"}</div>
+                </foreignObject>
+            </svg>
+        };
+
+        let svg_tag = assert_vtag(svg_node);
+        let (_, svg_tag) = svg_tag.attach(&root, &scope, &parent, NodeRef::default());
+        let foreign_object_tag = assert_btag_ref(svg_tag.children().get(0).unwrap());
+        assert_namespace(foreign_object_tag, SVG_NAMESPACE);
+        let div_tag = assert_btag_ref(foreign_object_tag.children().get(0).unwrap());
+        assert_namespace(div_tag, HTML_NAMESPACE);
+    }
+
+    #[test]
+    fn supports_mathml() {
+        let (root, scope, parent) = setup_parent();
+
+        let math_node = html! {
+            <math display="block">
+                <mfrac>
+                    <mi>{"a"}</mi>
+                    <mn>{"2"}</mn>
+                </mfrac>
+            </math>
+        };
+
+        let math_tag = assert_vtag(math_node);
+        let (_, math_tag) = math_tag.attach(&root, &scope, &parent, NodeRef::default());
+        assert_namespace(&math_tag, MATHML_NAMESPACE);
+        let mfrac_tag = assert_btag_ref(math_tag.children().get(0).unwrap());
+        assert_namespace(mfrac_tag, MATHML_NAMESPACE);
+        let mi_tag = assert_btag_ref(mfrac_tag.children().get(0).unwrap());
+        assert_namespace(mi_tag, MATHML_NAMESPACE);
+    }
+
     #[test]
     fn it_compares_values() {
         let a = html! {
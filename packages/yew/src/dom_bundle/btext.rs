@@ -1,9 +1,8 @@
 //! This module contains the bundle implementation of text [BText].
 
-use gloo::utils::document;
 use web_sys::{Element, Text as TextNode};
 
-use super::{insert_node, BNode, BSubtree, Reconcilable, ReconcileTarget};
+use super::{insert_node, owner_document, BNode, BSubtree, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::{AttrValue, VText};
 use crate::NodeRef;
@@ -46,8 +45,8 @@ impl Reconcilable for VText {
         parent: &Element,
         next_sibling: NodeRef,
     ) -> (NodeRef, Self::Bundle) {
-        let Self { text } = self;
-        let text_node = document().create_text_node(&text);
+        let Self { text, .. } = self;
+        let text_node = owner_document(parent).create_text_node(&text);
         insert_node(&text_node, parent, next_sibling.get().as_ref());
         let node_ref = NodeRef::new(text_node.clone().into());
         (node_ref, BText { text, text_node })
@@ -76,7 +75,7 @@ impl Reconcilable for VText {
         _next_sibling: NodeRef,
         btext: &mut Self::Bundle,
     ) -> NodeRef {
-        let Self { text } = self;
+        let Self { text, .. } = self;
         let ancestor_text = std::mem::replace(&mut btext.text, text);
         if btext.text != ancestor_text {
             btext.text_node.set_node_value(Some(&btext.text));
@@ -110,26 +109,26 @@ mod feat_hydration {
             if let Some(m) = fragment.front().cloned() {
                 // better safe than sorry.
                 if m.node_type() == Node::TEXT_NODE {
-                    if let Ok(m) = m.dyn_into::<TextNode>() {
-                        // pop current node.
-                        fragment.pop_front();
-
-                        // TODO: It may make sense to assert the text content in the text node
-                        // against the VText when #[cfg(debug_assertions)]
-                        // is true, but this may be complicated.
-                        // We always replace the text value for now.
-                        //
-                        // Please see the next comment for a detailed explanation.
-                        m.set_node_value(Some(self.text.as_ref()));
-
-                        return (
-                            NodeRef::new(m.clone().into()),
-                            BText {
-                                text: self.text,
-                                text_node: m,
-                            },
-                        );
-                    }
+                    let m = m.unchecked_into::<TextNode>();
+                    // pop current node.
+                    fragment.pop_front();
+                    root.record_hydrated_node(false);
+
+                    // TODO: It may make sense to assert the text content in the text node
+                    // against the VText when #[cfg(debug_assertions)]
+                    // is true, but this may be complicated.
+                    // We always replace the text value for now.
+                    //
+                    // Please see the next comment for a detailed explanation.
+                    m.set_node_value(Some(self.text.as_ref()));
+
+                    return (
+                        NodeRef::new(m.clone().into()),
+                        BText {
+                            text: self.text,
+                            text_node: m,
+                        },
+                    );
                 }
             }
 
@@ -138,6 +137,7 @@ mod feat_hydration {
             // if the next node is not a text node. Similarly, the value of the text
             // node may be a combination of multiple VText vnodes. So we always need to
             // override their values.
+            root.record_hydrated_node(true);
             self.attach(
                 root,
                 parent_scope,
@@ -1,11 +1,11 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::ops::{Deref, DerefMut};
 
 use web_sys::{Element, Node};
 
 use crate::dom_bundle::BSubtree;
 use crate::html::NodeRef;
-use crate::virtual_dom::Collectable;
+use crate::virtual_dom::{Collectable, Key};
 
 /// A Hydration Fragment
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -121,6 +121,76 @@ impl Fragment {
         Self(nodes)
     }
 
+    /// Collects the nodes of consecutive keyed components by their keys.
+    ///
+    /// The fragment has to start with one component for each of `keys`, in any order. Otherwise,
+    /// `None` is returned and the fragment is left untouched. Text nodes between the components
+    /// are removed.
+    pub fn collect_keyed_components(
+        &mut self,
+        keys: &[Key],
+        parent: &Element,
+    ) -> Option<HashMap<Key, Fragment>> {
+        let collectable = Collectable::for_component::<()>();
+        let comment_text = |node: &Node| {
+            (node.node_type() == Node::COMMENT_NODE)
+                .then(|| node.text_content().unwrap_or_default())
+        };
+
+        let mut ranges = HashMap::with_capacity(keys.len());
+        let mut pos = 0;
+
+        while ranges.len() < keys.len() {
+            while self.get(pos)?.node_type() == Node::TEXT_NODE {
+                pos += 1;
+            }
+
+            let start = pos;
+            let key = Collectable::key_from_open_tag(&comment_text(self.get(pos)?)?)?;
+            pos += 1;
+
+            let mut nested_layers = 1;
+            while nested_layers > 0 {
+                if let Some(m) = comment_text(self.get(pos)?) {
+                    if m.starts_with(collectable.open_start_mark())
+                        && m.ends_with(collectable.end_mark())
+                    {
+                        nested_layers += 1;
+                    } else if m.starts_with(collectable.close_start_mark())
+                        && m.ends_with(collectable.end_mark())
+                    {
+                        nested_layers -= 1;
+                    }
+                }
+                pos += 1;
+            }
+
+            // A duplicate key cannot be matched to a single component.
+            if ranges.insert(key, start..pos).is_some() {
+                return None;
+            }
+        }
+
+        if !keys.iter().all(|m| ranges.contains_key(m)) {
+            return None;
+        }
+
+        let nodes = self.drain(..pos).collect::<Vec<_>>();
+        let mut fragments = HashMap::with_capacity(ranges.len());
+        let mut collected = vec![false; nodes.len()];
+
+        for (key, range) in ranges {
+            collected[range.clone()].fill(true);
+            fragments.insert(key, Self(nodes[range].iter().cloned().collect()));
+        }
+
+        for (node, _) in nodes.iter().zip(collected).filter(|(_, m)| !m) {
+            parent.remove_child(node).unwrap();
+        }
+
+        Some(fragments)
+    }
+
     /// Remove child nodes until first non-text node.
     pub fn trim_start_text_nodes(&mut self, parent: &Element) {
         while let Some(ref m) = self.front().cloned() {
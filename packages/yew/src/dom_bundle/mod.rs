@@ -34,7 +34,7 @@ use btext::BText;
 use subtree_root::EventDescriptor;
 pub use subtree_root::{set_event_bubbling, BSubtree};
 use traits::{Reconcilable, ReconcileTarget};
-use utils::{insert_node, test_log};
+use utils::{insert_node, owner_document, test_log};
 
 /// A Bundle.
 ///
@@ -73,6 +73,12 @@ impl Bundle {
     pub fn detach(self, root: &BSubtree, parent: &Element, parent_to_detach: bool) {
         self.0.detach(root, parent, parent_to_detach);
     }
+
+    /// Collects information about the scopes of the components in this bundle.
+    #[cfg(feature = "debug-tooling")]
+    pub fn collect_scope_infos(&self, infos: &mut Vec<crate::ScopeInfo>) {
+        self.0.collect_scope_infos(infos);
+    }
 }
 
 #[cfg(feature = "hydration")]
@@ -97,6 +103,19 @@ mod feat_hydration {
             let (node_ref, bundle) = node.hydrate(root, parent_scope, parent, fragment);
             (node_ref, Self(bundle))
         }
+
+        /// Creates a bundle that keeps the server-rendered nodes of a fragment as they are.
+        ///
+        /// The nodes are replaced when the bundle is reconciled for the first time.
+        pub fn adopt(fragment: Fragment, next_sibling: NodeRef) -> (NodeRef, Self) {
+            let node_ref = fragment
+                .front()
+                .cloned()
+                .map(NodeRef::new)
+                .unwrap_or(next_sibling);
+
+            (node_ref, Self(BNode::Fragment(fragment)))
+        }
     }
 }
 #[cfg(feature = "hydration")]
@@ -8,10 +8,14 @@ use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use gloo::events::{EventListener, EventListenerOptions, EventListenerPhase};
 use wasm_bindgen::prelude::wasm_bindgen;
-use wasm_bindgen::JsCast;
-use web_sys::{Element, Event, EventTarget as HtmlEventTarget, ShadowRoot};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, Event, EventTarget as HtmlEventTarget, Node, ShadowRoot};
 
 use super::{test_log, Registry};
+#[cfg(feature = "hydration")]
+use crate::html::HydrationData;
+#[cfg(feature = "hydration")]
+use crate::renderer::HydrationReport;
 use crate::virtual_dom::{Listener, ListenerKind};
 
 /// DOM-Types that capture (bubbling) events. This generally includes event targets,
@@ -191,6 +195,12 @@ impl Eq for WeakSubtree {}
 struct AppData {
     subtrees: HashSet<WeakSubtree>,
     listening: HashSet<EventDescriptor>,
+    /// Prepared states of components that have not been hydrated yet.
+    #[cfg(feature = "hydration")]
+    hydration_data: HydrationData,
+    /// Metrics of the hydration of the app, collected until they are taken.
+    #[cfg(feature = "hydration")]
+    hydration_report: HydrationReport,
 }
 
 impl AppData {
@@ -245,7 +255,15 @@ struct BrandingSearchResult {
 fn shadow_aware_parent(el: &Element) -> Option<Element> {
     match el.parent_element() {
         s @ Some(_) => s,
-        None => el.parent_node()?.dyn_ref::<ShadowRoot>().map(|h| h.host()),
+        None => {
+            // The parent is not checked with `dyn_ref`, which rejects the shadow roots created in
+            // another window, e.g.: an iframe the application is rendered into.
+            let parent = el.parent_node()?;
+            let is_shadow_root = parent.node_type() == Node::DOCUMENT_FRAGMENT_NODE
+                && js_sys::Reflect::has(&parent, &JsValue::from_str("host")).unwrap_or(false);
+
+            is_shadow_root.then(|| parent.unchecked_into::<ShadowRoot>().host())
+        }
     }
 }
 
@@ -369,7 +387,13 @@ impl SubtreeData {
         }
         // We're tasked with finding the subtree that is reponsible with handling the event, and/or
         // run the handling if that's `self`.
-        let target = event_path.get(0).dyn_into::<Element>().ok()?;
+        // The target is not checked with `dyn_into`, which rejects the elements created by the
+        // document of another window, e.g.: an iframe the application is rendered into.
+        let target = event_path.get(0).unchecked_into::<Node>();
+        if target.node_type() != Node::ELEMENT_NODE {
+            return None;
+        }
+        let target = target.unchecked_into::<Element>();
         let should_bubble = BUBBLE_EVENTS.load(Ordering::Relaxed) && event.bubbles();
         // We say that the most deeply nested subtree is "responsible" for handling the event.
         let (responsible_tree_id, bubbling_start) = if let Some(branding) = cached_branding {
@@ -490,4 +514,35 @@ impl BSubtree {
     pub fn brand_element(&self, el: &dyn EventGrating) {
         el.set_subtree_id(self.0.subtree_id);
     }
+
+    /// Sets the prepared states that are collected separately from the server-side rendered
+    /// HTML of the app.
+    #[cfg(feature = "hydration")]
+    pub fn set_hydration_data(&self, data: HydrationData) {
+        self.0.app_data.borrow_mut().hydration_data = data;
+    }
+
+    /// Takes the prepared state with the given id out of the hydration data of the app.
+    #[cfg(feature = "hydration")]
+    pub fn take_hydration_data(&self, id: usize) -> Option<String> {
+        self.0.app_data.borrow_mut().hydration_data.take(id)
+    }
+
+    /// Records that a node was hydrated, or recreated if the server-side rendered result did not
+    /// contain it.
+    #[cfg(feature = "hydration")]
+    pub fn record_hydrated_node(&self, recreated: bool) {
+        let report = &mut self.0.app_data.borrow_mut().hydration_report;
+        if recreated {
+            report.recreated_nodes += 1;
+        } else {
+            report.hydrated_nodes += 1;
+        }
+    }
+
+    /// Takes the metrics of the hydration of the app collected so far.
+    #[cfg(feature = "hydration")]
+    pub fn take_hydration_report(&self) -> HydrationReport {
+        std::mem::take(&mut self.0.app_data.borrow_mut().hydration_report)
+    }
 }
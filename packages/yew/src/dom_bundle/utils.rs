@@ -1,4 +1,14 @@
-use web_sys::{Element, Node};
+use web_sys::{Document, Element, Node};
+
+/// Returns the document nodes are created with to be inserted into `parent`.
+///
+/// This is the document `parent` belongs to, which is not the document of the window if the
+/// application is rendered into an iframe.
+pub(super) fn owner_document(parent: &Element) -> Document {
+    parent
+        .owner_document()
+        .unwrap_or_else(gloo::utils::document)
+}
 
 /// Insert a concrete [Node] into the DOM
 pub(super) fn insert_node(node: &Node, parent: &Element, next_sibling: Option<&Node>) {
@@ -0,0 +1,101 @@
+//! Primitives of async function components.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::html::{Html, HtmlResult};
+use crate::suspense::{use_future_with_deps, Suspense};
+use crate::{function_component, html, Properties};
+
+/// Trait that allows a struct to act as an async Function Component.
+///
+/// The component renders a [`Suspense`] with the [`fallback`](Self::fallback) until the future
+/// returned by [`run`](Self::run) resolves, and the output of the future afterwards. When the
+/// properties change, the fallback is shown again until the future for the new properties
+/// resolves.
+///
+/// # Note
+///
+/// Async Function Components should not implement this trait directly.
+///
+/// Use the `#[function_component]` macro on an `async fn` instead.
+pub trait AsyncFunctionProvider: 'static {
+    /// Properties for the Function Component.
+    ///
+    /// The properties are cloned into the future, so they have to implement [`Clone`].
+    type Properties: Properties + PartialEq + Clone;
+
+    /// Renders the fallback that is shown while the future is pending.
+    fn fallback() -> Html;
+
+    /// Returns the future that renders the component for `props`.
+    fn run(props: Rc<Self::Properties>) -> Pin<Box<dyn Future<Output = Html>>>;
+}
+
+/// Renders an async function component in a suspense with its fallback.
+#[doc(hidden)]
+pub fn render_async_component<COMP>(props: &COMP::Properties) -> Html
+where
+    COMP: AsyncFunctionProvider,
+{
+    html! {
+        <Suspense fallback={COMP::fallback()}>
+            <AsyncBody<COMP> ..props.clone() />
+        </Suspense>
+    }
+}
+
+/// Awaits the future of an async function component, suspending while it is pending.
+#[function_component]
+fn AsyncBody<COMP>(props: &COMP::Properties) -> HtmlResult
+where
+    COMP: AsyncFunctionProvider,
+{
+    let html = use_future_with_deps(COMP::run, props.clone())?;
+
+    Ok((*html).clone())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::platform::time::sleep;
+    use crate::prelude::*;
+    use crate::LocalServerRenderer;
+
+    #[derive(Properties, PartialEq, Clone)]
+    struct Props {
+        name: String,
+    }
+
+    #[function_component(fallback = html! { {"loading"} })]
+    async fn Greeting(props: &Props) -> Html {
+        sleep(std::time::Duration::from_millis(10)).await;
+
+        html! { <p>{"Hello, "}{&props.name}</p> }
+    }
+
+    #[test]
+    async fn test_async_component() {
+        #[function_component]
+        fn App() -> Html {
+            html! { <div><Greeting name="Yew" /></div> }
+        }
+
+        let local = tokio::task::LocalSet::new();
+        let s = local
+            .run_until(async move {
+                LocalServerRenderer::<App>::new()
+                    .hydratable(false)
+                    .render()
+                    .await
+            })
+            .await;
+
+        assert_eq!(s, "<div><p>Hello, Yew</p></div>");
+    }
+}
@@ -18,6 +18,9 @@ impl fmt::Debug for UseForceUpdateHandle {
 
 impl UseForceUpdateHandle {
     /// Trigger an unconditional re-render of the associated function component
+    ///
+    /// The render is scheduled, so calling this multiple times before the scheduler runs results
+    /// in a single render. Calling this after the component is destroyed does nothing.
     pub fn force_update(&self) {
         (self.trigger)()
     }
@@ -71,6 +74,16 @@ mod feat_nightly {
 ///
 /// If the state of your component is not shared, you should need to use this hook.
 ///
+/// # Scheduling
+///
+/// [`UseForceUpdateHandle::force_update`] schedules a render of the component, the component is
+/// re-rendered even if none of its states changed. Calls made before the scheduler runs, e.g.:
+/// multiple calls in the same callback, are batched into one render. After the component is
+/// destroyed, calling it is a no-op, so the handle can be kept by an external store without being
+/// unsubscribed first.
+///
+/// On nightly Rust, the handle also implements `Fn()`.
+///
 /// # Example
 ///
 /// This example implements a silly, manually updated display of the current time. The component
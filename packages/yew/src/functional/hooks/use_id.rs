@@ -0,0 +1,50 @@
+use super::{Hook, HookContext};
+use crate::virtual_dom::AttrValue;
+
+/// This hook is used to generate an id that is unique within the application.
+///
+/// The id is derived from the position of the component in the component tree. It is the same
+/// for the server-side rendering and the client-side rendering of the same tree, so it can be
+/// used to associate elements with attributes such as `aria-labelledby` in applications that
+/// are hydrated. Within a component, ids are generated in the order in which the hook is called.
+///
+/// The id stays the same for the lifetime of the component.
+///
+/// Ids are only unique within the application they are generated by. When several applications or
+/// islands are rendered into the same page, each of them has to be given its own id prefix with
+/// [`Renderer::id_prefix`](crate::Renderer::id_prefix) or
+/// [`ServerRenderer::id_prefix`](crate::ServerRenderer::id_prefix), and the same prefix has to be
+/// used for the server-side rendering and the hydration of an application.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn LabelledInput() -> Html {
+///     let id = use_id();
+///
+///     html! {
+///         <>
+///             <label id={id.clone()}>{"Name"}</label>
+///             <input aria-labelledby={id} />
+///         </>
+///     }
+/// }
+/// ```
+pub fn use_id() -> impl Hook<Output = AttrValue> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = AttrValue;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let scope = ctx.scope.clone();
+
+            (*ctx.next_state(move |_| scope.next_id())).clone()
+        }
+    }
+
+    HookProvider
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+use std::ops::Deref;
+
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement, InputEvent};
+
+use super::{use_callback, use_state_eq, UseStateHandle};
+use crate::callback::Callback;
+use crate::functional::hook;
+use crate::html::TargetCast;
+use crate::virtual_dom::AttrValue;
+
+/// This hook is used to bind the value of a form control to the state of a function component.
+///
+/// The returned handle provides the current value for the `value` attribute and a callback for
+/// the `oninput` listener of an `<input>` or a `<textarea>`. The state is updated with every
+/// input, so the value of the control always reflects the state. The state can also be replaced
+/// with [`UseInputStateHandle::set`], which updates the value of the control on the next render.
+///
+/// When rendered on the server side, the initial value is rendered as the `value` attribute of
+/// an `<input>` or as the content of a `<textarea>`.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Greeting() -> Html {
+///     let name = use_input_state(|| "World");
+///
+///     html! {
+///         <div>
+///             <input value={name.value()} oninput={name.oninput()} />
+///             <p>{ "Hello, " }{ &*name }{ "!" }</p>
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_input_state<T, F>(init_fn: F) -> UseInputStateHandle
+where
+    T: Into<AttrValue>,
+    F: FnOnce() -> T,
+{
+    let value = use_state_eq(move || init_fn().into());
+    let oninput = use_callback(
+        |e: InputEvent, value| {
+            let target = e.target_unchecked_into::<web_sys::EventTarget>();
+            let next = match target.dyn_ref::<HtmlTextAreaElement>() {
+                Some(m) => m.value(),
+                None => target.unchecked_into::<HtmlInputElement>().value(),
+            };
+
+            value.set(next.into());
+        },
+        value.setter(),
+    );
+
+    UseInputStateHandle { value, oninput }
+}
+
+/// State handle for the [`use_input_state`] hook.
+pub struct UseInputStateHandle {
+    value: UseStateHandle<AttrValue>,
+    oninput: Callback<InputEvent>,
+}
+
+impl UseInputStateHandle {
+    /// Returns the current value, to be passed to the `value` attribute of the form control.
+    pub fn value(&self) -> AttrValue {
+        (*self.value).clone()
+    }
+
+    /// Returns the callback that updates the state, to be passed to the `oninput` listener of the
+    /// form control.
+    ///
+    /// The callback is guaranteed to be the same across the entire component lifecycle.
+    pub fn oninput(&self) -> Callback<InputEvent> {
+        self.oninput.clone()
+    }
+
+    /// Replaces the value.
+    pub fn set(&self, value: impl Into<AttrValue>) {
+        self.value.set(value.into())
+    }
+}
+
+impl Deref for UseInputStateHandle {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl fmt::Debug for UseInputStateHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseInputStateHandle")
+            .field("value", &*self.value)
+            .finish()
+    }
+}
+
+impl Clone for UseInputStateHandle {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            oninput: self.oninput.clone(),
+        }
+    }
+}
+
+impl PartialEq for UseInputStateHandle {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.value == rhs.value
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[test]
+    async fn test_initial_value() {
+        #[function_component]
+        fn Comp() -> Html {
+            let name = use_input_state(|| "World");
+            let notes = use_input_state(|| String::from("<notes>"));
+
+            html! {
+                <>
+                    <input value={name.value()} oninput={name.oninput()} />
+                    <textarea value={notes.value()} oninput={notes.oninput()}></textarea>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<input value="World"><textarea>&lt;notes&gt;</textarea>"#
+        );
+    }
+}
@@ -52,8 +52,6 @@ where
             let state = PreparedStateBase {
                 state: Some(state),
                 deps: Some(deps),
-                #[cfg(feature = "hydration")]
-                has_buf: true,
                 _marker: PhantomData,
             };
 
@@ -127,8 +125,6 @@ where
             let state = PreparedStateBase {
                 state: Some(state),
                 deps: Some(deps),
-                #[cfg(feature = "hydration")]
-                has_buf: true,
                 _marker: PhantomData,
             };
 
@@ -102,7 +102,6 @@ pub use yew_macro::use_prepared_state_without_closure as use_prepared_state_macr
 #[cfg(any(feature = "hydration", feature = "ssr"))]
 mod feat_any_hydration_ssr {
     use std::marker::PhantomData;
-    #[cfg(feature = "ssr")]
     use std::rc::Rc;
 
     use serde::de::DeserializeOwned;
@@ -115,12 +114,8 @@ mod feat_any_hydration_ssr {
         D: Serialize + DeserializeOwned + PartialEq + 'static,
         T: Serialize + DeserializeOwned + 'static,
     {
-        #[cfg(feature = "ssr")]
         pub state: Option<Rc<T>>,
-        #[cfg(feature = "ssr")]
         pub deps: Option<Rc<D>>,
-        #[cfg(feature = "hydration")]
-        pub has_buf: bool,
         pub _marker: PhantomData<(T, D)>,
     }
 
@@ -4,6 +4,9 @@ use std::marker::PhantomData;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
 use crate::functional::{hook, Hook, HookContext};
 
 type DispatchFn<T> = Rc<dyn Fn(<T as Reducible>::Action)>;
@@ -328,3 +331,180 @@ where
 {
     use_reducer_base(init_fn, T::ne)
 }
+
+/// [`use_reducer`] but the state is transferred from server-side rendering to hydration.
+///
+/// During server-side rendering, the state is serialized after the component is rendered and
+/// sent to the client side with the other prepared states of the component, see
+/// [`use_prepared_state`](macro@super::use_prepared_state). When the component is hydrated, the
+/// state is restored before its first render and `init_fn` is not called, so the component
+/// resumes with the state computed on the server side. Otherwise, the state is initialized with
+/// `init_fn`.
+///
+/// The state is restored only once, when the component is created. Subsequent renders keep the
+/// state of the component like [`use_reducer`]. States are matched by the order the hooks are
+/// called in the component, so the component has to call the same hooks on the server side and
+/// the client side.
+///
+/// Usually, this hook is not called directly, but by marking the result of [`use_reducer`] with
+/// `#[transfer]`:
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// # use serde::{Deserialize, Serialize};
+/// # use yew::prelude::*;
+/// #
+/// #[derive(Serialize, Deserialize)]
+/// struct Counter {
+///     value: u32,
+/// }
+///
+/// impl Reducible for Counter {
+///     type Action = ();
+///
+///     fn reduce(self: Rc<Self>, _action: ()) -> Rc<Self> {
+///         Self {
+///             value: self.value + 1,
+///         }
+///         .into()
+///     }
+/// }
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     #[transfer]
+///     let counter = use_reducer(|| Counter { value: 0 });
+///
+///     html! { <div>{ counter.value }</div> }
+/// }
+/// ```
+pub fn use_transferred_reducer<T, F>(init_fn: F) -> impl Hook<Output = UseReducerHandle<T>>
+where
+    T: Reducible + Serialize + DeserializeOwned + 'static,
+    F: FnOnce() -> T,
+{
+    struct HookProvider<T, F>
+    where
+        T: Reducible + Serialize + DeserializeOwned + 'static,
+        F: FnOnce() -> T,
+    {
+        _marker: PhantomData<T>,
+        init_fn: F,
+    }
+
+    impl<T, F> Hook for HookProvider<T, F>
+    where
+        T: Reducible + Serialize + DeserializeOwned + 'static,
+        F: FnOnce() -> T,
+    {
+        type Output = UseReducerHandle<T>;
+
+        #[cfg(not(any(feature = "hydration", feature = "ssr")))]
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            use_reducer(self.init_fn).run(ctx)
+        }
+
+        #[cfg(any(feature = "hydration", feature = "ssr"))]
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let init_fn = self.init_fn;
+            // The transferred state is created first, so it can initialize the reducer.
+            let state = ctx.next_prepared_state(|_re_render, buf| TransferredState::<T>::new(buf));
+
+            let handle = {
+                let state = state.clone();
+                use_reducer(move || state.take_restored().unwrap_or_else(init_fn)).run(ctx)
+            };
+
+            #[cfg(feature = "ssr")]
+            state.set_current(handle.value.clone());
+
+            handle
+        }
+    }
+
+    HookProvider {
+        _marker: PhantomData,
+        init_fn,
+    }
+}
+
+#[cfg(any(feature = "hydration", feature = "ssr"))]
+mod feat_any_hydration_ssr {
+    use std::cell::RefCell;
+    #[cfg(feature = "ssr")]
+    use std::rc::Rc;
+
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    use crate::functional::PreparedState;
+
+    /// The state of [`use_transferred_reducer`](super::use_transferred_reducer) that is sent from
+    /// the server side to the client side.
+    pub(super) struct TransferredState<T> {
+        #[cfg(feature = "ssr")]
+        current: RefCell<Option<Rc<T>>>,
+        #[cfg(feature = "hydration")]
+        restored: RefCell<Option<T>>,
+    }
+
+    impl<T> TransferredState<T>
+    where
+        T: DeserializeOwned,
+    {
+        pub fn new(_buf: Option<&str>) -> Self {
+            Self {
+                #[cfg(feature = "ssr")]
+                current: RefCell::default(),
+                #[cfg(feature = "hydration")]
+                restored: {
+                    use base64ct::{Base64, Encoding};
+
+                    let state = _buf.and_then(|buf| {
+                        let buf = Base64::decode_vec(buf).expect("failed to deserialize state");
+                        bincode::deserialize::<Option<T>>(&buf)
+                            .expect("failed to deserialize state")
+                    });
+
+                    RefCell::new(state)
+                },
+            }
+        }
+
+        /// Takes the state that was restored from the server side.
+        pub fn take_restored(&self) -> Option<T> {
+            #[cfg(feature = "hydration")]
+            {
+                self.restored.borrow_mut().take()
+            }
+            #[cfg(not(feature = "hydration"))]
+            {
+                None
+            }
+        }
+
+        /// Sets the state that is sent to the client side.
+        #[cfg(feature = "ssr")]
+        pub fn set_current(&self, state: Rc<T>) {
+            *self.current.borrow_mut() = Some(state);
+        }
+    }
+
+    impl<T> PreparedState for TransferredState<T>
+    where
+        T: Serialize,
+    {
+        #[cfg(feature = "ssr")]
+        fn prepare(&self) -> String {
+            use base64ct::{Base64, Encoding};
+
+            let state = bincode::serialize(&self.current.borrow().as_deref())
+                .expect("failed to prepare state");
+
+            Base64::encode_string(&state)
+        }
+    }
+}
+
+#[cfg(any(feature = "hydration", feature = "ssr"))]
+use feat_any_hydration_ssr::TransferredState;
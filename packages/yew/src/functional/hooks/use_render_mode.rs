@@ -0,0 +1,105 @@
+use std::cell::Cell;
+
+use super::{Hook, HookContext};
+use crate::functional::{Effect, ReRender};
+
+/// The environment a component is rendered in, see [`use_render_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    /// The component is rendered on the server side.
+    Server,
+    /// The component is hydrated from a server-side rendered result.
+    ///
+    /// The component renders in this mode until hydration is committed to the DOM.
+    Hydrating,
+    /// The component is rendered on the client side.
+    Client,
+}
+
+struct UseRenderMode {
+    hydrating: Cell<bool>,
+    re_render: ReRender,
+}
+
+impl Effect for UseRenderMode {
+    fn rendered(&self) {
+        // render again once hydrated, so the component can render its client side content.
+        if self.hydrating.replace(false) {
+            (self.re_render)();
+        }
+    }
+}
+
+/// Returns the mode the component was created in.
+pub(super) fn creation_mode(_ctx: &HookContext) -> RenderMode {
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
+    {
+        use crate::html::RenderMode as CreationMode;
+
+        match _ctx.creation_mode {
+            #[cfg(feature = "hydration")]
+            CreationMode::Hydration => RenderMode::Hydrating,
+            #[cfg(feature = "csr")]
+            CreationMode::Render => RenderMode::Client,
+            #[cfg(feature = "ssr")]
+            CreationMode::Ssr => RenderMode::Server,
+        }
+    }
+
+    #[cfg(not(any(feature = "hydration", feature = "ssr")))]
+    RenderMode::Client
+}
+
+/// This hook is used to find out whether a component is rendered on the server side, hydrated or
+/// rendered on the client side.
+///
+/// A component that is hydrated renders in [`RenderMode::Hydrating`] until hydration is committed
+/// to the DOM, and is then rendered again in [`RenderMode::Client`]. As hydration expects the same
+/// content that was rendered on the server side, render the same content for
+/// [`RenderMode::Server`] and [`RenderMode::Hydrating`], e.g.: to only access Web APIs once the
+/// component is rendered in [`RenderMode::Client`].
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Greeting() -> Html {
+///     let mode = use_render_mode();
+///
+///     html! {
+///         <div>
+///         {
+///             if mode == RenderMode::Client {
+///                 "Hello from the browser!"
+///             } else {
+///                 "Hello!"
+///             }
+///         }
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_render_mode() -> impl Hook<Output = RenderMode> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = RenderMode;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let mode = creation_mode(ctx);
+            let state = ctx.next_effect(move |re_render| UseRenderMode {
+                hydrating: Cell::new(mode == RenderMode::Hydrating),
+                re_render,
+            });
+
+            match mode {
+                RenderMode::Hydrating if !state.hydrating.get() => RenderMode::Client,
+                mode => mode,
+            }
+        }
+    }
+
+    HookProvider
+}
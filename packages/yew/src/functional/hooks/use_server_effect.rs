@@ -0,0 +1,58 @@
+use super::{Hook, HookContext};
+
+/// This hook is used to run a side effect during server-side rendering.
+///
+/// The callback is called once, when the component is rendered for the first time on the server
+/// side. It runs before the rendered result of the component is written, so it can be used to
+/// e.g.: log requests or populate a cache that is read while rendering. The callback is never
+/// called when the component is rendered or hydrated on the client side.
+///
+/// Effects created with [`use_effect`](super::use_effect) are the client side counterpart, they
+/// are never run during server-side rendering.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Page() -> Html {
+///     use_server_effect(|| {
+///         println!("rendering page");
+///     });
+///
+///     html! { <div>{"Hello!"}</div> }
+/// }
+/// ```
+pub fn use_server_effect<F>(f: F) -> impl Hook<Output = ()>
+where
+    F: 'static + FnOnce(),
+{
+    struct HookProvider<F> {
+        f: F,
+    }
+
+    impl<F> Hook for HookProvider<F>
+    where
+        F: 'static + FnOnce(),
+    {
+        type Output = ();
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let Self { f } = self;
+
+            #[cfg(feature = "ssr")]
+            let is_ssr = ctx.creation_mode == crate::html::RenderMode::Ssr;
+            #[cfg(not(feature = "ssr"))]
+            let is_ssr = false;
+
+            ctx.next_state(move |_| {
+                if is_ssr {
+                    f();
+                }
+            });
+        }
+    }
+
+    HookProvider { f }
+}
@@ -2,9 +2,17 @@ use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
-use super::{use_reducer, use_reducer_eq, Reducible, UseReducerDispatcher, UseReducerHandle};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    use_reducer, use_reducer_eq, use_transferred_reducer, Reducible, UseReducerDispatcher,
+    UseReducerHandle,
+};
 use crate::functional::hook;
 
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
 struct UseStateReducer<T> {
     value: T,
 }
@@ -97,6 +105,36 @@ where
     UseStateHandle { inner: handle }
 }
 
+/// [`use_state`] but the state is transferred from server-side rendering to hydration.
+///
+/// When the component is hydrated, it resumes with the state computed on the server side instead
+/// of calling `init_fn`. See [`use_transferred_reducer`] for more information.
+///
+/// Usually, this hook is not called directly, but by marking the result of [`use_state`] with
+/// `#[transfer]`:
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component]
+/// fn App() -> Html {
+///     #[transfer]
+///     let counter = use_state(|| 0);
+///
+///     html! { <div>{ *counter }</div> }
+/// }
+/// ```
+#[hook]
+pub fn use_transferred_state<T, F>(init_fn: F) -> UseStateHandle<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+    F: FnOnce() -> T,
+{
+    let handle = use_transferred_reducer(move || UseStateReducer { value: init_fn() });
+
+    UseStateHandle { inner: handle }
+}
+
 /// State handle for the [`use_state`] hook.
 pub struct UseStateHandle<T> {
     inner: UseReducerHandle<UseStateReducer<T>>,
@@ -0,0 +1,138 @@
+#[cfg(any(feature = "csr", feature = "ssr"))]
+use super::use_render_mode::creation_mode;
+#[cfg(any(feature = "csr", feature = "ssr"))]
+use super::RenderMode;
+use super::{Hook, HookContext};
+use crate::html::Style;
+
+/// This hook is used to scope a style sheet to a component.
+///
+/// The hook returns a [`Style`] with a class name derived from `css`, which can be used as the
+/// class of the elements of the component. Every `&` in `css` is replaced by the selector of the
+/// class name, a style sheet without `&` is a list of declarations that are applied to the
+/// elements with the class name. See [`Style`] for more information.
+///
+/// The style sheet is added to the page once, no matter how many components use it. On the server
+/// side, the style sheets are collected by
+/// [`LocalServerRenderer::render_with_styles`](crate::LocalServerRenderer::render_with_styles).
+/// On the client side, a `<style>` element is appended to the `<head>` of the document the
+/// component is rendered into, e.g.: the one of an iframe, unless it was rendered on the server
+/// side.
+///
+/// The style is created when the component is first rendered, changes to `css` afterwards are
+/// ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[function_component]
+/// fn Card() -> Html {
+///     let style = use_style("& { padding: 8px; } & > h2 { margin: 0; }");
+///
+///     html! {
+///         <div class={style}>
+///             <h2>{"Title"}</h2>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_style(css: &str) -> impl '_ + Hook<Output = Style> {
+    struct HookProvider<'a> {
+        css: &'a str,
+    }
+
+    impl Hook for HookProvider<'_> {
+        type Output = Style;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            let mode = creation_mode(ctx);
+            #[cfg(feature = "ssr")]
+            let styles = ctx.scope.style_registry().cloned();
+            #[cfg(feature = "csr")]
+            let document = ctx.document.clone();
+            let css = self.css;
+
+            let style = ctx.next_state(move |_| {
+                let style = Style::new(css);
+
+                #[cfg(feature = "ssr")]
+                if let (RenderMode::Server, Some(styles)) = (mode, styles) {
+                    styles.insert(&style);
+                }
+                #[cfg(feature = "csr")]
+                if mode != RenderMode::Server {
+                    if let Some(ref document) = document {
+                        feat_csr::inject(&style, document);
+                    }
+                }
+
+                style
+            });
+
+            (*style).clone()
+        }
+    }
+
+    HookProvider { css }
+}
+
+#[cfg(feature = "csr")]
+mod feat_csr {
+    use js_sys::{Set, WeakMap};
+    use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+    use web_sys::Document;
+
+    use crate::html::{Style, STYLE_ATTRIBUTE};
+
+    thread_local! {
+        // The class names of the injected styles by document. The documents are held weakly, so
+        // the ones of removed iframes can be collected.
+        static INJECTED: WeakMap = WeakMap::new();
+    }
+
+    /// Returns whether `class_name` was injected into `document` before and marks it as injected.
+    fn mark_injected(document: &Document, class_name: &str) -> bool {
+        INJECTED.with(|m| {
+            let class_names = match m.get(document).dyn_into::<Set>() {
+                Ok(class_names) => class_names,
+                Err(_) => {
+                    let class_names = Set::new(&JsValue::UNDEFINED);
+                    m.set(document, &class_names);
+                    class_names
+                }
+            };
+
+            let class_name = JsValue::from_str(class_name);
+            let injected = class_names.has(&class_name);
+            class_names.add(&class_name);
+
+            injected
+        })
+    }
+
+    /// Appends a `<style>` element for `style` to the `<head>` of `document`, unless it is already
+    /// present.
+    pub(super) fn inject(style: &Style, document: &Document) {
+        let class_name = style.class_name();
+        if mark_injected(document, class_name) {
+            return;
+        }
+
+        // the style may have been rendered on the server side.
+        let selector = format!(r#"style[{}="{}"]"#, STYLE_ATTRIBUTE, class_name);
+        if let Ok(Some(_)) = document.query_selector(&selector) {
+            return;
+        }
+
+        let element = document.create_element("style").unwrap_throw();
+        element
+            .set_attribute(STYLE_ATTRIBUTE, class_name)
+            .unwrap_throw();
+        element.set_text_content(Some(style.css()));
+        let head = document.head().expect_throw("no head in document");
+        head.append_child(&element).unwrap_throw();
+    }
+}
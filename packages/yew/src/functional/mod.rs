@@ -27,12 +27,12 @@ use std::rc::Rc;
 
 use wasm_bindgen::prelude::*;
 
-#[cfg(all(feature = "hydration", feature = "ssr"))]
-use crate::html::RenderMode;
 use crate::html::{AnyScope, BaseComponent, Context, HtmlResult};
 use crate::Properties;
 
+mod async_component;
 mod hooks;
+pub use async_component::*;
 pub use hooks::*;
 /// This attribute creates a function component from a normal Rust function.
 ///
@@ -60,6 +60,37 @@ pub use hooks::*;
 ///     }
 /// }
 /// ```
+///
+/// # Async Function Components
+///
+/// The attribute can also be applied to an `async fn`. The component is wrapped in a
+/// [`Suspense`](crate::suspense::Suspense) that shows a fallback until the function returns,
+/// the fallback is specified with `fallback = <expr>` and defaults to empty
+/// [`Html`](crate::Html). During server-side rendering, the function is awaited before the
+/// result is rendered.
+///
+/// The function is called again with a clone of the new properties whenever they change, so
+/// they have to implement [`Clone`]. Hooks can't be used in async function components, see
+/// [`AsyncFunctionProvider`].
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// # async fn fetch_name(id: u32) -> String {
+/// #     id.to_string()
+/// # }
+/// #
+/// #[derive(Properties, Clone, PartialEq)]
+/// pub struct Props {
+///     id: u32,
+/// }
+///
+/// #[function_component(fallback = html! { <p>{ "Loading..." }</p> })]
+/// async fn UserName(props: &Props) -> Html {
+///     let name = fetch_name(props.id).await;
+///
+///     html! { <p>{ name }</p> }
+/// }
+/// ```
 pub use yew_macro::function_component;
 /// This attribute creates a user-defined hook from a normal Rust function.
 pub use yew_macro::hook;
@@ -81,8 +112,10 @@ pub(crate) trait Effect {
 /// A hook context to be passed to hooks.
 pub struct HookContext {
     pub(crate) scope: AnyScope,
-    #[cfg(all(feature = "hydration", feature = "ssr"))]
-    creation_mode: RenderMode,
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
+    creation_mode: crate::html::RenderMode,
+    #[cfg(feature = "csr")]
+    pub(crate) document: Option<web_sys::Document>,
     re_render: ReRender,
 
     states: Vec<Rc<dyn Any>>,
@@ -105,15 +138,18 @@ impl HookContext {
     fn new(
         scope: AnyScope,
         re_render: ReRender,
-        #[cfg(all(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
+        #[cfg(any(feature = "hydration", feature = "ssr"))] creation_mode: crate::html::RenderMode,
+        #[cfg(feature = "csr")] document: Option<web_sys::Document>,
         #[cfg(feature = "hydration")] prepared_state: Option<&str>,
     ) -> RefCell<Self> {
         RefCell::new(HookContext {
             scope,
             re_render,
 
-            #[cfg(all(feature = "hydration", feature = "ssr"))]
+            #[cfg(any(feature = "hydration", feature = "ssr"))]
             creation_mode,
+            #[cfg(feature = "csr")]
+            document,
 
             states: Vec::new(),
 
@@ -341,8 +377,10 @@ where
             hook_ctx: HookContext::new(
                 scope,
                 re_render,
-                #[cfg(all(feature = "hydration", feature = "ssr"))]
+                #[cfg(any(feature = "hydration", feature = "ssr"))]
                 ctx.creation_mode(),
+                #[cfg(feature = "csr")]
+                ctx.document().cloned(),
                 #[cfg(feature = "hydration")]
                 ctx.prepared_state(),
             ),
@@ -10,6 +10,9 @@ use crate::virtual_dom::AttrValue;
 /// A set of classes.
 ///
 /// The preferred way of creating this is using the [`classes!`][yew::classes!] macro.
+///
+/// Classes are kept in the order they are first added. Adding a class that is already in the set
+/// has no effect, so `classes!("a", Some("b"), "a")` is rendered as `class="a b"`.
 #[derive(Debug, Clone, Default)]
 pub struct Classes {
     set: IndexSet<Cow<'static, str>>,
@@ -239,6 +242,7 @@ impl Eq for Classes {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::classes;
 
     struct TestClass;
 
@@ -330,4 +334,13 @@ mod tests {
         let subject = Classes::from(classes);
         assert!(subject.is_empty())
     }
+
+    #[test]
+    fn keeps_first_occurrence() {
+        let subject = classes!("a", Some("b"), "a", String::from("c b"), None::<&str>);
+        assert_eq!(subject.to_string(), "a b c");
+
+        let value: AttrValue = subject.into_prop_value();
+        assert_eq!(&*value, "a b c");
+    }
 }
@@ -1,9 +1,10 @@
 //! Component children module
 
 use std::fmt;
+use std::rc::Rc;
 
-use crate::html::Html;
-use crate::virtual_dom::{VChild, VNode};
+use crate::html::{BaseComponent, Html};
+use crate::virtual_dom::{VChild, VList, VNode, VText};
 use crate::Properties;
 
 /// A type used for accepting children elements in Component::Properties.
@@ -211,6 +212,248 @@ impl<T> IntoIterator for ChildrenRenderer<T> {
     }
 }
 
+/// A type used for accepting children elements that are only built when they are rendered.
+///
+/// Closures passed as children, e.g.: `{ || html! { <Expensive /> } }`, are only called when the
+/// component places the child into its rendered result. A component that renders some of its
+/// children, e.g.: the active tab of a tab panel, does not pay for building the others. Elements,
+/// text and components passed as children are built eagerly as usual.
+///
+/// As the closures are stored in the properties, they have to be `'static` and must not borrow
+/// from the rendering component, clone the values they need instead. A closure is called every
+/// time the child is rendered. Closures are compared by identity, so a component receiving lazy
+/// children re-renders whenever its parent re-renders.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::html::LazyChildren;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct TabsProps {
+///     active: usize,
+///     #[prop_or_default]
+///     children: LazyChildren,
+/// }
+///
+/// #[function_component]
+/// fn Tabs(props: &TabsProps) -> Html {
+///     html! { <div class="tab">{props.children.iter().nth(props.active)}</div> }
+/// }
+///
+/// # fn view() -> Html {
+/// html! {
+///     <Tabs active={0}>
+///         { || html! { <p>{"First"}</p> } }
+///         { || html! { <p>{"Second, never built"}</p> } }
+///     </Tabs>
+/// }
+/// # }
+/// ```
+pub type LazyChildren = ChildrenRenderer<LazyChild>;
+
+/// A child of [`LazyChildren`], which is built when it is converted into [`Html`].
+#[derive(Clone)]
+pub struct LazyChild {
+    inner: LazyChildInner,
+}
+
+#[derive(Clone)]
+enum LazyChildInner {
+    Node(Html),
+    Lazy(Rc<dyn Fn() -> Html>),
+}
+
+impl LazyChild {
+    /// Creates a child that is built by `f` when it is rendered.
+    pub fn new(f: impl 'static + Fn() -> Html) -> Self {
+        Self {
+            inner: LazyChildInner::Lazy(Rc::new(f)),
+        }
+    }
+
+    /// Builds the child.
+    pub fn render(&self) -> Html {
+        match &self.inner {
+            LazyChildInner::Node(node) => node.clone(),
+            LazyChildInner::Lazy(f) => f(),
+        }
+    }
+}
+
+impl PartialEq for LazyChild {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.inner, &other.inner) {
+            (LazyChildInner::Node(lhs), LazyChildInner::Node(rhs)) => lhs == rhs,
+            (LazyChildInner::Lazy(lhs), LazyChildInner::Lazy(rhs)) => Rc::ptr_eq(lhs, rhs),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for LazyChild {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LazyChild<_>")
+    }
+}
+
+impl<F> From<F> for LazyChild
+where
+    F: 'static + Fn() -> Html,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+impl From<Html> for LazyChild {
+    fn from(node: Html) -> Self {
+        Self {
+            inner: LazyChildInner::Node(node),
+        }
+    }
+}
+
+impl From<VText> for LazyChild {
+    fn from(text: VText) -> Self {
+        VNode::from(text).into()
+    }
+}
+
+impl<COMP: BaseComponent> From<VChild<COMP>> for LazyChild {
+    fn from(child: VChild<COMP>) -> Self {
+        VNode::from(child).into()
+    }
+}
+
+impl From<LazyChild> for Html {
+    fn from(child: LazyChild) -> Self {
+        child.render()
+    }
+}
+
+/// A type used for accepting children elements grouped into named slots.
+///
+/// Children are assigned to a slot by their `slot` attribute, e.g.: `<h1 slot="header" />`.
+/// Children without a `slot` attribute, including text, components and fragments, are assigned
+/// to the default slot. The order of children within a slot is the order in which they were
+/// passed to the component. The `slot` attribute is kept on the rendered element.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::html::ChildrenWithSlots;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct LayoutProps {
+///     #[prop_or_default]
+///     children: ChildrenWithSlots,
+/// }
+///
+/// #[function_component]
+/// fn Layout(props: &LayoutProps) -> Html {
+///     html! {
+///         <>
+///             <header>{props.children.slot("header")}</header>
+///             <main>{props.children.default_slot()}</main>
+///             <footer>{props.children.slot("footer")}</footer>
+///         </>
+///     }
+/// }
+///
+/// # fn view() -> Html {
+/// html! {
+///     <Layout>
+///         <p>{"Content"}</p>
+///         <span slot="footer">{"Footer"}</span>
+///         <h1 slot="header">{"Title"}</h1>
+///     </Layout>
+/// }
+/// # }
+/// ```
+#[derive(Clone, Default, PartialEq)]
+pub struct ChildrenWithSlots {
+    children: Vec<Html>,
+}
+
+impl ChildrenWithSlots {
+    /// Create children
+    pub fn new(children: Vec<Html>) -> Self {
+        Self { children }
+    }
+
+    /// Children list is empty
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Number of children elements
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Render all children regardless of their slot and return `Iterator`
+    pub fn iter(&self) -> impl Iterator<Item = Html> + '_ {
+        self.children.iter().cloned()
+    }
+
+    /// Returns `true` if at least one child is assigned to the slot `name`.
+    pub fn has_slot(&self, name: &str) -> bool {
+        self.children
+            .iter()
+            .any(|child| Self::slot_of(child) == Some(name))
+    }
+
+    /// Render the children assigned to the slot `name`.
+    pub fn slot(&self, name: &str) -> Html {
+        self.collect(|slot| slot == Some(name))
+    }
+
+    /// Render the children without a `slot` attribute.
+    pub fn default_slot(&self) -> Html {
+        self.collect(|slot| slot.is_none())
+    }
+
+    fn collect(&self, mut f: impl FnMut(Option<&str>) -> bool) -> Html {
+        VNode::VList(VList::with_children(
+            self.children
+                .iter()
+                .filter(|child| f(Self::slot_of(child)))
+                .cloned()
+                .collect(),
+            None,
+        ))
+    }
+
+    fn slot_of(child: &Html) -> Option<&str> {
+        match child {
+            VNode::VTag(tag) => tag
+                .attributes
+                .iter()
+                .find(|(key, _)| *key == "slot")
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for ChildrenWithSlots {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ChildrenWithSlots")
+    }
+}
+
+impl IntoIterator for ChildrenWithSlots {
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type Item = Html;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.into_iter()
+    }
+}
+
 /// A [Properties] type with Children being the only property.
 #[derive(Debug, Properties, PartialEq)]
 pub struct ChildrenProps {
@@ -218,3 +461,126 @@ pub struct ChildrenProps {
     #[prop_or_default]
     pub children: Children,
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[test]
+    async fn test_lazy_children() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static BUILT: [AtomicUsize; 2] = [AtomicUsize::new(0), AtomicUsize::new(0)];
+
+        #[derive(Properties, PartialEq)]
+        struct TabsProps {
+            active: usize,
+            #[prop_or_default]
+            children: LazyChildren,
+        }
+
+        #[function_component]
+        fn Tabs(props: &TabsProps) -> Html {
+            html! {
+                <div>
+                    <nav>{props.children.len()}</nav>
+                    {props.children.iter().nth(props.active)}
+                </div>
+            }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Tabs active={1}>
+                    { || {
+                        BUILT[0].fetch_add(1, Ordering::SeqCst);
+                        html! { <p>{"hidden"}</p> }
+                    } }
+                    { || {
+                        BUILT[1].fetch_add(1, Ordering::SeqCst);
+                        html! { <p>{"active"}</p> }
+                    } }
+                    <p>{"eager"}</p>
+                </Tabs>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div><nav>3</nav><p>active</p></div>");
+        assert_eq!(BUILT[0].load(Ordering::SeqCst), 0);
+        assert_eq!(BUILT[1].load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    async fn test_children_with_slots() {
+        #[derive(Properties, PartialEq)]
+        struct LayoutProps {
+            #[prop_or_default]
+            children: ChildrenWithSlots,
+        }
+
+        #[function_component]
+        fn Layout(props: &LayoutProps) -> Html {
+            html! {
+                <div>
+                    <header>{props.children.slot("header")}</header>
+                    <main>{props.children.default_slot()}</main>
+                    if props.children.has_slot("footer") {
+                        <footer>{props.children.slot("footer")}</footer>
+                    }
+                </div>
+            }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Layout>
+                    <p>{"first"}</p>
+                    <span slot="footer">{"footer"}</span>
+                    <h1 slot="header">{"title"}</h1>
+                    {"text"}
+                    <h2 slot="header">{"subtitle"}</h2>
+                    <p>{"second"}</p>
+                </Layout>
+            }
+        }
+
+        #[function_component]
+        fn NoFooter() -> Html {
+            html! {
+                <Layout>
+                    <p>{"content"}</p>
+                </Layout>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div><header><h1 slot="header">title</h1><h2 slot="header">subtitle</h2></header><main><p>first</p>text<p>second</p></main><footer><span slot="footer">footer</span></footer></div>"#
+        );
+
+        let s = ServerRenderer::<NoFooter>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div><header></header><main><p>content</p></main></div>");
+    }
+}
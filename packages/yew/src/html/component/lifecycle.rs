@@ -1,6 +1,10 @@
 //! Component lifecycle module
 
 use std::any::Any;
+#[cfg(feature = "hydration")]
+use std::any::TypeId;
+#[cfg(panic = "unwind")]
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 
 #[cfg(feature = "csr")]
@@ -12,10 +16,14 @@ use super::BaseComponent;
 use crate::dom_bundle::Fragment;
 #[cfg(feature = "csr")]
 use crate::dom_bundle::{BSubtree, Bundle};
+#[cfg(panic = "unwind")]
+use crate::html::ErrorBoundary;
 #[cfg(feature = "csr")]
 use crate::html::NodeRef;
-#[cfg(feature = "hydration")]
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 use crate::html::RenderMode;
+#[cfg(feature = "hydration")]
+use crate::html::Static;
 use crate::html::{Html, RenderError};
 use crate::scheduler::{self, Runnable, Shared};
 use crate::suspense::{BaseSuspense, Suspension};
@@ -204,7 +212,8 @@ where
             _ => return false,
         };
 
-        if self.context.props != props {
+        // Properties shared with the previous render are unchanged, they are not compared.
+        if !Rc::ptr_eq(&self.context.props, &props) && self.context.props != props {
             let old_props = std::mem::replace(&mut self.context.props, props);
             self.component.changed(&self.context, &old_props)
         } else {
@@ -228,8 +237,16 @@ pub(crate) struct ComponentState {
 
     #[cfg(feature = "csr")]
     has_rendered: bool,
+    #[cfg(feature = "csr")]
+    on_first_rendered: Option<Callback<()>>,
     #[cfg(feature = "hydration")]
     pending_props: Option<Rc<dyn Any>>,
+    /// Whether this is a [`Static`](crate::html::Static) component.
+    #[cfg(feature = "hydration")]
+    is_static: bool,
+    /// The nearest error boundary of the component.
+    #[cfg(panic = "unwind")]
+    error_boundary: Option<Scope<ErrorBoundary>>,
 
     suspension: Option<Suspension>,
 
@@ -247,24 +264,44 @@ impl ComponentState {
         initial_render_state: ComponentRenderState,
         scope: Scope<COMP>,
         props: Rc<COMP::Properties>,
+        #[cfg(feature = "csr")] on_first_rendered: Option<Callback<()>>,
         #[cfg(feature = "hydration")] prepared_state: Option<String>,
     ) -> Self {
         let comp_id = scope.id;
-        #[cfg(feature = "hydration")]
+        #[cfg(any(feature = "hydration", feature = "ssr"))]
         let creation_mode = {
             match initial_render_state {
+                #[cfg(feature = "csr")]
                 ComponentRenderState::Render { .. } => RenderMode::Render,
+                #[cfg(feature = "hydration")]
                 ComponentRenderState::Hydration { .. } => RenderMode::Hydration,
                 #[cfg(feature = "ssr")]
                 ComponentRenderState::Ssr { .. } => RenderMode::Ssr,
             }
         };
 
+        // Nodes are created by the document of the parent, see `owner_document`.
+        #[cfg(feature = "csr")]
+        let document = match initial_render_state {
+            ComponentRenderState::Render { ref parent, .. } => parent.owner_document(),
+            #[cfg(feature = "hydration")]
+            ComponentRenderState::Hydration { ref parent, .. } => parent.owner_document(),
+            #[cfg(feature = "ssr")]
+            ComponentRenderState::Ssr { .. } => None,
+        };
+
+        #[cfg(panic = "unwind")]
+        let error_boundary = scope
+            .get_parent()
+            .and_then(|m| m.find_parent_scope::<ErrorBoundary>());
+
         let context = Context {
             scope,
             props,
-            #[cfg(feature = "hydration")]
+            #[cfg(any(feature = "hydration", feature = "ssr"))]
             creation_mode,
+            #[cfg(feature = "csr")]
+            document,
             #[cfg(feature = "hydration")]
             prepared_state,
         };
@@ -281,8 +318,14 @@ impl ComponentState {
 
             #[cfg(feature = "csr")]
             has_rendered: false,
+            #[cfg(feature = "csr")]
+            on_first_rendered,
             #[cfg(feature = "hydration")]
             pending_props: None,
+            #[cfg(feature = "hydration")]
+            is_static: TypeId::of::<COMP>() == TypeId::of::<Static>(),
+            #[cfg(panic = "unwind")]
+            error_boundary,
 
             comp_id,
         }
@@ -298,6 +341,26 @@ impl ComponentState {
             .map(|m| &m.component)
     }
 
+    /// Runs a lifecycle method of the component.
+    ///
+    /// If the component has an error boundary, a panic of the lifecycle method is caught and the
+    /// error boundary is notified to render its fallback. Returns `None` if a panic was caught.
+    fn run_lifecycle<T>(&mut self, f: impl FnOnce(&mut dyn Stateful) -> T) -> Option<T> {
+        #[cfg(panic = "unwind")]
+        if let Some(ref error_boundary) = self.error_boundary {
+            let inner = &mut self.inner;
+            return match panic::catch_unwind(AssertUnwindSafe(|| f(inner.as_mut()))) {
+                Ok(m) => Some(m),
+                Err(_) => {
+                    error_boundary.send_message(());
+                    None
+                }
+            };
+        }
+
+        Some(f(self.inner.as_mut()))
+    }
+
     fn resume_existing_suspension(&mut self) {
         if let Some(m) = self.suspension.take() {
             let comp_scope = self.inner.any_scope();
@@ -312,6 +375,9 @@ pub(crate) struct CreateRunner<COMP: BaseComponent> {
     pub initial_render_state: ComponentRenderState,
     pub props: Rc<COMP::Properties>,
     pub scope: Scope<COMP>,
+    /// Notified once after the first `rendered` lifecycle of the component.
+    #[cfg(feature = "csr")]
+    pub on_first_rendered: Option<Callback<()>>,
     #[cfg(feature = "hydration")]
     pub prepared_state: Option<String>,
 }
@@ -324,6 +390,8 @@ impl<COMP: BaseComponent> Runnable for CreateRunner<COMP> {
                 self.initial_render_state,
                 self.scope.clone(),
                 self.props,
+                #[cfg(feature = "csr")]
+                self.on_first_rendered,
                 #[cfg(feature = "hydration")]
                 self.prepared_state,
             ));
@@ -342,7 +410,7 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn update(&mut self) -> bool {
-        let schedule_render = self.inner.flush_messages();
+        let schedule_render = self.run_lifecycle(|m| m.flush_messages()).unwrap_or(false);
         tracing::trace!(schedule_render);
         schedule_render
     }
@@ -435,9 +503,20 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn render(&mut self, shared_state: &Shared<Option<ComponentState>>) {
-        match self.inner.view() {
-            Ok(vnode) => self.commit_render(shared_state, vnode),
-            Err(RenderError::Suspended(susp)) => self.suspend(shared_state, susp),
+        match self.run_lifecycle(|m| m.view()) {
+            Some(Ok(vnode)) => self.commit_render(shared_state, vnode),
+            Some(Err(RenderError::Suspended(susp))) => self.suspend(shared_state, susp),
+            // The error boundary takes over, the last rendered result is kept until then. During
+            // server-side rendering, the component renders nothing, so the rendering of the error
+            // boundary can complete.
+            None => match self.render_state {
+                #[cfg(feature = "ssr")]
+                ComponentRenderState::Ssr { .. } => {
+                    self.commit_render(shared_state, Html::default());
+                }
+                #[cfg(feature = "csr")]
+                _ => {}
+            },
         };
     }
 
@@ -505,6 +584,8 @@ impl ComponentState {
                 #[cfg(feature = "hydration")]
                 next_sibling.debug_assert_not_trapped();
 
+                let hydrated_children = scope.position.children();
+
                 let new_node_ref =
                     bundle.reconcile(root, &scope, parent, next_sibling.clone(), new_root);
                 internal_ref.link(new_node_ref);
@@ -512,6 +593,12 @@ impl ComponentState {
                 let first_render = !self.has_rendered;
                 self.has_rendered = true;
 
+                // Unless they have been hydrated, the children of the first render are created
+                // from the back.
+                if first_render && hydrated_children == 0 {
+                    scope.position.reverse_children();
+                }
+
                 scheduler::push_component_rendered(
                     self.comp_id,
                     Box::new(RenderedRunner {
@@ -522,6 +609,41 @@ impl ComponentState {
                 );
             }
 
+            // Static components adopt the server-rendered nodes instead of hydrating their
+            // children. The children are only rendered if the properties change afterwards.
+            #[cfg(feature = "hydration")]
+            ComponentRenderState::Hydration {
+                ref mut fragment,
+                ref parent,
+                ref internal_ref,
+                ref next_sibling,
+                ref root,
+            } if self.is_static => {
+                let _ = new_root;
+
+                let fragment = std::mem::take(fragment);
+                let (node, bundle) = Bundle::adopt(fragment, next_sibling.clone());
+                internal_ref.link(node);
+
+                self.has_rendered = true;
+                self.render_state = ComponentRenderState::Render {
+                    root: root.clone(),
+                    bundle,
+                    parent: parent.clone(),
+                    internal_ref: internal_ref.clone(),
+                    next_sibling: next_sibling.clone(),
+                };
+
+                scheduler::push_component_rendered(
+                    self.comp_id,
+                    Box::new(RenderedRunner {
+                        state: shared_state.clone(),
+                        first_render: true,
+                    }),
+                    true,
+                );
+            }
+
             #[cfg(feature = "hydration")]
             ComponentRenderState::Hydration {
                 ref mut fragment,
@@ -632,7 +754,9 @@ mod feat_csr {
             }
 
             let should_render = |props: Option<Rc<dyn Any>>, state: &mut ComponentState| -> bool {
-                props.map(|m| state.inner.props_changed(m)).unwrap_or(false)
+                props
+                    .and_then(|m| state.run_lifecycle(|inner| inner.props_changed(m)))
+                    .unwrap_or(false)
             };
 
             #[cfg(feature = "hydration")]
@@ -642,7 +766,9 @@ mod feat_csr {
                         match state.has_rendered {
                             true => {
                                 state.pending_props = None;
-                                state.inner.props_changed(props)
+                                state
+                                    .run_lifecycle(|m| m.props_changed(props))
+                                    .unwrap_or(false)
                             }
                             false => {
                                 state.pending_props = Some(props);
@@ -715,7 +841,7 @@ mod feat_csr {
         )]
         fn rendered(&mut self, first_render: bool) -> bool {
             if self.suspension.is_none() {
-                self.inner.rendered(first_render);
+                self.run_lifecycle(|m| m.rendered(first_render));
             }
 
             #[cfg(feature = "hydration")]
@@ -731,6 +857,8 @@ mod feat_csr {
 
     impl Runnable for RenderedRunner {
         fn run(self: Box<Self>) {
+            let mut on_first_rendered = None;
+
             if let Some(state) = self.state.borrow_mut().as_mut() {
                 let has_pending_props = state.rendered(self.first_render);
 
@@ -741,6 +869,16 @@ mod feat_csr {
                         next_sibling: None,
                     }));
                 }
+
+                // A suspended component has not called its `rendered` lifecycle yet.
+                if state.suspension.is_none() {
+                    on_first_rendered = state.on_first_rendered.take();
+                }
+            }
+
+            // Emitted after the state is released, so the callback can access the component.
+            if let Some(m) = on_first_rendered {
+                m.emit(());
             }
         }
     }
@@ -889,6 +1027,7 @@ mod tests {
             NodeRef::default(),
             NodeRef::default(),
             Rc::new(props),
+            None,
         );
         crate::scheduler::start_now();
 
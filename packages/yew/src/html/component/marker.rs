@@ -1,7 +1,8 @@
 //! Primitive Components & Properties Types
 
-use crate::html::{BaseComponent, ChildrenProps, Html};
-use crate::{function_component, html};
+use crate::functional::{use_render_mode, RenderMode};
+use crate::html::{BaseComponent, Children, ChildrenProps, Html};
+use crate::{function_component, html, Properties};
 
 /// A Component to represent a component that does not exist in current implementation.
 ///
@@ -144,3 +145,33 @@ where
 {
     html! { <>{props.children.clone()}</> }
 }
+
+/// Properties of [`SsrFallback`].
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct SsrFallbackProps {
+    /// The content that is rendered on the server side.
+    pub fallback: Html,
+    /// The content that is rendered on the client side.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A Component that renders a fallback on the server side and its children on the client side.
+///
+/// This is usually not used directly, but through the `ssr_fallback` attribute of a component in
+/// the `html!` macro, e.g.: `<Chart ssr_fallback={html! { <p>{"Loading..."}</p> }} />`. The
+/// component is then wrapped in an `SsrFallback` with its key, so the hydration markers around
+/// it are the markers of `SsrFallback` instead of the markers of the component. Properties can't
+/// have a field named `ssr_fallback`, as it would be shadowed by the attribute.
+///
+/// When the server-side rendered result is hydrated, the fallback is hydrated first, as the
+/// client has to produce the same layout as the server. Once hydration is committed to the DOM,
+/// the fallback is replaced by the children. Applications that are rendered on the client side
+/// only render the children.
+#[function_component]
+pub fn SsrFallback(props: &SsrFallbackProps) -> Html {
+    match use_render_mode() {
+        RenderMode::Server | RenderMode::Hydrating => props.fallback.clone(),
+        RenderMode::Client => html! { <>{props.children.clone()}</> },
+    }
+}
@@ -18,10 +18,12 @@ pub use scope::{AnyScope, Scope, SendAsMessage};
 
 use super::{Html, HtmlResult, IntoHtmlResult};
 
-#[cfg(feature = "hydration")]
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum RenderMode {
+    #[cfg(feature = "hydration")]
     Hydration,
+    #[cfg(feature = "csr")]
     Render,
     #[cfg(feature = "ssr")]
     Ssr,
@@ -33,8 +35,11 @@ pub(crate) enum RenderMode {
 pub struct Context<COMP: BaseComponent> {
     scope: Scope<COMP>,
     props: Rc<COMP::Properties>,
-    #[cfg(feature = "hydration")]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     creation_mode: RenderMode,
+    /// The document the component is rendered into, `None` on the server side.
+    #[cfg(feature = "csr")]
+    document: Option<web_sys::Document>,
 
     #[cfg(feature = "hydration")]
     prepared_state: Option<String>,
@@ -53,11 +58,16 @@ impl<COMP: BaseComponent> Context<COMP> {
         &self.props
     }
 
-    #[cfg(feature = "hydration")]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     pub(crate) fn creation_mode(&self) -> RenderMode {
         self.creation_mode
     }
 
+    #[cfg(feature = "csr")]
+    pub(crate) fn document(&self) -> Option<&web_sys::Document> {
+        self.document.as_ref()
+    }
+
     /// The component's prepared state
     pub fn prepared_state(&self) -> Option<&str> {
         #[cfg(not(feature = "hydration"))]
@@ -255,6 +265,10 @@ mod tests {
             props: Rc::new(()),
             #[cfg(feature = "hydration")]
             creation_mode: crate::html::RenderMode::Hydration,
+            #[cfg(all(feature = "ssr", not(feature = "hydration")))]
+            creation_mode: crate::html::RenderMode::Ssr,
+            #[cfg(feature = "csr")]
+            document: None,
             #[cfg(feature = "hydration")]
             prepared_state: None,
         };
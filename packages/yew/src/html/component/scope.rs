@@ -1,6 +1,7 @@
 //! Component scope module
 
 use std::any::{Any, TypeId};
+use std::cell::Cell;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::ops::Deref;
@@ -14,16 +15,89 @@ use super::lifecycle::ComponentState;
 use super::BaseComponent;
 use crate::callback::Callback;
 use crate::context::{ContextHandle, ContextProvider};
+#[cfg(feature = "ssr")]
+use crate::html::StyleRegistry;
 use crate::platform::spawn_local;
 #[cfg(any(feature = "csr", feature = "ssr"))]
 use crate::scheduler::Shared;
+use crate::suspense::SuspenseCache;
+use crate::virtual_dom::AttrValue;
+
+/// The prefix of the ids generated with [`use_id`](crate::functional::use_id), unless the root
+/// sets another one.
+#[cfg(any(feature = "ssr", feature = "csr", test))]
+const DEFAULT_ID_PREFIX: &str = "yew";
+
+/// The position of a component among the components created by its parent.
+///
+/// Ids generated with [`use_id`](crate::functional::use_id) are derived from the positions of a
+/// component and its ancestors, so they are the same whenever the same tree is rendered.
+#[derive(Debug, Default)]
+pub(crate) struct ScopePosition {
+    /// The order in which this component was created by its parent.
+    index: usize,
+    /// The number of components created by this component.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    children: Cell<usize>,
+    /// The number of components created by the first render, if they were created in reverse
+    /// order.
+    reversed_children: Cell<Option<usize>>,
+    /// The number of ids generated by this component.
+    ids: Cell<usize>,
+}
+
+impl ScopePosition {
+    /// Creates the position of the next component created by this component.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    fn next_child(&self) -> Self {
+        let index = self.children.get();
+        self.children.set(index + 1);
+
+        Self {
+            index,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of components created by this component.
+    #[cfg(feature = "csr")]
+    pub(crate) fn children(&self) -> usize {
+        self.children.get()
+    }
+
+    /// Marks all components created so far as created in reverse document order.
+    ///
+    /// A client side render attaches the nodes of a list from the back, which creates its
+    /// components in the opposite order of server-side rendering and hydration.
+    #[cfg(feature = "csr")]
+    pub(crate) fn reverse_children(&self) {
+        self.reversed_children.set(Some(self.children.get()));
+    }
+
+    /// Returns the position of this component in document order among the components created by
+    /// `parent`.
+    fn index_in(&self, parent: &ScopePosition) -> usize {
+        match parent.reversed_children.get() {
+            Some(m) if self.index < m => m - 1 - self.index,
+            _ => self.index,
+        }
+    }
+}
 
 /// Untyped scope used for accessing parent scope
 #[derive(Clone)]
 pub struct AnyScope {
     type_id: TypeId,
+    #[cfg(all(debug_assertions, feature = "ssr"))]
+    type_name: &'static str,
     parent: Option<Rc<AnyScope>>,
     typed_scope: Rc<dyn Any>,
+    pub(crate) position: Rc<ScopePosition>,
+    id_prefix: AttrValue,
+    #[cfg(feature = "ssr")]
+    suspense_cache: Option<SuspenseCache>,
+    #[cfg(feature = "ssr")]
+    styles: Option<StyleRegistry>,
 }
 
 impl fmt::Debug for AnyScope {
@@ -36,13 +110,38 @@ impl<COMP: BaseComponent> From<Scope<COMP>> for AnyScope {
     fn from(scope: Scope<COMP>) -> Self {
         AnyScope {
             type_id: TypeId::of::<COMP>(),
+            #[cfg(all(debug_assertions, feature = "ssr"))]
+            type_name: std::any::type_name::<COMP>(),
             parent: scope.parent.clone(),
+            position: scope.position.clone(),
+            id_prefix: scope.id_prefix.clone(),
+            #[cfg(feature = "ssr")]
+            suspense_cache: scope.suspense_cache.clone(),
+            #[cfg(feature = "ssr")]
+            styles: scope.styles.clone(),
             typed_scope: Rc::new(scope),
         }
     }
 }
 
 impl AnyScope {
+    #[cfg(test)]
+    pub(crate) fn test() -> Self {
+        Self {
+            type_id: TypeId::of::<()>(),
+            #[cfg(all(debug_assertions, feature = "ssr"))]
+            type_name: std::any::type_name::<()>(),
+            parent: None,
+            typed_scope: Rc::new(()),
+            position: Rc::default(),
+            id_prefix: AttrValue::Static(DEFAULT_ID_PREFIX),
+            #[cfg(feature = "ssr")]
+            suspense_cache: None,
+            #[cfg(feature = "ssr")]
+            styles: None,
+        }
+    }
+
     /// Returns the parent scope
     pub fn get_parent(&self) -> Option<&AnyScope> {
         self.parent.as_deref()
@@ -53,6 +152,12 @@ impl AnyScope {
         &self.type_id
     }
 
+    /// Returns the type name of the linked component
+    #[cfg(all(debug_assertions, feature = "ssr"))]
+    pub(crate) fn get_type_name(&self) -> &'static str {
+        self.type_name
+    }
+
     /// Attempts to downcast into a typed scope
     ///
     /// # Panics
@@ -88,12 +193,63 @@ impl AnyScope {
         let component = scope.get_component()?;
         Some(component.subscribe_consumer(callback, scope_clone))
     }
+
+    /// Generates the next id of the linked component.
+    ///
+    /// The id has the format `{prefix}-{p1}-...-{pn}-{i}`, where `prefix` is the id prefix of the
+    /// root, `yew` by default, `p1` to `pn` are the positions of the component and its ancestors
+    /// below the root component and `i` counts the ids previously generated by the component.
+    pub(crate) fn next_id(&self) -> AttrValue {
+        use std::fmt::Write;
+
+        let mut path = Vec::new();
+        let mut scope = self;
+        while let Some(parent) = scope.get_parent() {
+            path.push(scope.position.index_in(&parent.position));
+            scope = parent;
+        }
+
+        let mut id = self.id_prefix.to_string();
+        for index in path.into_iter().rev() {
+            let _ = write!(id, "-{}", index);
+        }
+
+        let ids = self.position.ids.get();
+        self.position.ids.set(ids + 1);
+        let _ = write!(id, "-{}", ids);
+
+        id.into()
+    }
+
+    /// Returns the [`SuspenseCache`] of the server-side render the linked component belongs to.
+    pub(crate) fn suspense_cache(&self) -> Option<SuspenseCache> {
+        #[cfg(feature = "ssr")]
+        {
+            self.suspense_cache.clone()
+        }
+        #[cfg(not(feature = "ssr"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the [`StyleRegistry`] of the server-side render the linked component belongs to.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn style_registry(&self) -> Option<&StyleRegistry> {
+        self.styles.as_ref()
+    }
 }
 
 /// A context which allows sending messages to a component.
 pub struct Scope<COMP: BaseComponent> {
     _marker: PhantomData<COMP>,
     parent: Option<Rc<AnyScope>>,
+    position: Rc<ScopePosition>,
+    id_prefix: AttrValue,
+    #[cfg(feature = "ssr")]
+    suspense_cache: Option<SuspenseCache>,
+    #[cfg(feature = "ssr")]
+    styles: Option<StyleRegistry>,
 
     #[cfg(any(feature = "csr", feature = "ssr"))]
     pub(crate) pending_messages: MsgQueue<COMP::Message>,
@@ -118,6 +274,12 @@ impl<COMP: BaseComponent> Clone for Scope<COMP> {
             #[cfg(any(feature = "csr", feature = "ssr"))]
             pending_messages: self.pending_messages.clone(),
             parent: self.parent.clone(),
+            position: self.position.clone(),
+            id_prefix: self.id_prefix.clone(),
+            #[cfg(feature = "ssr")]
+            suspense_cache: self.suspense_cache.clone(),
+            #[cfg(feature = "ssr")]
+            styles: self.styles.clone(),
 
             #[cfg(any(feature = "csr", feature = "ssr"))]
             state: self.state.clone(),
@@ -293,22 +455,42 @@ impl<COMP: BaseComponent> Scope<COMP> {
 mod feat_ssr {
     use std::fmt::Write;
 
+    #[cfg(feature = "tracing")]
+    use tracing::Instrument;
+
     use super::*;
     use crate::html::component::lifecycle::{
         ComponentRenderState, CreateRunner, DestroyRunner, RenderRunner,
     };
+    #[cfg(panic = "unwind")]
+    use crate::html::{ErrorBoundary, ErrorBoundaryProps};
+    use crate::html::{Html, Static};
+    #[cfg(panic = "unwind")]
+    use crate::platform::fmt::BufStream;
     use crate::platform::fmt::BufWriter;
     use crate::platform::pinned::oneshot;
     use crate::scheduler;
-    use crate::virtual_dom::Collectable;
+    use crate::virtual_dom::{Collectable, Key, SsrContext};
 
     impl<COMP: BaseComponent> Scope<COMP> {
-        pub(crate) async fn render_into_stream(
-            &self,
-            w: &mut BufWriter,
-            props: Rc<COMP::Properties>,
-            hydratable: bool,
-        ) {
+        /// Sets the [`SuspenseCache`] shared by the component and its descendants.
+        pub(crate) fn with_suspense_cache(mut self, cache: SuspenseCache) -> Self {
+            self.suspense_cache = Some(cache);
+
+            self
+        }
+
+        /// Sets the [`StyleRegistry`] the component and its descendants add their styles to.
+        pub(crate) fn with_styles(mut self, styles: StyleRegistry) -> Self {
+            self.styles = Some(styles);
+
+            self
+        }
+
+        /// Schedules the creation and first render of the component.
+        ///
+        /// The returned receiver resolves to the rendered [`Html`] of the component.
+        fn schedule_ssr_render(&self, props: Rc<COMP::Properties>) -> oneshot::Receiver<Html> {
             // Rust's Future implementation is stack-allocated and incurs zero runtime-cost.
             //
             // If the content of this channel is ready before it is awaited, it is
@@ -322,6 +504,8 @@ mod feat_ssr {
                     initial_render_state: state,
                     props,
                     scope: self.clone(),
+                    #[cfg(feature = "csr")]
+                    on_first_rendered: None,
                     #[cfg(feature = "hydration")]
                     prepared_state: None,
                 }),
@@ -331,33 +515,178 @@ mod feat_ssr {
             );
             scheduler::start();
 
-            let collectable = Collectable::for_component::<COMP>();
+            rx
+        }
+
+        /// Renders the children of an [`ErrorBoundary`], its fallback is rendered instead if a
+        /// descendant component panics.
+        ///
+        /// The children are buffered until they are rendered completely, so nothing of them is
+        /// written if a descendant panics.
+        #[cfg(panic = "unwind")]
+        async fn render_error_boundary(
+            &self,
+            w: &mut BufWriter,
+            html: Html,
+            props: &dyn Any,
+            ctx: &SsrContext,
+        ) {
+            let self_any_scope = AnyScope::from(self.clone());
+
+            let children = {
+                let self_any_scope = self_any_scope.clone();
+                let ctx = ctx.clone();
+                BufStream::new(move |mut w| async move {
+                    html.render_into_stream(&mut w, &self_any_scope, &ctx).await;
+                })
+                .collect::<String>()
+                .await
+            };
 
-            if hydratable {
-                collectable.write_open_tag(w);
+            let panicked = self.get_component().map_or(false, |m| {
+                (&*m as &dyn Any)
+                    .downcast_ref::<ErrorBoundary>()
+                    .map_or(false, ErrorBoundary::panicked)
+            });
+
+            match props.downcast_ref::<ErrorBoundaryProps>() {
+                Some(props) if panicked => {
+                    props
+                        .fallback
+                        .render_into_stream(w, &self_any_scope, ctx)
+                        .await
+                }
+                _ => {
+                    let _ = w.write_str(&children);
+                }
             }
+        }
+
+        fn destroy_ssr(&self) {
+            scheduler::push_component_destroy(Box::new(DestroyRunner {
+                state: self.state.clone(),
+                parent_to_detach: false,
+            }));
+            scheduler::start();
+        }
 
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                level = tracing::Level::DEBUG,
+                name = "render_component",
+                skip_all,
+                fields(component.id = self.id, component.name = std::any::type_name::<COMP>()),
+            )
+        )]
+        pub(crate) async fn render_into_stream(
+            &self,
+            w: &mut BufWriter,
+            props: Rc<COMP::Properties>,
+            key: Option<&Key>,
+            ctx: &SsrContext,
+        ) {
+            #[cfg(panic = "unwind")]
+            let boundary_props = props.clone();
+            let rx = self.schedule_ssr_render(props);
+
+            // The markers of the root component are omitted if only its children are hydratable.
+            let omit_markers = ctx.hydratable_children;
+            let inner_ctx;
+            let ctx = if omit_markers {
+                inner_ctx = SsrContext {
+                    hydratable_children: false,
+                    ..ctx.clone()
+                };
+                &inner_ctx
+            } else {
+                ctx
+            };
+
+            // Results that are not hydratable do not have any markers.
+            let collectable = if ctx.hydratable && !omit_markers {
+                let collectable = Collectable::for_component::<COMP>();
+                match key {
+                    Some(key) => collectable.write_keyed_open_tag(w, key),
+                    None => collectable.write_open_tag(w),
+                }
+
+                Some(collectable)
+            } else {
+                None
+            };
+
+            // Resolves once the component is no longer suspended.
+            #[cfg(feature = "tracing")]
+            let rx = rx.instrument(tracing::debug_span!("wait_for_render"));
             let html = rx.await.unwrap();
 
             let self_any_scope = AnyScope::from(self.clone());
-            html.render_into_stream(w, &self_any_scope, hydratable)
-                .await;
 
-            if let Some(prepared_state) = self.get_component().unwrap().prepare_state() {
-                let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
-                let _ = w.write_str(&prepared_state);
-                let _ = w.write_str(r#"</script>"#);
+            #[cfg(panic = "unwind")]
+            let is_error_boundary = TypeId::of::<COMP>() == TypeId::of::<ErrorBoundary>();
+            #[cfg(not(panic = "unwind"))]
+            let is_error_boundary = false;
+
+            if ctx.hydratable && TypeId::of::<COMP>() == TypeId::of::<Static>() {
+                // Children of a static component are not hydrated, so they do not need any
+                // markers.
+                let children_ctx = SsrContext {
+                    hydratable: false,
+                    ..ctx.clone()
+                };
+                html.render_into_stream(w, &self_any_scope, &children_ctx)
+                    .await;
+            } else if is_error_boundary {
+                #[cfg(panic = "unwind")]
+                self.render_error_boundary(w, html, &*boundary_props, ctx)
+                    .await;
+            } else {
+                html.render_into_stream(w, &self_any_scope, ctx).await;
+            }
+
+            // Prepared states are only read during hydration.
+            let prepared_state = if omit_markers || !ctx.hydratable {
+                None
+            } else {
+                self.get_component().unwrap().prepare_state()
+            };
+            if let Some(prepared_state) = prepared_state {
+                match ctx.hydration_data {
+                    Some(ref m) => {
+                        let id = m.borrow_mut().push(prepared_state);
+                        let _ = write!(
+                            w,
+                            r#"<script type="application/x-yew-comp-state" data-id="{}"></script>"#,
+                            id
+                        );
+                    }
+                    None => {
+                        let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
+                        let _ = w.write_str(&prepared_state);
+                        let _ = w.write_str(r#"</script>"#);
+                    }
+                }
             }
 
-            if hydratable {
+            if let Some(collectable) = collectable {
                 collectable.write_close_tag(w);
             }
 
-            scheduler::push_component_destroy(Box::new(DestroyRunner {
-                state: self.state.clone(),
-                parent_to_detach: false,
-            }));
-            scheduler::start();
+            self.destroy_ssr();
+        }
+
+        pub(crate) async fn collect_text_content(
+            &self,
+            w: &mut String,
+            props: Rc<COMP::Properties>,
+        ) {
+            let html = self.schedule_ssr_render(props).await.unwrap();
+
+            let self_any_scope = AnyScope::from(self.clone());
+            html.collect_text_content(w, &self_any_scope).await;
+
+            self.destroy_ssr();
         }
     }
 }
@@ -434,6 +763,19 @@ mod feat_csr_ssr {
     impl<COMP: BaseComponent> Scope<COMP> {
         /// Crate a scope with an optional parent scope
         pub(crate) fn new(parent: Option<AnyScope>) -> Self {
+            let position = parent
+                .as_ref()
+                .map(|m| m.position.next_child())
+                .unwrap_or_default();
+            let id_prefix = parent
+                .as_ref()
+                .map_or(AttrValue::Static(DEFAULT_ID_PREFIX), |m| {
+                    m.id_prefix.clone()
+                });
+            #[cfg(feature = "ssr")]
+            let suspense_cache = parent.as_ref().and_then(|m| m.suspense_cache.clone());
+            #[cfg(feature = "ssr")]
+            let styles = parent.as_ref().and_then(|m| m.styles.clone());
             let parent = parent.map(Rc::new);
 
             let state = Rc::new(RefCell::new(None));
@@ -447,11 +789,24 @@ mod feat_csr_ssr {
 
                 state,
                 parent,
+                position: Rc::new(position),
+                id_prefix,
+                #[cfg(feature = "ssr")]
+                suspense_cache,
+                #[cfg(feature = "ssr")]
+                styles,
 
                 id: COMP_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
             }
         }
 
+        /// Sets the prefix of the ids generated by the component and its descendants.
+        pub(crate) fn with_id_prefix(mut self, prefix: AttrValue) -> Self {
+            self.id_prefix = prefix;
+
+            self
+        }
+
         #[rustversion::before(1.63)]
         #[inline]
         pub(super) fn arch_get_component(&self) -> Option<impl Deref<Target = COMP> + '_> {
@@ -526,17 +881,8 @@ mod feat_csr {
     };
     use crate::html::NodeRef;
     use crate::scheduler;
-
-    impl AnyScope {
-        #[cfg(test)]
-        pub(crate) fn test() -> Self {
-            Self {
-                type_id: TypeId::of::<()>(),
-                parent: None,
-                typed_scope: Rc::new(()),
-            }
-        }
-    }
+    #[cfg(feature = "debug-tooling")]
+    use crate::virtual_dom::Key;
 
     fn schedule_props_update(
         state: Shared<Option<ComponentState>>,
@@ -557,6 +903,9 @@ mod feat_csr {
         COMP: BaseComponent,
     {
         /// Mounts a component with `props` to the specified `element` in the DOM.
+        ///
+        /// `on_first_rendered` is notified once after the first `rendered` lifecycle of the
+        /// component.
         pub(crate) fn mount_in_place(
             &self,
             root: BSubtree,
@@ -564,6 +913,7 @@ mod feat_csr {
             next_sibling: NodeRef,
             internal_ref: NodeRef,
             props: Rc<COMP::Properties>,
+            on_first_rendered: Option<Callback<()>>,
         ) {
             let bundle = Bundle::new();
             internal_ref.link(next_sibling.clone());
@@ -583,6 +933,7 @@ mod feat_csr {
                     initial_render_state: state,
                     props,
                     scope: self.clone(),
+                    on_first_rendered,
                     #[cfg(feature = "hydration")]
                     prepared_state: None,
                 }),
@@ -608,6 +959,9 @@ mod feat_csr {
         /// Process an event to destroy a component
         fn destroy(self, parent_to_detach: bool);
         fn destroy_boxed(self: Box<Self>, parent_to_detach: bool);
+        /// Get information about this scope and the scopes of its descendants
+        #[cfg(feature = "debug-tooling")]
+        fn scope_info(&self, key: Option<&Key>) -> crate::ScopeInfo;
     }
 
     impl<COMP: BaseComponent> Scoped for Scope<COMP> {
@@ -646,6 +1000,29 @@ mod feat_csr {
                 render_state.render_state.shift(parent, next_sibling)
             }
         }
+
+        #[cfg(feature = "debug-tooling")]
+        fn scope_info(&self, key: Option<&Key>) -> crate::ScopeInfo {
+            let mut children = Vec::new();
+            if let Some(render_state) = self.render_state() {
+                match *render_state {
+                    ComponentRenderState::Render { ref bundle, .. } => {
+                        bundle.collect_scope_infos(&mut children)
+                    }
+                    // the children of a component are not known until it is hydrated.
+                    #[cfg(feature = "hydration")]
+                    ComponentRenderState::Hydration { .. } => {}
+                    #[cfg(feature = "ssr")]
+                    ComponentRenderState::Ssr { .. } => {}
+                }
+            }
+
+            crate::ScopeInfo {
+                type_name: std::any::type_name::<COMP>(),
+                key: key.cloned(),
+                children,
+            }
+        }
     }
 }
 #[cfg(feature = "csr")]
@@ -682,6 +1059,7 @@ mod feat_hydration {
             fragment: &mut Fragment,
             internal_ref: NodeRef,
             props: Rc<COMP::Properties>,
+            on_first_rendered: Option<Callback<()>>,
         ) {
             // This is very helpful to see which component is failing during hydration
             // which means this component may not having a stable layout / differs between
@@ -712,7 +1090,11 @@ mod feat_hydration {
                 Some(m) if m.type_() == "application/x-yew-comp-state" => {
                     fragment.pop_back();
                     parent.remove_child(&m).unwrap();
-                    Some(m.text().unwrap())
+                    // States collected separately are referred to by their id.
+                    match m.get_attribute("data-id").and_then(|m| m.parse().ok()) {
+                        Some(id) => root.take_hydration_data(id),
+                        None => Some(m.text().unwrap()),
+                    }
                 }
                 _ => None,
             };
@@ -731,6 +1113,7 @@ mod feat_hydration {
                     initial_render_state: state,
                     props,
                     scope: self.clone(),
+                    on_first_rendered,
                     prepared_state,
                 }),
                 Box::new(RenderRunner {
@@ -1,10 +1,13 @@
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use implicit_clone::unsync::{IArray, IMap};
 pub use implicit_clone::ImplicitClone;
 
 use super::super::callback::Callback;
-use super::{BaseComponent, Children, ChildrenRenderer, Component, NodeRef, Scope};
+use super::{
+    BaseComponent, Children, ChildrenRenderer, ChildrenWithSlots, Component, NodeRef, Scope,
+};
 use crate::virtual_dom::{AttrValue, VChild, VNode};
 
 impl ImplicitClone for NodeRef {}
@@ -141,6 +144,20 @@ where
     }
 }
 
+impl IntoPropValue<ChildrenWithSlots> for ChildrenRenderer<VNode> {
+    #[inline]
+    fn into_prop_value(self) -> ChildrenWithSlots {
+        ChildrenWithSlots::new(self.into_iter().collect())
+    }
+}
+
+impl IntoPropValue<Option<ChildrenWithSlots>> for ChildrenRenderer<VNode> {
+    #[inline]
+    fn into_prop_value(self) -> Option<ChildrenWithSlots> {
+        Some(ChildrenWithSlots::new(self.into_iter().collect()))
+    }
+}
+
 macro_rules! impl_into_prop {
     (|$value:ident: $from_ty:ty| -> $to_ty:ty { $conversion:expr }) => {
         // implement V -> T
@@ -175,6 +192,12 @@ impl_into_prop!(|value: &'static str| -> String { value.to_owned() });
 impl_into_prop!(|value: &'static str| -> AttrValue { AttrValue::Static(value) });
 impl_into_prop!(|value: String| -> AttrValue { AttrValue::Rc(Rc::from(value)) });
 impl_into_prop!(|value: Rc<str>| -> AttrValue { AttrValue::Rc(value) });
+impl_into_prop!(|value: Cow<'static, str>| -> AttrValue {
+    match value {
+        Cow::Borrowed(value) => AttrValue::Static(value),
+        Cow::Owned(value) => AttrValue::Rc(Rc::from(value)),
+    }
+});
 impl_into_prop!(|value: VNode| -> Children { Children::new(vec![value]) });
 
 impl<T: ImplicitClone + 'static> IntoPropValue<IArray<T>> for &'static [T] {
@@ -218,6 +241,36 @@ mod test {
         let _: Option<AttrValue> = Rc::<str>::from("foo").into_prop_value();
     }
 
+    #[test]
+    fn test_static_attr_value() {
+        static FOO: &str = "foo";
+
+        let value: AttrValue = FOO.into_prop_value();
+        assert!(matches!(value, AttrValue::Static(_)));
+        // The string is borrowed and not copied to the heap.
+        assert_eq!(value.as_str().as_ptr(), FOO.as_ptr());
+
+        assert_eq!(value, AttrValue::Rc(Rc::from("foo")));
+        assert_ne!(value, AttrValue::Rc(Rc::from("bar")));
+    }
+
+    #[test]
+    fn test_cow_attr_value() {
+        static FOO: &str = "foo";
+
+        let value: AttrValue = Cow::Borrowed(FOO).into_prop_value();
+        assert!(matches!(value, AttrValue::Static(_)));
+        // The borrowed string is not copied to the heap.
+        assert_eq!(value.as_str().as_ptr(), FOO.as_ptr());
+
+        let value: AttrValue = Cow::<'static, str>::Owned("foo".to_owned()).into_prop_value();
+        assert!(matches!(value, AttrValue::Rc(_)));
+        assert_eq!(value, AttrValue::Static(FOO));
+
+        let _: Option<AttrValue> = Cow::Borrowed(FOO).into_prop_value();
+        let _: Option<AttrValue> = Some(Cow::Borrowed(FOO)).into_prop_value();
+    }
+
     #[test]
     fn test_callback() {
         let _: Callback<String> = (|_: String| ()).into_prop_value();
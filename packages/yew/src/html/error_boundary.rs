@@ -0,0 +1,189 @@
+//! This module contains the [`ErrorBoundary`] component.
+
+use crate::html;
+use crate::html::{Children, Component, Context, Html, Properties};
+
+/// Properties for [ErrorBoundary].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ErrorBoundaryProps {
+    /// The Children of the current ErrorBoundary Component.
+    #[prop_or_default]
+    pub children: Children,
+
+    /// The content to render in place of the children after a descendant component panicked.
+    #[prop_or_default]
+    pub fallback: Html,
+}
+
+/// Recovers from panics of descendant components.
+///
+/// When a component placed inside an `<ErrorBoundary />` panics in one of the following
+/// lifecycle methods, the panic is caught and the nearest error boundary replaces its children
+/// with its fallback, while the rest of the application keeps working:
+///
+/// - [`view`](Component::view), including the hooks of function components.
+/// - [`update`](Component::update).
+/// - [`changed`](Component::changed).
+/// - [`rendered`](Component::rendered).
+///
+/// Panics in [`create`](Component::create) and [`destroy`](Component::destroy) are not caught.
+/// The panic hook is called as usual before a panic is caught, so the panic is still reported.
+///
+/// The children are rendered again when the error boundary receives new properties.
+///
+/// During server-side rendering, a panic in [`view`](Component::view) of a descendant is caught as
+/// well and the fallback is rendered in place of the children. A hydrated error boundary starts
+/// with its fallback if it was rendered on the server.
+///
+/// # Note
+///
+/// Recovering from a panic requires unwinding. In builds with `panic = "abort"`, which is the
+/// default for `wasm32-unknown-unknown`, an error boundary renders its children like a fragment
+/// and its fallback is never used. A panic of a descendant aborts the application as it would
+/// without an error boundary, e.g.: on `wasm32-unknown-unknown` the module traps and the
+/// application stops responding.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::html::ErrorBoundary;
+///
+/// #[function_component]
+/// fn Widget() -> Html {
+///     panic!("widget failed to render");
+/// }
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <ErrorBoundary fallback={html! { {"Something went wrong."} }}>
+///             <Widget />
+///         </ErrorBoundary>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ErrorBoundary {
+    panicked: bool,
+}
+
+impl Component for ErrorBoundary {
+    type Message = ();
+    type Properties = ErrorBoundaryProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        // The prepared state is only set if the fallback was rendered on the server.
+        Self {
+            panicked: ctx.prepared_state().is_some(),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        !std::mem::replace(&mut self.panicked, true)
+    }
+
+    fn changed(&mut self, _ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.panicked = false;
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.panicked {
+            return ctx.props().fallback.clone();
+        }
+
+        let children = ctx.props().children.clone();
+
+        html! { <>{children}</> }
+    }
+
+    fn prepare_state(&self) -> Option<String> {
+        self.panicked.then(String::new)
+    }
+}
+
+impl ErrorBoundary {
+    /// Returns whether a descendant panicked since the children were rendered last.
+    #[cfg(all(feature = "ssr", panic = "unwind"))]
+    pub(crate) fn panicked(&self) -> bool {
+        self.panicked
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(panic = "unwind")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[function_component]
+    fn Faulty() -> Html {
+        panic!("faulty component failed");
+    }
+
+    #[test]
+    async fn test_fallback() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <ErrorBoundary fallback={html! { {"failed"} }}>
+                        <span>{"before"}</span>
+                        <Faulty />
+                    </ErrorBoundary>
+                    <ErrorBoundary fallback={html! { {"failed"} }}>
+                        <span>{"ok"}</span>
+                    </ErrorBoundary>
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        // The output of the children rendered before the panic is discarded.
+        assert_eq!(s, "<div>failed<span>ok</span></div>");
+
+        // The client starts with the fallback when it hydrates the error boundary.
+        let s = ServerRenderer::<Comp>::new().render().await;
+
+        assert_eq!(
+            s.matches(r#"failed<script type="application/x-yew-comp-state"></script>"#)
+                .count(),
+            1
+        );
+        assert!(s.contains("<span>ok</span>"));
+    }
+
+    #[test]
+    async fn test_nested_fallback() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={html! { {"outer"} }}>
+                    <ErrorBoundary fallback={html! { {"inner"} }}>
+                        <Faulty />
+                    </ErrorBoundary>
+                    <span>{"sibling"}</span>
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        // The nearest error boundary renders its fallback.
+        assert_eq!(s, "inner<span>sibling</span>");
+    }
+}
@@ -0,0 +1,68 @@
+//! This module contains the [`HydrationData`] type.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Prepared states of components, collected separately from the server-side rendered HTML.
+///
+/// Components prepare their state with
+/// [`use_prepared_state!`](crate::functional::use_prepared_state) or
+/// [`use_transitive_state!`](crate::functional::use_transitive_state). Usually, a prepared state
+/// is embedded into the rendered HTML. When rendered with
+/// [`LocalServerRenderer::render_with_hydration_data`](crate::LocalServerRenderer::render_with_hydration_data),
+/// each prepared state is stored in this collection under an id that is unique within the render
+/// instead, and the HTML only refers to it by its id:
+///
+/// ```html
+/// <script type="application/x-yew-comp-state" data-id="0"></script>
+/// ```
+///
+/// The reference is placed where the state would otherwise be embedded, right before the closing
+/// hydration marker of the component that prepared the state. Ids are assigned in the order that
+/// components finish rendering, which is not necessarily their order in the document.
+///
+/// This type can be serialized (e.g.: into a JSON blob) and passed to
+/// [`Renderer::hydrate_with_data`](crate::Renderer::hydrate_with_data) on the client.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HydrationData {
+    states: BTreeMap<usize, String>,
+}
+
+impl HydrationData {
+    /// Returns the encoded prepared state with the given id.
+    pub fn get(&self, id: usize) -> Option<&str> {
+        self.states.get(&id).map(String::as_str)
+    }
+
+    /// Returns an iterator over the ids and encoded prepared states, ordered by id.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.states.iter().map(|(id, m)| (*id, m.as_str()))
+    }
+
+    /// Returns the number of collected prepared states.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns `true` if no prepared states were collected.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Stores a prepared state and returns its id.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn push(&mut self, state: String) -> usize {
+        let id = self.states.len();
+        self.states.insert(id, state);
+
+        id
+    }
+
+    /// Removes the prepared state with the given id.
+    #[cfg(feature = "hydration")]
+    pub(crate) fn take(&mut self, id: usize) -> Option<String> {
+        self.states.remove(&id)
+    }
+}
@@ -0,0 +1,164 @@
+//! This module contains the [`InlineComponent`] component.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::html::{Component, Context, Html, Properties};
+
+/// Properties for [InlineComponent].
+#[derive(Properties)]
+pub struct InlineComponentProps<P: PartialEq + 'static> {
+    /// The function that renders the component.
+    pub render: Rc<dyn Fn(&P) -> Html>,
+
+    /// The properties passed to `render`.
+    pub props: P,
+}
+
+impl<P> InlineComponentProps<P>
+where
+    P: PartialEq + 'static,
+{
+    /// Creates properties that render `props` with `render`.
+    pub fn new<F>(render: F, props: P) -> Self
+    where
+        F: Fn(&P) -> Html + 'static,
+    {
+        Self {
+            render: Rc::new(render),
+            props,
+        }
+    }
+}
+
+impl<P> Clone for InlineComponentProps<P>
+where
+    P: PartialEq + Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            render: self.render.clone(),
+            props: self.props.clone(),
+        }
+    }
+}
+
+#[allow(clippy::vtable_address_comparisons)]
+impl<P> PartialEq for InlineComponentProps<P>
+where
+    P: PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.render, &other.render) && self.props == other.props
+    }
+}
+
+impl<P> fmt::Debug for InlineComponentProps<P>
+where
+    P: PartialEq + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InlineComponentProps<_>")
+    }
+}
+
+/// A component that is rendered by a closure.
+///
+/// This allows rendering a closure `Fn(&P) -> Html` without declaring a component, e.g.: for
+/// prototypes and tests. The closure is called with the properties `P` whenever the component is
+/// rendered. The component is re-rendered when either the closure or `P` changes, closures are
+/// compared by pointer equality.
+///
+/// # Note
+///
+/// The closure is not a function component, hooks cannot be used inside of it. Declare a
+/// [`#[function_component]`](crate::functional::function_component) to use hooks.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::html::{InlineComponent, InlineComponentProps};
+/// use yew::ServerRenderer;
+///
+/// # async fn render() {
+/// let rendered = ServerRenderer::<InlineComponent<String>>::with_props(|| {
+///     InlineComponentProps::new(
+///         |name: &String| html! { <div>{"Hello, "}{name}{"!"}</div> },
+///         "Yew".to_string(),
+///     )
+/// })
+/// .hydratable(false)
+/// .render()
+/// .await;
+///
+/// assert_eq!(rendered, "<div>Hello, Yew!</div>");
+/// # }
+/// ```
+pub struct InlineComponent<P = ()>
+where
+    P: PartialEq + 'static,
+{
+    _marker: PhantomData<P>,
+}
+
+impl<P> fmt::Debug for InlineComponent<P>
+where
+    P: PartialEq + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InlineComponent<_>")
+    }
+}
+
+impl<P> Component for InlineComponent<P>
+where
+    P: PartialEq + 'static,
+{
+    type Message = ();
+    type Properties = InlineComponentProps<P>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let InlineComponentProps { render, props } = ctx.props();
+
+        render(props)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::{html, ServerRenderer};
+
+    #[test]
+    async fn test_inline_component() {
+        let s = ServerRenderer::<InlineComponent<u32>>::with_props(|| {
+            InlineComponentProps::new(|count: &u32| html! { <span>{*count}</span> }, 3)
+        })
+        .hydratable(false)
+        .render()
+        .await;
+
+        assert_eq!(s, "<span>3</span>");
+
+        let s = ServerRenderer::<InlineComponent>::with_props(|| {
+            InlineComponentProps::new(|_: &()| html! { <div>{"Hello!"}</div> }, ())
+        })
+        .hydratable(false)
+        .render()
+        .await;
+
+        assert_eq!(s, "<div>Hello!</div>");
+    }
+}
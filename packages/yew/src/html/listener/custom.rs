@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::CustomEvent;
+
+use crate::callback::Callback;
+use crate::virtual_dom::{Listener, ListenerKind};
+
+/// An error while reading the `detail` of a custom event.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CustomEventError {
+    /// The dispatched event is not a `CustomEvent`, e.g.: a plain `Event` with the same name.
+    #[error("the event is not a CustomEvent")]
+    NotCustomEvent,
+    /// The `detail` of the event could not be deserialized into the expected type.
+    #[error("failed to deserialize the detail of the event: {0}")]
+    Detail(String),
+}
+
+/// Creates a listener of the custom event `name`, e.g.: an event dispatched by a web component.
+///
+/// The `detail` of the event is deserialized into `T` with
+/// [`serde-wasm-bindgen`](https://docs.rs/serde-wasm-bindgen), so JavaScript objects are
+/// deserialized into structs and maps, and arrays into sequences. If the event is not a
+/// `CustomEvent` or its `detail` does not match `T`, the callback receives a
+/// [`CustomEventError`] instead, use `()` or [`Option`] for events without a `detail`.
+///
+/// The listener is added to an element with
+/// [`VTag::add_listener`](crate::virtual_dom::VTag::add_listener) or
+/// [`TagBuilder::listener`](crate::virtual_dom::TagBuilder::listener).
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use yew::html::oncustomevent;
+/// use yew::prelude::*;
+///
+/// #[derive(Deserialize)]
+/// struct ColorDetail {
+///     color: String,
+/// }
+///
+/// #[function_component]
+/// fn Picker() -> Html {
+///     let color = use_state(|| "#000000".to_owned());
+///     let onchange = {
+///         let color = color.clone();
+///         Callback::from(move |detail: Result<ColorDetail, _>| match detail {
+///             Ok(detail) => color.set(detail.color),
+///             Err(e) => tracing::warn!("unexpected event: {}", e),
+///         })
+///     };
+///
+///     Html::list()
+///         .child(Html::tag("color-picker").listener(oncustomevent("color-change", onchange)))
+///         .child(Html::text((*color).clone()))
+///         .build()
+/// }
+/// ```
+pub fn oncustomevent<T>(
+    name: impl Into<Cow<'static, str>>,
+    callback: Callback<Result<T, CustomEventError>>,
+) -> Rc<dyn Listener>
+where
+    T: DeserializeOwned + 'static,
+{
+    Rc::new(CustomEventListener {
+        name: name.into(),
+        callback,
+    })
+}
+
+/// Returns whether `event` is a `CustomEvent`.
+///
+/// The event is not checked with `dyn_into`, which rejects the events created in another window,
+/// e.g.: an iframe the application is rendered into. Its class is read with
+/// `Object.prototype.toString` instead, checking for a `detail` would also match other events,
+/// e.g.: `UIEvent`s.
+fn is_custom_event(event: &web_sys::Event) -> bool {
+    let object_prototype = js_sys::Object::get_prototype_of(&js_sys::Object::new());
+
+    js_sys::Reflect::get(&object_prototype, &JsValue::from_str("toString"))
+        .ok()
+        .and_then(|m| m.dyn_into::<js_sys::Function>().ok())
+        .and_then(|m| m.call0(event).ok())
+        .map_or(false, |m| m == "[object CustomEvent]")
+}
+
+struct CustomEventListener<T> {
+    name: Cow<'static, str>,
+    callback: Callback<Result<T, CustomEventError>>,
+}
+
+impl<T> Listener for CustomEventListener<T>
+where
+    T: DeserializeOwned,
+{
+    fn kind(&self) -> ListenerKind {
+        ListenerKind::other(self.name.clone())
+    }
+
+    fn handle(&self, event: web_sys::Event) {
+        let detail = if is_custom_event(&event) {
+            let event = event.unchecked_into::<CustomEvent>();
+            serde_wasm_bindgen::from_value(event.detail())
+                .map_err(|e| CustomEventError::Detail(e.to_string()))
+        } else {
+            Err(CustomEventError::NotCustomEvent)
+        };
+
+        self.callback.emit(detail);
+    }
+
+    fn passive(&self) -> bool {
+        false
+    }
+}
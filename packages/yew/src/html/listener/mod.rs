@@ -1,6 +1,10 @@
+#[cfg(feature = "csr")]
+mod custom;
 #[macro_use]
 mod events;
 
+#[cfg(feature = "csr")]
+pub use custom::{oncustomevent, CustomEventError};
 pub use events::*;
 use wasm_bindgen::JsCast;
 use web_sys::{Event, EventTarget};
@@ -4,7 +4,14 @@ mod classes;
 mod component;
 mod conversion;
 mod error;
+mod error_boundary;
+#[cfg(any(feature = "ssr", feature = "hydration"))]
+mod hydration_data;
+mod inline_component;
 mod listener;
+mod parse_attribute;
+mod static_content;
+mod style;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -13,7 +20,18 @@ pub use classes::*;
 pub use component::*;
 pub use conversion::*;
 pub use error::*;
+pub use error_boundary::*;
+#[cfg(any(feature = "ssr", feature = "hydration"))]
+pub use hydration_data::*;
+pub use inline_component::*;
 pub use listener::*;
+pub use parse_attribute::*;
+pub use static_content::*;
+pub use style::Style;
+#[cfg(feature = "ssr")]
+pub(crate) use style::StyleRegistry;
+#[cfg(feature = "csr")]
+pub(crate) use style::STYLE_ATTRIBUTE;
 use wasm_bindgen::JsValue;
 use web_sys::{Element, Node};
 
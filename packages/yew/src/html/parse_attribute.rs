@@ -0,0 +1,172 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+use web_sys::NamedNodeMap;
+
+/// An error while parsing the value of an attribute, see [`ParseAttribute`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseAttributeError {
+    /// The element does not have the attribute.
+    #[error("the attribute is missing")]
+    Missing,
+    /// The value of the attribute cannot be parsed into the expected type.
+    #[error("`{value}` is not a valid value: {reason}")]
+    Invalid {
+        /// The value of the attribute.
+        value: String,
+        /// Why the value is invalid.
+        reason: String,
+    },
+}
+
+/// A type that can be parsed from the value of an attribute of an element.
+///
+/// This is used to read typed properties from the attributes of an element, e.g.: the root of an
+/// application or a custom element, with [`parse_attribute`] and [`try_parse_attribute`].
+///
+/// - Numbers and [`String`]s require the attribute to be present.
+/// - [`bool`] follows the semantics of HTML boolean attributes: it is `true` if the attribute is
+///   present, regardless of its value, and `false` otherwise.
+/// - [`Option`] is [`None`] if the attribute is missing and parses the value otherwise.
+pub trait ParseAttribute: Sized {
+    /// Parses the value of an attribute, `value` is [`None`] if the element does not have the
+    /// attribute.
+    fn parse_attribute(value: Option<&str>) -> Result<Self, ParseAttributeError>;
+}
+
+impl ParseAttribute for String {
+    fn parse_attribute(value: Option<&str>) -> Result<Self, ParseAttributeError> {
+        value.map(str::to_owned).ok_or(ParseAttributeError::Missing)
+    }
+}
+
+impl ParseAttribute for bool {
+    fn parse_attribute(value: Option<&str>) -> Result<Self, ParseAttributeError> {
+        Ok(value.is_some())
+    }
+}
+
+impl<T> ParseAttribute for Option<T>
+where
+    T: ParseAttribute,
+{
+    fn parse_attribute(value: Option<&str>) -> Result<Self, ParseAttributeError> {
+        value.map(|m| T::parse_attribute(Some(m))).transpose()
+    }
+}
+
+/// Parses a value with [`FromStr`], ignoring surrounding whitespace.
+fn parse_from_str<T>(value: Option<&str>) -> Result<T, ParseAttributeError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    let value = value.ok_or(ParseAttributeError::Missing)?;
+
+    value
+        .trim()
+        .parse()
+        .map_err(|e: T::Err| ParseAttributeError::Invalid {
+            value: value.to_owned(),
+            reason: e.to_string(),
+        })
+}
+
+macro_rules! impl_parse_attribute_from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ParseAttribute for $ty {
+                fn parse_attribute(value: Option<&str>) -> Result<Self, ParseAttributeError> {
+                    parse_from_str(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_attribute_from_str!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// Parses the attribute `name` of `attributes`, e.g.: the result of
+/// [`Element::attributes`](web_sys::Element::attributes).
+///
+/// See [`parse_attribute`] for a variant that falls back to a default value.
+pub fn try_parse_attribute<T>(
+    attributes: &NamedNodeMap,
+    name: &str,
+) -> Result<T, ParseAttributeError>
+where
+    T: ParseAttribute,
+{
+    let value = attributes.get_named_item(name).map(|m| m.value());
+
+    T::parse_attribute(value.as_deref())
+}
+
+/// Parses the attribute `name` of `attributes`, e.g.: the result of
+/// [`Element::attributes`](web_sys::Element::attributes).
+///
+/// Returns the default value of `T` if the attribute is missing or cannot be parsed. Invalid
+/// values are logged as a warning through `tracing`, missing attributes are not.
+///
+/// # Example
+///
+/// ```no_run
+/// use yew::html::parse_attribute;
+///
+/// let root = gloo::utils::document().get_element_by_id("app").unwrap();
+/// let attributes = root.attributes();
+///
+/// // <div id="app" page-size="20" compact></div>
+/// let page_size: u32 = parse_attribute(&attributes, "page-size");
+/// let compact: bool = parse_attribute(&attributes, "compact");
+/// ```
+pub fn parse_attribute<T>(attributes: &NamedNodeMap, name: &str) -> T
+where
+    T: ParseAttribute + Default,
+{
+    match try_parse_attribute(attributes, name) {
+        Ok(m) => m,
+        Err(ParseAttributeError::Missing) => T::default(),
+        Err(e) => {
+            tracing::warn!("failed to parse attribute `{}`: {}", name, e);
+            T::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(i32::parse_attribute(Some(" -42 ")), Ok(-42));
+        assert_eq!(f64::parse_attribute(Some("1.5")), Ok(1.5));
+        assert_eq!(
+            i32::parse_attribute(None),
+            Err(ParseAttributeError::Missing)
+        );
+        assert!(matches!(
+            i32::parse_attribute(Some("4.2")),
+            Err(ParseAttributeError::Invalid { ref value, .. }) if value == "4.2"
+        ));
+    }
+
+    #[test]
+    fn parse_bool() {
+        assert_eq!(bool::parse_attribute(Some("")), Ok(true));
+        assert_eq!(bool::parse_attribute(Some("false")), Ok(true));
+        assert_eq!(bool::parse_attribute(None), Ok(false));
+    }
+
+    #[test]
+    fn parse_option() {
+        assert_eq!(Option::<i32>::parse_attribute(None), Ok(None));
+        assert_eq!(Option::<i32>::parse_attribute(Some("7")), Ok(Some(7)));
+        assert!(Option::<i32>::parse_attribute(Some("seven")).is_err());
+        assert_eq!(
+            Option::<String>::parse_attribute(Some("")),
+            Ok(Some(String::new()))
+        );
+    }
+}
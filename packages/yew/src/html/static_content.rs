@@ -0,0 +1,105 @@
+//! This module contains the [`Static`] component.
+
+use crate::html;
+use crate::html::{Children, Component, Context, Html, Properties};
+
+/// Properties for [Static].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct StaticProps {
+    /// The Children of the current Static Component.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Marks a subtree as static for server-side rendering and hydration.
+///
+/// When rendered by a hydratable [`ServerRenderer`](crate::ServerRenderer), the children of a
+/// `<Static />` component are rendered without any hydration markers, only the `<Static />`
+/// component itself is surrounded by its component markers.
+///
+/// During hydration, the client recognises a static region by the markers of the `<Static />`
+/// component and adopts the server-rendered nodes between them as-is. The children are not
+/// hydrated, which means that nested components are not created and event listeners are not
+/// attached. If the children of a hydrated `<Static />` component change afterwards, the adopted
+/// nodes are replaced by the children rendered on the client.
+///
+/// When rendered on the client without hydration, this component renders its children as usual.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::html::Static;
+///
+/// #[function_component]
+/// fn Article() -> Html {
+///     html! { <article>{"Content that never changes."}</article> }
+/// }
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Static>
+///             <Article />
+///         </Static>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Static;
+
+impl Component for Static {
+    type Message = ();
+    type Properties = StaticProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let children = ctx.props().children.clone();
+
+        html! { <>{children}</> }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[test]
+    async fn test_static_omits_nested_markers() {
+        #[function_component]
+        fn Child() -> Html {
+            html! { <div>{"Hello!"}</div> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Static>
+                    <Child />
+                </Static>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().render().await;
+
+        // Comp and Static open and close markers.
+        assert_eq!(s.matches("<!--").count(), 4);
+        assert!(s.contains("<div>Hello!</div>"));
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Hello!</div>");
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::rc::Rc;
+
+use super::Classes;
+
+/// The name of the attribute that marks the `<style>` element of a [`Style`] with its class
+/// name, i.e.: `<style data-yew-style="yew-1a2b3c4d">`.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) const STYLE_ATTRIBUTE: &str = "data-yew-style";
+
+/// A style sheet whose rules are scoped to the elements with a generated class name.
+///
+/// Every `&` in the style sheet is replaced by the selector of the class name, e.g.:
+/// `& { color: red; } & > p { margin: 0; }`. A style sheet without `&` is a list of declarations
+/// that are applied to the elements with the class name, e.g.: `color: red;`.
+///
+/// The class name is derived from the content of the style sheet, so the same style sheet always
+/// has the same class name, on the server side and on the client side. Styles are usually created
+/// with [`use_style`](crate::functional::use_style), which also adds the style sheet to the page.
+///
+/// A style can be converted into [`Classes`] to be used as the class of an element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Style {
+    class_name: Rc<str>,
+    css: Rc<str>,
+}
+
+impl Style {
+    /// Creates a style with a class name derived from `css`.
+    pub fn new(css: &str) -> Self {
+        // FNV-1a, the class name has to be the same on all targets and compiler versions.
+        let hash = css.bytes().fold(0x811c_9dc5_u32, |hash, m| {
+            (hash ^ u32::from(m)).wrapping_mul(0x0100_0193)
+        });
+        let class_name = format!("yew-{:08x}", hash);
+
+        let selector = format!(".{}", class_name);
+        let css = if css.contains('&') {
+            css.replace('&', &selector)
+        } else {
+            format!("{} {{ {} }}", selector, css.trim())
+        };
+
+        Self {
+            class_name: class_name.into(),
+            css: css.into(),
+        }
+    }
+
+    /// Returns the generated class name.
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// Returns the style sheet, scoped to the class name.
+    pub fn css(&self) -> &str {
+        &self.css
+    }
+
+    /// Writes the `<style>` element of the style sheet.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn write_style_element(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        // The style sheet must not close the element, a `/` can be escaped in CSS.
+        write!(
+            w,
+            r#"<style {}="{}">{}</style>"#,
+            STYLE_ATTRIBUTE,
+            self.class_name,
+            self.css.replace("</", "<\\/")
+        )
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.class_name)
+    }
+}
+
+impl From<&Style> for Classes {
+    fn from(style: &Style) -> Self {
+        Self::from(style.class_name().to_owned())
+    }
+}
+
+impl From<Style> for Classes {
+    fn from(style: Style) -> Self {
+        Self::from(&style)
+    }
+}
+
+#[cfg(feature = "ssr")]
+mod feat_ssr {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    /// The styles used by the components of a server-side render, in the order they were first
+    /// used.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct StyleRegistry {
+        styles: Rc<RefCell<Vec<Style>>>,
+    }
+
+    impl StyleRegistry {
+        /// Adds `style` unless a style with the same class name has been added before.
+        pub fn insert(&self, style: &Style) {
+            let mut styles = self.styles.borrow_mut();
+            if !styles.iter().any(|m| m.class_name == style.class_name) {
+                styles.push(style.clone());
+            }
+        }
+
+        /// Returns the `<style>` elements of all styles.
+        pub fn to_html(&self) -> String {
+            let mut s = String::new();
+            for style in self.styles.borrow().iter() {
+                let _ = style.write_style_element(&mut s);
+            }
+
+            s
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::StyleRegistry;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scoped_css() {
+        let style = Style::new("color: red;");
+        assert!(style.class_name().starts_with("yew-"));
+        assert_eq!(
+            style.css(),
+            format!(".{} {{ color: red; }}", style.class_name())
+        );
+
+        let style = Style::new("& > p { margin: 0; }");
+        assert_eq!(
+            style.css(),
+            format!(".{} > p {{ margin: 0; }}", style.class_name())
+        );
+    }
+
+    #[test]
+    fn class_name_is_stable() {
+        assert_eq!(Style::new("color: red;"), Style::new("color: red;"));
+        assert_ne!(
+            Style::new("color: red;").class_name(),
+            Style::new("color: blue;").class_name()
+        );
+        assert_eq!(
+            Classes::from(&Style::new("color: red;")).to_string(),
+            Style::new("color: red;").class_name()
+        );
+    }
+}
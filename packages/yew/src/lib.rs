@@ -21,12 +21,23 @@
 //! ### Note
 //!
 //! Server-Side Rendering should work on all targets when feature `ssr` is enabled.
+//! Browser-only dependencies (e.g.: `gloo`, `console_error_panic_hook`) are only linked when
+//! feature `csr` is enabled. `web-sys`, `js-sys` and `wasm-bindgen` are always dependencies, as
+//! their types are part of the public API, e.g.: events and [`NodeRef`](html::NodeRef).
 //!
 //! ### Supported Features:
 //! - `csr`: Enables Client-side Rendering support and [`Renderer`]. Only enable this feature if you
 //!   are making a Yew application (not a library).
 //! - `ssr`: Enables Server-side Rendering support and [`ServerRenderer`].
 //! - `hydration`: Enables Hydration support.
+//! - `test-util`: Enables utilities for testing components in `yew::tests`, e.g.: for their
+//!   server-side rendered output or their number of renders. Implies `ssr`.
+//! - `debug-tooling`: Enables [`AppHandle::scope_tree`] to inspect the components of a running
+//!   application, e.g.: in development tools. Implies `csr`.
+//! - `a11y-lints`: Warns about obvious accessibility mistakes in the attributes of elements, e.g.:
+//!   an invalid `role`, during server-side rendering in debug builds. Implies `ssr`.
+//! - `tracing`: Emits a [`tracing`] span for each component and suspense rendered during
+//!   server-side rendering, e.g.: to find the components that dominate the render time.
 //!
 //! ## Example
 //!
@@ -270,7 +281,7 @@ pub use yew_macro::props;
 
 /// This module contains macros which implements html! macro and JSX-like templates
 pub mod macros {
-    pub use crate::{classes, html, html_nested, props};
+    pub use crate::{classes, data_attrs, html, html_nested, props};
 }
 
 pub mod callback;
@@ -295,8 +306,7 @@ mod app_handle;
 #[cfg(feature = "csr")]
 mod renderer;
 
-#[cfg(feature = "csr")]
-#[cfg(test)]
+#[cfg(any(all(feature = "csr", test), feature = "test-util"))]
 pub mod tests;
 
 /// The module that contains all events available in the framework.
@@ -315,8 +325,14 @@ pub mod events {
 
 #[cfg(feature = "csr")]
 pub use crate::app_handle::AppHandle;
+#[cfg(feature = "debug-tooling")]
+pub use crate::app_handle::ScopeInfo;
 #[cfg(feature = "csr")]
-pub use crate::renderer::{set_custom_panic_hook, Renderer};
+pub use crate::renderer::{
+    set_custom_panic_hook, set_custom_panic_hook_with_default, MountError, Renderer,
+};
+#[cfg(feature = "hydration")]
+pub use crate::renderer::{HydrationError, HydrationReport};
 
 pub mod prelude {
     //! The Yew Prelude
@@ -1,11 +1,17 @@
+use std::borrow::Cow;
 use std::cell::Cell;
+use std::fmt;
 use std::panic::PanicInfo;
 use std::rc::Rc;
+use std::time::Duration;
 
+use thiserror::Error;
 use web_sys::Element;
 
 use crate::app_handle::AppHandle;
-use crate::html::BaseComponent;
+use crate::callback::Callback;
+use crate::html::{BaseComponent, Scope};
+use crate::scheduler;
 
 thread_local! {
     static PANIC_HOOK_IS_SET: Cell<bool> = Cell::new(false);
@@ -20,24 +26,73 @@ pub fn set_custom_panic_hook(hook: Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 's
     PANIC_HOOK_IS_SET.with(|hook_is_set| hook_is_set.set(true));
 }
 
+/// Set a custom panic hook that runs after Yew's default panic hook.
+///
+/// The default panic hook logs the panic message with a readable stack trace to the browser
+/// console. When a panic occurs, the default panic hook always runs to completion before `hook` is
+/// called with the same [`PanicInfo`].
+///
+/// Like [set_custom_panic_hook], Yew will not overwrite the panic hook set through this function
+/// when an application is rendered with [Renderer].
+#[cfg(feature = "csr")]
+pub fn set_custom_panic_hook_with_default(
+    hook: Box<dyn Fn(&PanicInfo<'_>) + Sync + Send + 'static>,
+) {
+    set_custom_panic_hook(Box::new(move |info| {
+        console_error_panic_hook::hook(info);
+        hook(info);
+    }));
+}
+
 fn set_default_panic_hook() {
     if !PANIC_HOOK_IS_SET.with(|hook_is_set| hook_is_set.replace(true)) {
         std::panic::set_hook(Box::new(console_error_panic_hook::hook));
     }
 }
 
+/// An error returned when an application cannot be rendered, see
+/// [`try_render`](Renderer::try_render).
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MountError {
+    /// The root element does not belong to a document that is displayed in a window, e.g.:
+    /// because it was created by a `DOMParser` or its iframe was removed.
+    ///
+    /// A root that is merely detached from its document is supported, see
+    /// [`with_root_and_props`](Renderer::with_root_and_props).
+    #[error("the root element does not belong to a document that is displayed in a window.")]
+    RootNotInDocument,
+}
+
+type CreateProps<COMP> = Box<dyn FnOnce(&Element) -> <COMP as BaseComponent>::Properties>;
+
 /// The Yew Renderer.
 ///
 /// This is the main entry point of a Yew application.
 #[cfg(feature = "csr")]
-#[derive(Debug)]
 #[must_use = "Renderer does nothing unless render() is called."]
 pub struct Renderer<COMP>
 where
     COMP: BaseComponent + 'static,
 {
     root: Element,
-    props: COMP::Properties,
+    create_props: CreateProps<COMP>,
+    time_budget: Option<Duration>,
+    install_panic_hook: bool,
+    id_prefix: Option<Cow<'static, str>>,
+}
+
+impl<COMP> fmt::Debug for Renderer<COMP>
+where
+    COMP: BaseComponent + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Renderer")
+            .field("root", &self.root)
+            .field("time_budget", &self.time_budget)
+            .field("install_panic_hook", &self.install_panic_hook)
+            .field("id_prefix", &self.id_prefix)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<COMP> Default for Renderer<COMP>
@@ -82,29 +137,313 @@ where
     }
 
     /// Creates a [Renderer] that renders into a custom root with custom properties.
+    ///
+    /// The root does not have to be attached to the document. An application can be rendered
+    /// into an element created with `document.create_element`, which is then inserted into the
+    /// document in a single operation. The lifecycle of components does not depend on whether the
+    /// root is attached, e.g.: `rendered` is called even while the root is detached. Events are
+    /// handled on the root element, so the root itself has to be attached to receive them.
+    ///
+    /// Nodes are created by the document the root belongs to, so the root can also be an element
+    /// of an iframe, e.g.: `iframe.content_document().unwrap().body().unwrap().into()`.
     pub fn with_root_and_props(root: Element, props: COMP::Properties) -> Self {
-        Self { root, props }
+        Self::with_root_and_props_fn(root, move |_| props)
+    }
+
+    /// Creates a [Renderer] that renders into the document body with properties created by
+    /// `create_props`.
+    ///
+    /// See [`with_root_and_props_fn`](Self::with_root_and_props_fn) for more information.
+    pub fn with_props_fn<F>(create_props: F) -> Self
+    where
+        F: 'static + FnOnce(&Element) -> COMP::Properties,
+    {
+        Self::with_root_and_props_fn(
+            gloo::utils::document()
+                .body()
+                .expect("no body node found")
+                .into(),
+            create_props,
+        )
+    }
+
+    /// Creates a [Renderer] that renders into a custom root with properties created by
+    /// `create_props`.
+    ///
+    /// `create_props` is called with the root element once, when the application is rendered or
+    /// hydrated, before the root is cleared. This allows the properties to be derived from the
+    /// root, e.g.: from its `data-*` attributes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use yew::prelude::*;
+    /// #[derive(Properties, PartialEq)]
+    /// struct AppProps {
+    ///     user: Option<String>,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn App(props: &AppProps) -> Html {
+    ///     html! { <p>{props.user.clone().unwrap_or_default()}</p> }
+    /// }
+    ///
+    /// let root = gloo::utils::document().get_element_by_id("app").unwrap();
+    /// yew::Renderer::<App>::with_root_and_props_fn(root, |root| AppProps {
+    ///     user: root.get_attribute("data-user"),
+    /// })
+    /// .render();
+    /// ```
+    pub fn with_root_and_props_fn<F>(root: Element, create_props: F) -> Self
+    where
+        F: 'static + FnOnce(&Element) -> COMP::Properties,
+    {
+        Self {
+            root,
+            create_props: Box::new(create_props),
+            time_budget: None,
+            install_panic_hook: true,
+            id_prefix: None,
+        }
+    }
+
+    /// Sets the time the scheduler may run before it yields to the browser.
+    ///
+    /// By default, all pending updates are processed at once, which may block the main thread
+    /// for a long time, e.g.: when a long list is updated. With a time budget, the scheduler
+    /// processes updates until the budget is exhausted and continues in a new task afterwards, so
+    /// the browser can handle input and paint in between. Components are still rendered one at
+    /// a time, so a single component that renders for longer than the budget is not interrupted.
+    /// Newly created components, e.g.: while the application is hydrated, are always rendered
+    /// before the scheduler yields.
+    ///
+    /// The scheduler is shared by all applications on the same thread, the smallest budget of
+    /// the applications that have not been destroyed applies to all of them.
+    pub fn time_budget(mut self, budget: Duration) -> Self {
+        self.time_budget = Some(budget);
+
+        self
+    }
+
+    /// Sets whether the default panic hook is installed when the application is rendered.
+    ///
+    /// Defaults to `true`, which replaces the existing panic hook with one that logs panics to the
+    /// browser console, unless a hook was set with [set_custom_panic_hook]. When this is set to
+    /// `false`, Yew does not touch the panic hook, e.g.: when the application is embedded in a
+    /// larger application that manages the panic hook itself.
+    pub fn install_panic_hook(mut self, val: bool) -> Self {
+        self.install_panic_hook = val;
+
+        self
+    }
+
+    /// Sets the prefix of the ids generated with [`use_id`](crate::functional::use_id).
+    ///
+    /// Defaults to `yew`. Ids are only unique within an application, so applications or islands
+    /// that are rendered into the same page need distinct prefixes. When the application is
+    /// hydrated, the prefix has to be the one it was rendered with by the
+    /// [`ServerRenderer`](crate::ServerRenderer).
+    pub fn id_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.id_prefix = Some(prefix.into());
+
+        self
+    }
+
+    /// Applies the configuration that is shared by all applications, creates the properties and
+    /// the scope of the root component and starts the application with `start`.
+    fn init<E>(
+        self,
+        start: impl FnOnce(Element, Rc<COMP::Properties>, Scope<COMP>) -> Result<AppHandle<COMP>, E>,
+    ) -> Result<AppHandle<COMP>, E> {
+        if self.install_panic_hook {
+            set_default_panic_hook();
+        }
+
+        let mut scope = Scope::new(None);
+        if let Some(m) = self.id_prefix {
+            scope = scope.with_id_prefix(m.into());
+        }
+
+        let props = (self.create_props)(&self.root);
+        let app = start(self.root, Rc::new(props), scope)?;
+
+        // The budget is removed once the application is destroyed.
+        if let Some(m) = self.time_budget {
+            scheduler::set_time_budget(app.scope.id, m);
+        }
+
+        Ok(app)
     }
 
     /// Renders the application.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the application cannot be rendered into the root element, see
+    /// [`try_render`](Self::try_render) for a fallible variant.
     pub fn render(self) -> AppHandle<COMP> {
-        set_default_panic_hook();
-        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props))
+        self.try_render().expect("failed to render the application")
+    }
+
+    /// Renders the application, returning an error instead of panicking if it cannot be rendered
+    /// into the root element.
+    ///
+    /// The root element is left untouched if an error is returned.
+    pub fn try_render(self) -> Result<AppHandle<COMP>, MountError> {
+        self.init(|root, props, scope| {
+            AppHandle::<COMP>::mount_with_props(root, props, scope, None)
+        })
+    }
+
+    /// Renders the application and notifies `cb` once the initial render has been committed to
+    /// the DOM.
+    ///
+    /// The callback is emitted after the first `rendered` lifecycle of the root component, which
+    /// happens after the `rendered` lifecycle of all its children.
+    pub fn render_with_callback(self, cb: Callback<()>) -> AppHandle<COMP> {
+        self.init(|root, props, scope| {
+            AppHandle::<COMP>::mount_with_props(root, props, scope, Some(cb))
+        })
+        .expect("failed to render the application")
     }
 }
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use super::*;
+    use crate::html::HydrationData;
+
+    /// An error returned when an application cannot be hydrated.
+    #[derive(Error, Debug, Clone, PartialEq, Eq)]
+    pub enum HydrationError {
+        /// The root element does not belong to a document that is displayed in a window, see
+        /// [`MountError::RootNotInDocument`].
+        #[error("the root element does not belong to a document that is displayed in a window.")]
+        RootNotInDocument,
+        /// The root element does not start with the hydration marker of the root component,
+        /// e.g.: because it was not rendered by a [`ServerRenderer`](crate::ServerRenderer) or was
+        /// rendered with `hydratable(false)`.
+        #[error("the root element does not contain the hydration markers of the root component.")]
+        MissingHydrationMarkers,
+    }
+
+    impl From<MountError> for HydrationError {
+        fn from(e: MountError) -> Self {
+            match e {
+                MountError::RootNotInDocument => Self::RootNotInDocument,
+            }
+        }
+    }
+
+    /// Metrics of the hydration of an application, see
+    /// [`hydrate_with_report`](Renderer::hydrate_with_report).
+    ///
+    /// Elements that do not match the server-side rendered result cause a panic, while text
+    /// nodes are created if the server-side rendered result does not contain them. A healthy
+    /// hydration does not recreate any nodes.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct HydrationReport {
+        /// The number of elements and text nodes that were taken over from the server-side
+        /// rendered result.
+        pub hydrated_nodes: usize,
+        /// The number of text nodes that were created because the server-side rendered result
+        /// did not contain them.
+        pub recreated_nodes: usize,
+        /// The time between the start of the hydration and the initial render being committed
+        /// to the DOM.
+        pub duration: Duration,
+    }
 
     impl<COMP> Renderer<COMP>
     where
         COMP: BaseComponent + 'static,
     {
         /// Hydrates the application.
+        ///
+        /// If the root element contains the markers of the root boundary, written by
+        /// [`ServerRenderer::root_marker`](crate::ServerRenderer::root_marker), only the nodes
+        /// between them are hydrated and the other children of the root element are left
+        /// untouched.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the root element does not contain the server-side rendered result of the
+        /// application, see [`try_hydrate`](Self::try_hydrate) for a fallible variant.
         pub fn hydrate(self) -> AppHandle<COMP> {
-            set_default_panic_hook();
-            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props))
+            self.try_hydrate()
+                .expect("failed to hydrate the application")
+        }
+
+        /// Hydrates the application and notifies `cb` with a [`HydrationReport`] once the initial
+        /// render has been committed to the DOM.
+        ///
+        /// The report only covers the nodes hydrated up to the first `rendered` lifecycle of the
+        /// root component. Contents of suspended components are hydrated after they resume and
+        /// are not included.
+        pub fn hydrate_with_report(self, cb: Callback<HydrationReport>) -> AppHandle<COMP> {
+            self.init(|root, props, scope| {
+                AppHandle::<COMP>::hydrate_with_props(root, props, scope, None, Some(cb))
+            })
+            .expect("failed to hydrate the application")
+        }
+
+        /// Hydrates the application, returning an error instead of panicking if the root element
+        /// cannot be the root of an application or does not contain the hydration markers of the
+        /// root component.
+        ///
+        /// Only the presence of the markers is checked before hydration starts. If the markers
+        /// are present but the server-side rendered result differs from the application, e.g.:
+        /// because it was rendered with different properties, hydration still panics.
+        pub fn try_hydrate(self) -> Result<AppHandle<COMP>, HydrationError> {
+            if !AppHandle::<COMP>::has_hydration_markers(&self.root) {
+                return Err(HydrationError::MissingHydrationMarkers);
+            }
+
+            self.init(|root, props, scope| {
+                AppHandle::<COMP>::hydrate_with_props(root, props, scope, None, None)
+                    .map_err(HydrationError::from)
+            })
+        }
+
+        /// Hydrates a single component, an island, of a server-side rendered page.
+        ///
+        /// Unlike [`hydrate`](Self::hydrate), the root element does not have to contain only the
+        /// server-side rendered result of the component. The island is located by the first
+        /// opening hydration marker of `COMP` among the children of the root element, e.g.:
+        /// `<!--<[app::Counter]>-->`, and ends at the matching closing marker. Nodes before and
+        /// after the island are left untouched, so several islands rendered by the same page can
+        /// be hydrated independently by wrapping each of them in its own element.
+        ///
+        /// The properties must be equal to the ones the component was rendered with on the
+        /// server.
+        ///
+        /// The names of components are only written into the hydration markers in debug builds.
+        /// In release builds, the island starts at the opening hydration marker of the first
+        /// component among the children of the root element, so the root element must not contain
+        /// the markers of other components before the island.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the root element does not contain the hydration markers of `COMP`.
+        pub fn hydrate_island(self) -> AppHandle<COMP> {
+            self.init(|root, props, scope| {
+                AppHandle::<COMP>::hydrate_island_with_props(root, props, scope)
+            })
+            .expect("failed to hydrate the island")
+        }
+
+        /// Hydrates the application with prepared states that were collected separately from the
+        /// server-side rendered HTML.
+        ///
+        /// See [`HydrationData`] for more information.
+        pub fn hydrate_with_data(self, data: HydrationData) -> AppHandle<COMP> {
+            self.init(|root, props, scope| {
+                AppHandle::<COMP>::hydrate_with_props(root, props, scope, Some(data), None)
+            })
+            .expect("failed to hydrate the application")
         }
     }
 }
+
+#[cfg(feature = "hydration")]
+pub use feat_hydration::*;
@@ -3,6 +3,8 @@
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::rc::Rc;
+#[cfg(feature = "csr")]
+use std::time::Duration;
 
 /// Alias for `Rc<RefCell<T>>`
 pub type Shared<T> = Rc<RefCell<T>>;
@@ -143,10 +145,34 @@ mod feat_csr_ssr {
 #[cfg(any(feature = "ssr", feature = "csr"))]
 pub(crate) use feat_csr_ssr::*;
 
+#[cfg(feature = "csr")]
+thread_local! {
+    /// The times the scheduler runs before it yields to the browser, by the id of the root
+    /// component of the application that set them.
+    static TIME_BUDGETS: RefCell<Vec<(usize, Duration)>> = RefCell::default();
+}
+
 #[cfg(feature = "csr")]
 mod feat_csr {
     use super::*;
 
+    /// Sets the time the scheduler runs before it yields to the browser, until the application
+    /// with the root component `app_id` is destroyed.
+    pub(crate) fn set_time_budget(app_id: usize, budget: Duration) {
+        TIME_BUDGETS.with(|m| m.borrow_mut().push((app_id, budget)));
+    }
+
+    /// Removes the time budget of the application with the root component `app_id`, if any.
+    pub(crate) fn clear_time_budget(app_id: usize) {
+        TIME_BUDGETS.with(|m| m.borrow_mut().retain(|(id, _)| *id != app_id));
+    }
+
+    /// Returns the smallest time budget of all applications, if any.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn time_budget() -> Option<Duration> {
+        TIME_BUDGETS.with(|m| m.borrow().iter().map(|(_, budget)| *budget).min())
+    }
+
     pub(crate) fn push_component_rendered(
         component_id: usize,
         rendered: Box<dyn Runnable>,
@@ -187,6 +213,7 @@ pub(crate) use feat_hydration::*;
 pub(crate) fn start_now() {
     #[tracing::instrument(level = tracing::Level::DEBUG)]
     fn scheduler_loop() {
+        let time_slice = TimeSlice::start();
         let mut queue = vec![];
         loop {
             with(|s| s.fill_queue(&mut queue));
@@ -196,6 +223,12 @@ pub(crate) fn start_now() {
             for r in queue.drain(..) {
                 r.task.run();
             }
+
+            // The remaining tasks are run after the browser had the chance to handle events.
+            if time_slice.is_exhausted() && with(|s| s.can_yield()) {
+                resume_later();
+                break;
+            }
         }
     }
 
@@ -214,6 +247,13 @@ pub(crate) fn start_now() {
 
 #[cfg(target_arch = "wasm32")]
 mod arch {
+    #[cfg(feature = "csr")]
+    use gloo::events::EventListener;
+    #[cfg(feature = "csr")]
+    use wasm_bindgen::JsValue;
+    #[cfg(feature = "csr")]
+    use web_sys::MessageChannel;
+
     use crate::platform::spawn_local;
 
     /// We delay the start of the scheduler to the end of the micro task queue.
@@ -223,6 +263,63 @@ mod arch {
             super::start_now();
         });
     }
+
+    /// Continues running the scheduler in a new task, so the browser can handle events and paint
+    /// before.
+    ///
+    /// The task is started by posting a message to a [`MessageChannel`] instead of a timeout, as
+    /// browsers delay nested timeouts by at least 4ms, even if they are set to 0ms.
+    #[cfg(feature = "csr")]
+    pub(super) fn resume_later() {
+        thread_local! {
+            static CHANNEL: (MessageChannel, EventListener) = {
+                let channel = MessageChannel::new().expect("failed to create a message channel");
+                let port = channel.port1();
+                let listener = EventListener::new(&port, "message", |_| super::start_now());
+                // Messages are only dispatched to event listeners once the port is started.
+                port.start();
+
+                (channel, listener)
+            };
+        }
+
+        CHANNEL.with(|(channel, _)| {
+            channel
+                .port2()
+                .post_message(&JsValue::UNDEFINED)
+                .expect("failed to resume the scheduler");
+        });
+    }
+
+    /// The scheduler only yields with a time budget, which requires `csr`.
+    #[cfg(not(feature = "csr"))]
+    pub(super) fn resume_later() {
+        start();
+    }
+
+    /// The time a single run of the scheduler may take, see [`Renderer::time_budget`].
+    ///
+    /// [`Renderer::time_budget`]: crate::Renderer::time_budget
+    pub(super) struct TimeSlice {
+        deadline: Option<f64>,
+    }
+
+    impl TimeSlice {
+        pub fn start() -> Self {
+            #[cfg(feature = "csr")]
+            let budget = super::time_budget();
+            #[cfg(not(feature = "csr"))]
+            let budget: Option<Duration> = None;
+
+            Self {
+                deadline: budget.map(|m| js_sys::Date::now() + m.as_secs_f64() * 1000.0),
+            }
+        }
+
+        pub fn is_exhausted(&self) -> bool {
+            self.deadline.map_or(false, |m| js_sys::Date::now() >= m)
+        }
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -235,11 +332,42 @@ mod arch {
     pub(crate) fn start() {
         super::start_now();
     }
+
+    pub(super) fn resume_later() {
+        super::start_now();
+    }
+
+    /// The scheduler never yields outside of the browser.
+    pub(super) struct TimeSlice;
+
+    impl TimeSlice {
+        pub fn start() -> Self {
+            Self
+        }
+
+        pub fn is_exhausted(&self) -> bool {
+            false
+        }
+    }
 }
 
 pub(crate) use arch::*;
 
 impl Scheduler {
+    /// Returns whether the scheduler may yield to the browser.
+    ///
+    /// It only yields between batches of updates and renders. Pending creations, first renders
+    /// and priority renders, e.g.: of a tree that is being hydrated, are always finished before,
+    /// so the browser never sees a partially created tree.
+    fn can_yield(&self) -> bool {
+        self.destroy.inner.is_empty()
+            && self.create.inner.is_empty()
+            && self.render_first.inner.is_empty()
+            && self.props_update.inner.is_empty()
+            && self.render_priority.inner.is_empty()
+            && self.rendered_first.inner.is_empty()
+    }
+
     /// Fill vector with tasks to be executed according to Runnable type execution priority
     ///
     /// This method is optimized for typical usage, where possible, but does not break on
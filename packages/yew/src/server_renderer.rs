@@ -1,278 +1,2892 @@
-use std::fmt;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::{self, Write};
 use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll};
 
 use futures::pin_mut;
 use futures::stream::{Stream, StreamExt};
+use slab::Slab;
+use thiserror::Error;
 use tracing::Instrument;
 
-use crate::html::{BaseComponent, Scope};
+use crate::html::{AnyScope, BaseComponent, HydrationData, Scope, StyleRegistry};
 use crate::platform::fmt::BufStream;
 use crate::platform::{LocalHandle, Runtime};
+use crate::suspense::SuspenseCache;
+use crate::virtual_dom::vtag::{RAW_TEXT_ELEMENTS, VOID_ELEMENTS};
+use crate::virtual_dom::{
+    SsrContext, SuspenseMark, VNode, KEY_MARK, ROOT_CLOSE_MARK, ROOT_OPEN_MARK,
+};
 
-/// A Yew Server-side Renderer that renders on the current thread.
-///
-/// # Note
-///
-/// This renderer does not spawn its own runtime and can only be used when:
+/// The kind of a [`HydrationMarker`].
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HydrationMarkerKind {
+    /// The boundary of a component.
+    ///
+    /// Contains the type name of the component in debug builds.
+    Component(Option<String>),
+    /// The boundary of a suspense.
+    Suspense,
+}
+
+/// The position of a hydration marker in a server-side rendered result.
 ///
-/// - `wasm-bindgen-futures` is selected as the backend of Yew runtime.
-/// - running within a [`Runtime`](crate::platform::Runtime).
-/// - running within a tokio [`LocalSet`](struct@tokio::task::LocalSet).
+/// Hydratable results mark the boundaries of components and suspenses with HTML comments, such
+/// as `<!--<[app::App]>-->` and `<!--</[app::App]>-->`.
 #[cfg(feature = "ssr")]
-#[derive(Debug)]
-pub struct LocalServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-{
-    props: COMP::Properties,
-    hydratable: bool,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrationMarker {
+    /// The byte offset of the marker into the UTF-8 encoded result.
+    pub offset: usize,
+    /// The length of the marker in bytes.
+    pub len: usize,
+    /// Whether the marker closes a boundary, as opposed to opening it.
+    pub closing: bool,
+    /// The kind of the boundary.
+    pub kind: HydrationMarkerKind,
 }
 
-impl<COMP> Default for LocalServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-    COMP::Properties: Default,
-{
-    fn default() -> Self {
-        Self::with_props(COMP::Properties::default())
+#[cfg(feature = "ssr")]
+impl HydrationMarker {
+    /// Finds the hydration markers in a rendered result, in document order.
+    pub(crate) fn collect(html: &str) -> Vec<Self> {
+        let mut markers = Vec::new();
+        let mut pos = 0;
+
+        while let Some(start) = html[pos..].find("<!--") {
+            let offset = pos + start;
+            let body_start = offset + "<!--".len();
+            let body_end = match html[body_start..].find("-->") {
+                Some(m) => body_start + m,
+                None => break,
+            };
+            pos = body_end + "-->".len();
+
+            let (closing, kind) = match Self::parse(&html[body_start..body_end]) {
+                Some(m) => m,
+                None => continue,
+            };
+
+            markers.push(Self {
+                offset,
+                len: pos - offset,
+                closing,
+                kind,
+            });
+        }
+
+        markers
+    }
+
+    /// Parses the text of a comment as a hydration marker.
+    ///
+    /// Returns whether the marker closes a boundary and the kind of the boundary.
+    fn parse(body: &str) -> Option<(bool, HydrationMarkerKind)> {
+        let (closing, body) = match body.strip_prefix("</") {
+            Some(m) => (true, m),
+            None => (false, body.strip_prefix('<')?),
+        };
+
+        // suspenses are opened with their mark, i.e.: `<!--<?>-->` or `<!--<?defer>-->`.
+        let is_suspense = body
+            .strip_prefix('?')
+            .and_then(|m| m.strip_suffix('>'))
+            .map_or(false, |m| {
+                m == SuspenseMark::Resolved.as_str() || m == SuspenseMark::Deferred.as_str()
+            });
+        let kind = if is_suspense {
+            HydrationMarkerKind::Suspense
+        } else {
+            let name = body.strip_prefix('[')?.strip_suffix("]>")?;
+            // keyed components write their key after the name, i.e.: `<!--<[app::Item#1]>-->`.
+            let name = name.split(KEY_MARK).next().unwrap_or_default();
+            HydrationMarkerKind::Component((!name.is_empty()).then(|| name.to_owned()))
+        };
+
+        Some((closing, kind))
     }
 }
 
-impl<COMP> LocalServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-    COMP::Properties: Default,
-{
-    /// Creates a [LocalServerRenderer] with default properties.
-    pub fn new() -> Self {
-        Self::default()
+/// An element or a hydration boundary that is open while validating a rendered result.
+#[cfg(feature = "ssr")]
+enum OpenNode<'a> {
+    Element(&'a str),
+    Boundary(HydrationMarkerKind),
+}
+
+#[cfg(feature = "ssr")]
+impl fmt::Display for OpenNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Element(m) => write!(f, "element `<{}>`", m),
+            Self::Boundary(HydrationMarkerKind::Component(Some(m))) => {
+                write!(f, "boundary of component `{}`", m)
+            }
+            Self::Boundary(HydrationMarkerKind::Component(None)) => {
+                f.write_str("boundary of a component")
+            }
+            Self::Boundary(HydrationMarkerKind::Suspense) => f.write_str("boundary of a suspense"),
+        }
     }
 }
 
-impl<COMP> LocalServerRenderer<COMP>
+/// Checks the result emitted by `s` with [`validate_html`] once the stream ends, if `validate` is
+/// set.
+#[cfg(feature = "ssr")]
+fn validate_stream<S>(s: S, validate: bool) -> impl Stream<Item = String>
 where
-    COMP: BaseComponent,
+    S: Stream<Item = String> + Unpin,
 {
-    /// Creates a [LocalServerRenderer] with custom properties.
-    pub fn with_props(props: COMP::Properties) -> Self {
-        Self {
-            props,
-            hydratable: true,
+    futures::stream::unfold((s, String::new()), move |(mut s, mut html)| async move {
+        match s.next().await {
+            Some(m) => {
+                if validate {
+                    html.push_str(&m);
+                }
+
+                Some((m, (s, html)))
+            }
+            None => {
+                if validate {
+                    validate_html(&html);
+                }
+
+                None
+            }
         }
-    }
+    })
+}
 
-    /// Sets whether an the rendered result is hydratable.
-    ///
-    /// Defaults to `true`.
-    ///
-    /// When this is sets to `true`, the rendered artifact will include additional information
-    /// to assist with the hydration process.
-    pub fn hydratable(mut self, val: bool) -> Self {
-        self.hydratable = val;
+/// Checks that the elements and the hydration markers of a rendered result are balanced.
+///
+/// Void elements and self-closed elements are not required to be closed. The content of raw text
+/// elements is skipped.
+///
+/// # Panics
+///
+/// Panics with the byte offset of the offending tag or marker if the result is not well-formed.
+#[cfg(feature = "ssr")]
+fn validate_html(html: &str) {
+    fn close(open_nodes: &mut Vec<(usize, OpenNode<'_>)>, offset: usize, closing: OpenNode<'_>) {
+        let (open_offset, open) = match open_nodes.pop() {
+            Some(m) => m,
+            None => panic!(
+                "invalid render result: the closing {} at byte {} was never opened",
+                closing, offset
+            ),
+        };
 
-        self
+        let matched = match (&open, &closing) {
+            (OpenNode::Element(m), OpenNode::Element(n)) => m.eq_ignore_ascii_case(n),
+            (OpenNode::Boundary(m), OpenNode::Boundary(n)) => m == n,
+            _ => false,
+        };
+
+        if !matched {
+            panic!(
+                "invalid render result: the closing {} at byte {} does not match the {} opened at \
+                 byte {}",
+                closing, offset, open, open_offset
+            );
+        }
     }
 
-    /// Renders Yew Application.
-    pub async fn render(self) -> String {
-        let s = self.render_stream();
-        futures::pin_mut!(s);
+    let mut open_nodes = Vec::new();
+    let mut pos = 0;
+    while let Some(start) = html[pos..].find('<') {
+        let offset = pos + start;
+        let rest = &html[offset..];
 
-        s.collect().await
-    }
+        if let Some(body) = rest.strip_prefix("<!--") {
+            let end = body.find("-->").unwrap_or_else(|| {
+                panic!(
+                    "invalid render result: the comment at byte {} is never closed",
+                    offset
+                )
+            });
+            pos = offset + "<!--".len() + end + "-->".len();
 
-    /// Renders Yew Application to a String.
-    pub async fn render_to_string(self, w: &mut String) {
-        let s = self.render_stream();
-        futures::pin_mut!(s);
+            match HydrationMarker::parse(&body[..end]) {
+                Some((true, kind)) => close(&mut open_nodes, offset, OpenNode::Boundary(kind)),
+                Some((false, kind)) => open_nodes.push((offset, OpenNode::Boundary(kind))),
+                None => {}
+            }
+            continue;
+        }
 
-        while let Some(m) = s.next().await {
-            w.push_str(&m);
+        // doctypes and processing instructions.
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            pos = offset + rest.find('>').map(|m| m + 1).unwrap_or(rest.len());
+            continue;
         }
-    }
 
-    /// Renders Yew Application into a string Stream
-    #[tracing::instrument(
-        level = tracing::Level::DEBUG,
-        name = "render",
-        skip(self),
-        fields(hydratable = self.hydratable),
-    )]
-    pub fn render_stream(self) -> impl Stream<Item = String> {
-        let scope = Scope::<COMP>::new(None);
+        let (closing, name) = match rest.strip_prefix("</") {
+            Some(m) => (true, m),
+            None => (false, &rest[1..]),
+        };
+        let name = &name[..name
+            .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+            .unwrap_or(name.len())];
 
-        let outer_span = tracing::Span::current();
-        BufStream::new(move |mut w| async move {
-            let render_span = tracing::debug_span!("render_stream_item");
-            render_span.follows_from(outer_span);
-            scope
-                .render_into_stream(&mut w, self.props.into(), self.hydratable)
-                .instrument(render_span)
-                .await;
-        })
+        // a `<` that does not start a tag is text.
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            pos = offset + 1;
+            continue;
+        }
+
+        // attribute values may contain `>`.
+        let mut quote = None;
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| match quote {
+                Some(m) if m == *c => {
+                    quote = None;
+                    false
+                }
+                Some(_) => false,
+                None if *c == '"' || *c == '\'' => {
+                    quote = Some(*c);
+                    false
+                }
+                None => *c == '>',
+            })
+            .map(|(m, _)| m)
+            .unwrap_or_else(|| {
+                panic!(
+                    "invalid render result: the tag `<{}{}` at byte {} is not terminated",
+                    if closing { "/" } else { "" },
+                    name,
+                    offset
+                )
+            });
+        pos = offset + end + 1;
+
+        if closing {
+            close(&mut open_nodes, offset, OpenNode::Element(name));
+        } else if rest[..end].ends_with('/')
+            || VOID_ELEMENTS.iter().any(|m| m.eq_ignore_ascii_case(name))
+        {
+            // void and self-closed elements have no closing tag.
+        } else {
+            if RAW_TEXT_ELEMENTS
+                .iter()
+                .any(|m| m.eq_ignore_ascii_case(name))
+                || name.eq_ignore_ascii_case("textarea")
+            {
+                // the text is skipped until the closing tag, which is checked in the next
+                // iteration.
+                let closing_tag = format!("</{}", name.to_ascii_lowercase());
+                pos += html[pos..]
+                    .to_ascii_lowercase()
+                    .find(&closing_tag)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "invalid render result: the element `<{}>` at byte {} is never closed",
+                            name, offset
+                        )
+                    });
+            }
+
+            open_nodes.push((offset, OpenNode::Element(name)));
+        }
+    }
+
+    if let Some((offset, open)) = open_nodes.pop() {
+        panic!(
+            "invalid render result: the {} at byte {} is never closed",
+            open, offset
+        );
     }
 }
 
-/// A Yew Server-side Renderer.
+/// A cache of server-side rendered components that is shared between renders.
 ///
-/// This renderer spawns the rendering task to a Yew [`Runtime`]. and receives result when
-/// the rendering process has finished.
+/// Components are only cached if they are rendered with
+/// [`VChild::ssr_cached`](crate::virtual_dom::VChild::ssr_cached), their rendered result is
+/// memoized by their type and their properties. A cached component is not created again when it
+/// is rendered with equal properties, so its result must only depend on its properties, e.g.: not
+/// on contexts or prepared states.
 ///
-/// See [`yew::platform`] for more information.
+/// The cache is only used for results that are neither
+/// [hydratable](LocalServerRenderer::hydratable) nor [pretty-printed](LocalServerRenderer::pretty),
+/// as hydration markers and indentation depend on where a component is rendered. It is not used by
+/// [`render_with_styles`](LocalServerRenderer::render_with_styles) either, as the styles of the
+/// components in a cached result would not be collected.
+///
+/// The cache can be cloned cheaply and shared between renderers on different threads. When it is
+/// full, the least recently used result is evicted.
 #[cfg(feature = "ssr")]
-pub struct ServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-{
-    create_props: Box<dyn Send + FnOnce() -> COMP::Properties>,
-    hydratable: bool,
-    rt: Option<Runtime>,
+#[derive(Debug, Clone)]
+pub struct SsrCache {
+    inner: Arc<Mutex<SsrCacheInner>>,
 }
 
-impl<COMP> fmt::Debug for ServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("ServerRenderer<_>")
-    }
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+struct SsrCacheInner {
+    capacity: usize,
+    // the index of each key in `entries`.
+    indices: HashMap<SsrCacheKey, usize>,
+    // linked from the least to the most recently used, so a result is used and evicted in O(1).
+    entries: Slab<SsrCacheEntry>,
+    // the least recently used entry.
+    first: Option<usize>,
+    // the most recently used entry.
+    last: Option<usize>,
 }
 
-impl<COMP> Default for ServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-    COMP::Properties: Default,
-{
-    fn default() -> Self {
-        Self::with_props(Default::default)
-    }
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+struct SsrCacheEntry {
+    key: SsrCacheKey,
+    html: Arc<str>,
+    // the entry that was used before this one.
+    prev: Option<usize>,
+    // the entry that was used after this one.
+    next: Option<usize>,
 }
 
-impl<COMP> ServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-    COMP::Properties: Default,
-{
-    /// Creates a [ServerRenderer] with default properties.
-    pub fn new() -> Self {
-        Self::default()
+#[cfg(feature = "ssr")]
+impl SsrCacheInner {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            indices: HashMap::with_capacity(capacity),
+            entries: Slab::with_capacity(capacity),
+            first: None,
+            last: None,
+        }
     }
-}
 
-impl<COMP> ServerRenderer<COMP>
-where
-    COMP: BaseComponent,
-{
-    /// Creates a [ServerRenderer] with custom properties.
-    ///
-    /// # Note
-    ///
-    /// The properties does not have to implement `Send`.
-    /// However, the function to create properties needs to be `Send`.
-    pub fn with_props<F>(create_props: F) -> Self
-    where
-        F: 'static + Send + FnOnce() -> COMP::Properties,
-    {
-        Self {
-            create_props: Box::new(create_props),
-            hydratable: true,
-            rt: None,
+    /// Removes the entry at `index` from the list, without removing it from the cache.
+    fn unlink(&mut self, index: usize) {
+        let SsrCacheEntry { prev, next, .. } = self.entries[index];
+
+        match prev {
+            Some(m) => self.entries[m].next = next,
+            None => self.first = next,
+        }
+        match next {
+            Some(m) => self.entries[m].prev = prev,
+            None => self.last = prev,
         }
     }
 
-    /// Sets the runtime the ServerRenderer will run the rendering task with.
-    pub fn with_runtime(mut self, rt: Runtime) -> Self {
-        self.rt = Some(rt);
+    /// Appends the entry at `index` to the list as the most recently used one.
+    fn push_back(&mut self, index: usize) {
+        let entry = &mut self.entries[index];
+        entry.prev = self.last;
+        entry.next = None;
 
-        self
+        match self.last {
+            Some(m) => self.entries[m].next = Some(index),
+            None => self.first = Some(index),
+        }
+        self.last = Some(index);
     }
 
-    /// Sets whether an the rendered result is hydratable.
-    ///
-    /// Defaults to `true`.
-    ///
-    /// When this is sets to `true`, the rendered artifact will include additional information
-    /// to assist with the hydration process.
-    pub fn hydratable(mut self, val: bool) -> Self {
-        self.hydratable = val;
+    fn get(&mut self, key: &SsrCacheKey) -> Option<Arc<str>> {
+        let index = *self.indices.get(key)?;
+        self.unlink(index);
+        self.push_back(index);
 
-        self
+        Some(self.entries[index].html.clone())
     }
 
-    /// Renders Yew Application.
-    pub async fn render(self) -> String {
-        let Self {
-            create_props,
-            hydratable,
-            rt,
-        } = self;
+    fn insert(&mut self, key: SsrCacheKey, html: Arc<str>) {
+        if self.capacity == 0 {
+            return;
+        }
 
-        let (tx, rx) = futures::channel::oneshot::channel();
-        let create_task = move || async move {
-            let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
-                .hydratable(hydratable)
-                .render()
-                .await;
+        if let Some(&index) = self.indices.get(&key) {
+            self.entries[index].html = html;
+            self.unlink(index);
+            self.push_back(index);
 
-            let _ = tx.send(s);
-        };
+            return;
+        }
 
-        Self::spawn_rendering_task(rt, create_task);
+        if self.entries.len() == self.capacity {
+            if let Some(index) = self.first {
+                self.indices.remove(&self.entries[index].key);
+                self.unlink(index);
+                self.entries.remove(index);
+            }
+        }
 
-        rx.await.expect("failed to render application")
+        let index = self.entries.vacant_key();
+        self.indices.insert(key.clone(), index);
+        self.entries.insert(SsrCacheEntry {
+            key,
+            html,
+            prev: None,
+            next: None,
+        });
+        self.push_back(index);
     }
 
-    /// Renders Yew Application to a String.
-    pub async fn render_to_string(self, w: &mut String) {
-        let mut s = self.render_stream();
-
-        while let Some(m) = s.next().await {
-            w.push_str(&m);
-        }
+    fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+        self.first = None;
+        self.last = None;
     }
+}
 
-    #[inline]
-    fn spawn_rendering_task<F, Fut>(rt: Option<Runtime>, create_task: F)
+/// A component rendered with [`VChild::ssr_cached`](crate::virtual_dom::VChild::ssr_cached).
+#[cfg(feature = "ssr")]
+#[derive(Clone)]
+pub(crate) struct CachedComponent {
+    type_id: TypeId,
+    // the hash of the type and the properties.
+    hash: u64,
+    props: Arc<dyn CachedProps>,
+}
+
+#[cfg(feature = "ssr")]
+impl CachedComponent {
+    pub(crate) fn new<COMP, P>(props: &P) -> Self
     where
-        F: 'static + Send + FnOnce() -> Fut,
-        Fut: Future<Output = ()> + 'static,
+        COMP: 'static,
+        P: 'static + Hash + PartialEq + Clone + Send + Sync,
     {
-        match rt {
-            // If a runtime is specified, spawn to the specified runtime.
-            Some(m) => m.spawn_pinned(create_task),
-            None => match LocalHandle::try_current() {
-                // If within a Yew Runtime, spawn to the current runtime.
-                Some(m) => m.spawn_local(create_task()),
-                // Outside of Yew Runtime, spawn to the default runtime.
-                None => Runtime::default().spawn_pinned(create_task),
-            },
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<COMP>().hash(&mut hasher);
+        props.hash(&mut hasher);
+
+        Self {
+            type_id: TypeId::of::<COMP>(),
+            hash: hasher.finish(),
+            props: Arc::new(props.clone()),
         }
     }
+}
 
-    /// Renders Yew Application into a string Stream.
-    pub fn render_stream(self) -> impl Send + Stream<Item = String> {
-        let Self {
-            create_props,
-            hydratable,
-            rt,
-        } = self;
+#[cfg(feature = "ssr")]
+impl fmt::Debug for CachedComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedComponent")
+            .field("type_id", &self.type_id)
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
 
-        let (tx, rx) = futures::channel::mpsc::unbounded();
-        let create_task = move || async move {
-            let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
-                .hydratable(hydratable)
-                .render_stream();
-            pin_mut!(s);
+/// The properties of a cached component.
+///
+/// They are compared on lookup, so components whose properties have the same hash never share a
+/// result.
+#[cfg(feature = "ssr")]
+trait CachedProps: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn props_eq(&self, other: &dyn CachedProps) -> bool;
+}
+
+#[cfg(feature = "ssr")]
+impl<T> CachedProps for T
+where
+    T: 'static + PartialEq + Send + Sync,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn props_eq(&self, other: &dyn CachedProps) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+/// The key of a result in the [`SsrCache`].
+///
+/// The result of a component also depends on the options of the renderer, e.g.: whether it is
+/// rendered as XHTML, so they are part of the key.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone)]
+pub(crate) struct SsrCacheKey {
+    component: CachedComponent,
+    // xhtml, defer_suspense, collapse_whitespace and the id of the url rewriter.
+    options: (bool, bool, bool, Option<u64>),
+}
+
+#[cfg(feature = "ssr")]
+impl SsrCacheKey {
+    pub(crate) fn new(component: CachedComponent, ctx: &SsrContext) -> Self {
+        Self {
+            component,
+            options: (
+                ctx.xhtml,
+                ctx.defer_suspense,
+                ctx.collapse_whitespace,
+                ctx.url_rewriter.as_ref().map(UrlRewriter::id),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl Hash for SsrCacheKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.component.hash.hash(state);
+        self.options.hash(state);
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl PartialEq for SsrCacheKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.component.type_id == other.component.type_id
+            && self.options == other.options
+            && self
+                .component
+                .props
+                .as_ref()
+                .props_eq(other.component.props.as_ref())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl Eq for SsrCacheKey {}
+
+#[cfg(feature = "ssr")]
+impl SsrCache {
+    /// Creates a cache that holds the results of up to `capacity` components.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SsrCacheInner::with_capacity(capacity))),
+        }
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if no result is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    pub(crate) fn get(&self, key: &SsrCacheKey) -> Option<Arc<str>> {
+        self.lock().get(key)
+    }
+
+    pub(crate) fn insert(&self, key: SsrCacheKey, html: Arc<str>) {
+        self.lock().insert(key, html);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SsrCacheInner> {
+        // the entries are always consistent, even if a thread panicked while holding the lock.
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The attributes a [`UrlRewriter`] is applied to by default.
+#[cfg(feature = "ssr")]
+const DEFAULT_URL_ATTRIBUTES: &[&str] = &["src", "href", "srcset"];
+
+/// Rewrites URLs in the attributes of server-side rendered elements.
+///
+/// A rewriter is registered with [`LocalServerRenderer::url_rewriter`], e.g.: to prefix the
+/// URLs of assets with the base path of a CDN. It is called with the name and the value of every
+/// attribute it applies to, before the value is escaped. By default, it applies to `src`, `href`
+/// and `srcset`, see [`UrlRewriter::attributes`]. The value of `srcset` is passed as a whole,
+/// including the descriptors of its candidates.
+///
+/// The rewriter only changes the rendered HTML. The virtual DOM the client renders still contains
+/// the original URLs, so the attributes are set to them during hydration.
+///
+/// The rewriter can be cloned cheaply and shared between renderers on different threads.
+/// Components rendered with [`VChild::ssr_cached`](crate::virtual_dom::VChild::ssr_cached) are
+/// cached separately for each rewriter, clones of a rewriter share their cached results.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use yew::UrlRewriter;
+///
+/// let rewriter = UrlRewriter::new(|_name, value| {
+///     if value.starts_with('/') {
+///         Cow::Owned(format!("https://cdn.example.com{}", value))
+///     } else {
+///         Cow::Borrowed(value)
+///     }
+/// });
+/// ```
+#[cfg(feature = "ssr")]
+#[derive(Clone)]
+pub struct UrlRewriter {
+    id: u64,
+    attributes: Arc<[Cow<'static, str>]>,
+    rewrite: Arc<RewriteFn>,
+}
+
+#[cfg(feature = "ssr")]
+type RewriteFn = dyn Send + Sync + for<'a> Fn(&str, &'a str) -> Cow<'a, str>;
+
+#[cfg(feature = "ssr")]
+impl UrlRewriter {
+    /// Creates a rewriter that applies `f` to the default attributes.
+    ///
+    /// `f` is called with the name and the value of the attribute and returns the new value.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: 'static + Send + Sync + for<'a> Fn(&str, &'a str) -> Cow<'a, str>,
+    {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            attributes: DEFAULT_URL_ATTRIBUTES
+                .iter()
+                .map(|&m| Cow::Borrowed(m))
+                .collect(),
+            rewrite: Arc::new(f),
+        }
+    }
+
+    /// Sets the attributes the rewriter applies to, replacing the default ones.
+    ///
+    /// Attribute names are compared case-insensitively.
+    pub fn attributes<I, S>(mut self, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.attributes = attributes.into_iter().map(Into::into).collect();
+
+        self
+    }
+
+    /// Rewrites the value of the attribute `name` if the rewriter applies to it.
+    pub(crate) fn rewrite<'a>(&self, name: &str, value: &'a str) -> Cow<'a, str> {
+        if self.attributes.iter().any(|m| m.eq_ignore_ascii_case(name)) {
+            (self.rewrite)(name, value)
+        } else {
+            Cow::Borrowed(value)
+        }
+    }
+
+    /// Identifies the rewriter in the keys of cached components.
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl fmt::Debug for UrlRewriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UrlRewriter")
+            .field("attributes", &self.attributes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error that aborted server-side rendering, see [`LocalServerRenderer::try_render`].
+#[cfg(feature = "ssr")]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ServerRenderError {
+    /// A component panicked while it was rendered.
+    #[error("a component panicked while rendering: {message}")]
+    Panicked {
+        /// The message of the panic, if the panic was raised with a string.
+        message: String,
+    },
+}
+
+#[cfg(feature = "ssr")]
+impl ServerRenderError {
+    fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(m) => *m,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(m) => (*m).to_owned(),
+                Err(_) => "Box<dyn Any>".to_owned(),
+            },
+        };
+
+        Self::Panicked { message }
+    }
+}
+
+/// The UTF-8 byte order mark written before the rendered result if enabled.
+const BOM: &str = "\u{feff}";
+
+/// The number of suspenses that may be nested by default, see
+/// [`LocalServerRenderer::max_suspense_depth`].
+pub(crate) const DEFAULT_MAX_SUSPENSE_DEPTH: usize = 128;
+
+/// The number of bytes a rendered stream buffers before it emits a chunk by default.
+const DEFAULT_FLUSH_THRESHOLD: usize = 8 * 1024;
+
+/// Buffers the chunks of a rendered stream until at least `threshold` bytes are available.
+///
+/// Buffered bytes are emitted early when the rendering is waiting, e.g.: for a suspended
+/// component, so the client receives everything rendered so far. Bytes that are rendered at once
+/// are split into chunks of about `threshold` bytes.
+struct FlushThreshold<S> {
+    inner: Pin<Box<S>>,
+    threshold: usize,
+    buf: String,
+    // the number of bytes of `buf` that have been emitted.
+    offset: usize,
+    done: bool,
+}
+
+impl<S> FlushThreshold<S>
+where
+    S: Stream<Item = String>,
+{
+    fn new(inner: S, threshold: usize) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            threshold,
+            buf: String::new(),
+            offset: 0,
+            done: false,
+        }
+    }
+
+    /// Takes about `threshold` bytes from the buffer, or all of them if `all` is set.
+    fn take(&mut self, all: bool) -> String {
+        let rest = &self.buf[self.offset..];
+        let len = match rest.len() {
+            len if all || len <= self.threshold || self.threshold == 0 => len,
+            _ => (self.threshold..)
+                .find(|&m| rest.is_char_boundary(m))
+                .expect("the end of the buffer is a char boundary"),
+        };
+
+        let chunk = rest[..len].to_owned();
+        self.offset += len;
+        if self.offset == self.buf.len() {
+            self.buf.clear();
+            self.offset = 0;
+        }
+
+        chunk
+    }
+}
+
+impl<S> Stream for FlushThreshold<S>
+where
+    S: Stream<Item = String>,
+{
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            let buffered = this.buf.len() - this.offset;
+            if buffered > 0 && buffered >= this.threshold {
+                return Poll::Ready(Some(this.take(false)));
+            }
+
+            if this.done {
+                return match buffered {
+                    0 => Poll::Ready(None),
+                    _ => Poll::Ready(Some(this.take(true))),
+                };
+            }
+
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(m)) => {
+                    if this.buf.is_empty() {
+                        this.buf = m;
+                    } else {
+                        this.buf.push_str(&m);
+                    }
+                }
+                Poll::Ready(None) => this.done = true,
+                // the rendering is waiting, flushes what has been rendered so far.
+                Poll::Pending if buffered > 0 => return Poll::Ready(Some(this.take(true))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// A Yew Server-side Renderer that renders on the current thread.
+///
+/// # Note
+///
+/// This renderer does not spawn its own runtime. The rendering futures do not depend on a specific
+/// async runtime and can be driven by any single-threaded executor, e.g.:
+/// `futures::executor::block_on`. However, components that spawn tasks, e.g.: by suspending on a
+/// future with [`Suspension::from_future`](crate::suspense::Suspension::from_future), or use
+/// timers, can only be rendered when:
+///
+/// - `wasm-bindgen-futures` is selected as the backend of Yew runtime.
+/// - running within a [`Runtime`](crate::platform::Runtime).
+/// - running within a tokio [`LocalSet`](struct@tokio::task::LocalSet).
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+pub struct LocalServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+{
+    props: COMP::Properties,
+    hydratable: bool,
+    pretty: bool,
+    defer_suspense: bool,
+    xhtml: bool,
+    hydratable_children: bool,
+    root_marker: bool,
+    collapse_whitespace: bool,
+    validate: bool,
+    bom: bool,
+    cache: Option<SsrCache>,
+    suspense_cache: Option<SuspenseCache>,
+    styles: Option<StyleRegistry>,
+    url_rewriter: Option<UrlRewriter>,
+    flush_threshold: usize,
+    max_suspense_depth: usize,
+    id_prefix: Option<Cow<'static, str>>,
+}
+
+impl<COMP> Default for LocalServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    fn default() -> Self {
+        Self::with_props(COMP::Properties::default())
+    }
+}
+
+impl<COMP> LocalServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    /// Creates a [LocalServerRenderer] with default properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<COMP> LocalServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+{
+    /// Creates a [LocalServerRenderer] with custom properties.
+    pub fn with_props(props: COMP::Properties) -> Self {
+        Self {
+            props,
+            hydratable: true,
+            pretty: false,
+            defer_suspense: false,
+            xhtml: false,
+            hydratable_children: false,
+            root_marker: false,
+            collapse_whitespace: false,
+            validate: false,
+            bom: false,
+            cache: None,
+            suspense_cache: None,
+            styles: None,
+            url_rewriter: None,
+            flush_threshold: DEFAULT_FLUSH_THRESHOLD,
+            max_suspense_depth: DEFAULT_MAX_SUSPENSE_DEPTH,
+            id_prefix: None,
+        }
+    }
+
+    /// Sets whether an the rendered result is hydratable.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// When this is sets to `true`, the rendered artifact will include additional information
+    /// to assist with the hydration process.
+    pub fn hydratable(mut self, val: bool) -> Self {
+        self.hydratable = val;
+
+        self
+    }
+
+    /// Sets whether the rendered result is pretty-printed.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, block-level elements are put on their own lines and indented
+    /// by their depth, which makes the rendered result suitable for snapshot testing. The content
+    /// of whitespace-sensitive elements (e.g.: `<pre>` and `<textarea>`) is left untouched.
+    ///
+    /// This option is ignored if the rendered result is hydratable, as hydration expects the
+    /// exact nodes the application renders.
+    pub fn pretty(mut self, val: bool) -> Self {
+        self.pretty = val;
+
+        self
+    }
+
+    /// Sets whether suspense fallbacks are rendered instead of waiting for suspended children.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// By default, the server waits for every [`Suspense`](crate::suspense::Suspense) to resume
+    /// and renders its children. When this is set to `true`, the fallback of every `Suspense` is
+    /// rendered instead and its children are not rendered on the server at all, so a slow
+    /// suspension never delays the response.
+    ///
+    /// When the result is hydratable, such a `Suspense` is marked as deferred. During hydration,
+    /// the rendered fallback is kept in the document while the children are rendered from scratch
+    /// on the client. It is replaced by the children once they are no longer suspended.
+    pub fn defer_suspense(mut self, val: bool) -> Self {
+        self.defer_suspense = val;
+
+        self
+    }
+
+    /// Sets whether elements are serialized as XHTML.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, void elements are self-closed (e.g.: `<br/>` instead of
+    /// `<br>`) and boolean attributes are given their name as value (e.g.: `disabled="disabled"`),
+    /// so the rendered result is well-formed XML that can be embedded into XHTML documents,
+    /// feeds or e-mails. All other elements are always explicitly closed. As HTML parsers
+    /// ignore the trailing slash of void elements, the result can still be parsed and hydrated
+    /// as HTML.
+    ///
+    /// The content of [`VRaw`](crate::virtual_dom::VRaw) nodes is written as is and has to be
+    /// well-formed by itself.
+    pub fn xhtml(mut self, val: bool) -> Self {
+        self.xhtml = val;
+
+        self
+    }
+
+    /// Sets whether only the children of the rendered component are hydratable.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the hydration markers of the rendered component itself, i.e.:
+    /// `<!--<[app::App]>-->` and `<!--</[app::App]>-->`, and its prepared state are omitted. The
+    /// markers of all components and suspenses it renders are kept, so their boundaries can still
+    /// be hydrated. This is useful to embed the rendered result as static content into a page
+    /// that is already hydrated.
+    ///
+    /// This option is ignored if the rendered result is not hydratable.
+    pub fn hydratable_children(mut self, val: bool) -> Self {
+        self.hydratable_children = val;
+
+        self
+    }
+
+    /// Sets whether the rendered result is wrapped in the markers of the root boundary.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the rendered result is enclosed by `<!--<yew-root>-->` and
+    /// `<!--</yew-root>-->`. The markers are distinct from the markers of components and
+    /// suspenses and are not reported by [`render_with_markers`](Self::render_with_markers).
+    /// [`Renderer::hydrate`](crate::Renderer::hydrate) hydrates the application between these
+    /// markers and leaves all other children of the root element untouched, so the rendered result
+    /// can be embedded into a template that adds its own markup around it, e.g.: in the `<body>`
+    /// of a page.
+    ///
+    /// This option is ignored if the rendered result is not hydratable.
+    pub fn root_marker(mut self, val: bool) -> Self {
+        self.root_marker = val;
+
+        self
+    }
+
+    /// Sets whether runs of whitespace in texts are collapsed.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, every run of ASCII whitespace in a text is written as a single
+    /// space, like the browser renders it for elements with `white-space: normal`. This reduces
+    /// the size of the rendered result. Whitespace is neither trimmed nor removed, as it may
+    /// separate inline content, and runs spanning several adjacent texts are collapsed separately.
+    ///
+    /// The content of `<pre>`, `<textarea>`, `<script>` and `<style>` elements is left untouched.
+    /// Elements whose whitespace is preserved by CSS, e.g.: with `white-space: pre`, are not
+    /// detected and must not contain collapsible whitespace when this option is enabled.
+    ///
+    /// During hydration, texts whose whitespace was collapsed are replaced with the texts of the
+    /// application.
+    pub fn collapse_whitespace(mut self, val: bool) -> Self {
+        self.collapse_whitespace = val;
+
+        self
+    }
+
+    /// Sets whether the rendered result is checked to be well-formed.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the result of every rendering method, except
+    /// [`render_text_content`](Self::render_text_content), is checked for unbalanced tags and
+    /// hydration markers after rendering. Rendering panics with the position of the first
+    /// offending tag or marker, which helps to catch malformed content written with
+    /// [`VNode::from_html_unchecked`](crate::virtual_dom::VNode::from_html_unchecked).
+    ///
+    /// Void elements (e.g.: `<br>` and `<input>`) and self-closed elements do not have to be
+    /// closed, and the content of `<script>`, `<style>` and `<textarea>` elements is not checked.
+    /// Streamed results, i.e.: of [`render_stream`](Self::render_stream) and
+    /// [`render_to_writer`](Self::render_to_writer), are checked once the stream ends, after the
+    /// last chunk has been emitted.
+    ///
+    /// This option is ignored in release builds.
+    pub fn validate(mut self, val: bool) -> Self {
+        self.validate = cfg!(debug_assertions) && val;
+
+        self
+    }
+
+    /// Sets whether the rendered result starts with a UTF-8 byte order mark (`U+FEFF`).
+    ///
+    /// Defaults to `false`.
+    ///
+    /// The rendered result is always encoded as UTF-8. Some consumers, e.g.: legacy e-mail
+    /// clients, only detect the encoding of a document with a byte order mark. When this is set to
+    /// `true`, the mark is written before the rendered result by every rendering method, including
+    /// streams. [`render_text_content`](Self::render_text_content) is not affected.
+    pub fn bom(mut self, val: bool) -> Self {
+        self.bom = val;
+
+        self
+    }
+
+    /// Sets the cache of components rendered with
+    /// [`VChild::ssr_cached`](crate::virtual_dom::VChild::ssr_cached).
+    ///
+    /// By default, no component is cached. The cache is shared by all renderers it is passed to,
+    /// so components can be reused across requests. See [`SsrCache`] for more information.
+    pub fn cache(mut self, cache: SsrCache) -> Self {
+        self.cache = Some(cache);
+
+        self
+    }
+
+    /// Sets the cache of the results of
+    /// [`use_future_with_key`](crate::suspense::use_future_with_key).
+    ///
+    /// By default, every render starts with an empty cache. Values inserted into `cache` before
+    /// rendering are used without suspending. See [`SuspenseCache`] for more information.
+    pub fn suspense_cache(mut self, cache: SuspenseCache) -> Self {
+        self.suspense_cache = Some(cache);
+
+        self
+    }
+
+    /// Sets the rewriter of URLs in the attributes of rendered elements.
+    ///
+    /// By default, URLs are rendered as they are. See [`UrlRewriter`] for more information.
+    pub fn url_rewriter(mut self, rewriter: UrlRewriter) -> Self {
+        self.url_rewriter = Some(rewriter);
+
+        self
+    }
+
+    /// Sets the number of bytes [`render_stream`](Self::render_stream) buffers before it emits a
+    /// chunk.
+    ///
+    /// Defaults to 8 KiB.
+    ///
+    /// A small threshold lets the client receive the rendered result earlier, while a large one
+    /// reduces the number of writes. Regardless of the threshold, the buffered result is emitted
+    /// whenever rendering waits for a suspended component and once rendering completes. Results
+    /// that are rendered at once are split into chunks of about `bytes` bytes. A threshold of `0`
+    /// emits everything as soon as it is rendered, without splitting it.
+    pub fn flush_threshold(mut self, bytes: usize) -> Self {
+        self.flush_threshold = bytes;
+
+        self
+    }
+
+    /// Sets the number of suspenses that may be nested in each other.
+    ///
+    /// Defaults to `128`.
+    ///
+    /// A component that renders the suspense it is rendered in, e.g.: because it suspends on the
+    /// same resource again, nests suspenses without bound. Rendering panics once the limit is
+    /// exceeded, instead of consuming memory until the process runs out of it.
+    pub fn max_suspense_depth(mut self, depth: usize) -> Self {
+        self.max_suspense_depth = depth;
+
+        self
+    }
+
+    /// Sets the prefix of the ids generated with [`use_id`](crate::functional::use_id).
+    ///
+    /// Defaults to `yew`. Ids are only unique within an application, so applications or islands
+    /// that are rendered into the same page need distinct prefixes. The application has to be
+    /// hydrated with the same prefix, see [`Renderer::id_prefix`](crate::Renderer::id_prefix).
+    pub fn id_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.id_prefix = Some(prefix.into());
+
+        self
+    }
+
+    /// Renders Yew Application.
+    pub async fn render(self) -> String {
+        let validate = self.validate;
+
+        let s = self.render_stream_with(None, None);
+        futures::pin_mut!(s);
+
+        let html: String = s.collect().await;
+        if validate {
+            validate_html(&html);
+        }
+
+        html
+    }
+
+    /// Renders Yew Application, returning the output rendered so far if rendering fails.
+    ///
+    /// If a component panics while it is rendered, the panic is caught and the output rendered
+    /// before the panic, e.g.: the shell of a page, is returned with the error. A server may send
+    /// the partial output with a client-side error boundary instead of failing the whole response.
+    /// The partial output is not well-formed, the elements it opens are never closed.
+    ///
+    /// Panics of futures that components suspend on are not caught, as they are not polled by the
+    /// renderer. On targets that abort on panics, e.g.: `wasm32-unknown-unknown`, panics can't be
+    /// caught at all.
+    pub async fn try_render(self) -> Result<String, (String, ServerRenderError)> {
+        let validate = self.validate;
+        let caught_panic = Rc::new(Cell::new(None));
+
+        let s = self.render_stream_with(None, Some(caught_panic.clone()));
+        futures::pin_mut!(s);
+
+        let html: String = s.collect().await;
+        if let Some(e) = caught_panic.take() {
+            return Err((html, e));
+        }
+        if validate {
+            validate_html(&html);
+        }
+
+        Ok(html)
+    }
+
+    /// Renders Yew Application to a String.
+    ///
+    /// The rendered result is appended to `w`. A server can reuse the same String for
+    /// consecutive renders by clearing it after each response, which keeps its allocated
+    /// capacity and avoids growing a new buffer for every render.
+    pub async fn render_to_string(self, w: &mut String) {
+        let validate = self.validate;
+        let start = w.len();
+
+        let s = self.render_stream_with(None, None);
+        futures::pin_mut!(s);
+
+        while let Some(m) = s.next().await {
+            w.push_str(&m);
+        }
+
+        if validate {
+            validate_html(&w[start..]);
+        }
+    }
+
+    /// Renders Yew Application to a [`Write`](io::Write).
+    ///
+    /// The rendered result is written as it becomes available. If writing fails, e.g.: because
+    /// the client disconnected, rendering is aborted and the error is returned.
+    pub async fn render_to_writer<W>(self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let s = self.render_stream();
+        futures::pin_mut!(s);
+
+        while let Some(m) = s.next().await {
+            w.write_all(m.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders Yew Application and returns its text content.
+    ///
+    /// This is analogous to the DOM's
+    /// [`textContent`](https://developer.mozilla.org/en-US/docs/Web/API/Node/textContent) of the
+    /// rendered application. Tags and attributes are skipped and suspended components are waited
+    /// for, the text of suspense fallbacks is never collected.
+    pub async fn render_text_content(self) -> String {
+        let scope = Self::create_scope(self.suspense_cache, self.id_prefix);
+
+        let mut s = String::new();
+        scope.collect_text_content(&mut s, self.props.into()).await;
+
+        s
+    }
+
+    /// Renders Yew Application and collects the prepared states of components separately.
+    ///
+    /// The rendered HTML refers to each prepared state by its id in the returned
+    /// [`HydrationData`], see [`HydrationData`] for more information.
+    pub async fn render_with_hydration_data(self) -> (String, HydrationData) {
+        let validate = self.validate;
+        let hydration_data = Rc::new(RefCell::new(HydrationData::default()));
+
+        let s = self.render_stream_with(Some(hydration_data.clone()), None);
+        futures::pin_mut!(s);
+
+        let html: String = s.collect().await;
+        if validate {
+            validate_html(&html);
+        }
+
+        (html, hydration_data.take())
+    }
+
+    /// Renders Yew Application and collects the styles used by its components separately.
+    ///
+    /// The second string contains a `<style>` element for each distinct
+    /// [`Style`](crate::html::Style) that was used with
+    /// [`use_style`](crate::functional::use_style) while rendering, to be placed in the `<head>`
+    /// of the page. When the application is hydrated, the styles are reused instead of being added
+    /// to the page again.
+    pub async fn render_with_styles(mut self) -> (String, String) {
+        let styles = StyleRegistry::default();
+        self.styles = Some(styles.clone());
+
+        let html = self.render().await;
+
+        (html, styles.to_html())
+    }
+
+    /// Renders Yew Application and returns the positions of its hydration markers.
+    ///
+    /// The markers are listed in the order they appear in the rendered result. Markers are only
+    /// emitted when the result is [hydratable](Self::hydratable). Comments written with
+    /// [`VNode::from_html_unchecked`](crate::virtual_dom::VNode::from_html_unchecked) that look
+    /// like markers are returned as well.
+    pub async fn render_with_markers(self) -> (String, Vec<HydrationMarker>) {
+        let html = self.render().await;
+        let markers = HydrationMarker::collect(&html);
+
+        (html, markers)
+    }
+
+    /// Renders Yew Application into a string Stream
+    ///
+    /// The rendered result is emitted in chunks, see
+    /// [`flush_threshold`](Self::flush_threshold) for more information.
+    pub fn render_stream(self) -> impl Stream<Item = String> {
+        let flush_threshold = self.flush_threshold;
+        let validate = self.validate;
+
+        let s = FlushThreshold::new(self.render_stream_with(None, None), flush_threshold);
+
+        Box::pin(validate_stream(s, validate))
+    }
+
+    /// Creates the scope of the root component.
+    fn create_scope(
+        suspense_cache: Option<SuspenseCache>,
+        id_prefix: Option<Cow<'static, str>>,
+    ) -> Scope<COMP> {
+        let scope =
+            Scope::<COMP>::new(None).with_suspense_cache(suspense_cache.unwrap_or_default());
+
+        match id_prefix {
+            Some(m) => scope.with_id_prefix(m.into()),
+            None => scope,
+        }
+    }
+
+    /// Renders the application into a stream.
+    ///
+    /// If `caught_panic` is set, a panic while rendering ends the stream after the output rendered
+    /// before the panic and is stored in `caught_panic`, instead of unwinding through the stream.
+    #[tracing::instrument(
+        level = tracing::Level::DEBUG,
+        name = "render",
+        skip_all,
+        fields(
+            hydratable = self.hydratable,
+            pretty = self.pretty,
+            defer_suspense = self.defer_suspense,
+            xhtml = self.xhtml,
+            hydratable_children = self.hydratable_children,
+        ),
+    )]
+    fn render_stream_with(
+        self,
+        hydration_data: Option<Rc<RefCell<HydrationData>>>,
+        caught_panic: Option<Rc<Cell<Option<ServerRenderError>>>>,
+    ) -> impl Stream<Item = String> {
+        let mut scope = Self::create_scope(self.suspense_cache, self.id_prefix);
+        if let Some(styles) = self.styles {
+            scope = scope.with_styles(styles);
+        }
+        let pretty = self.pretty && !self.hydratable;
+        let ctx = SsrContext {
+            hydratable: self.hydratable,
+            indent: if pretty { Some(0) } else { None },
+            has_block_children: None,
+            hydration_data,
+            defer_suspense: self.defer_suspense,
+            xhtml: self.xhtml,
+            // the option is ignored if the result is not hydratable.
+            hydratable_children: self.hydratable && self.hydratable_children,
+            raw_text_element: None,
+            cache: self.cache,
+            url_rewriter: self.url_rewriter,
+            suspense_depth: 0,
+            max_suspense_depth: self.max_suspense_depth,
+            collapse_whitespace: self.collapse_whitespace,
+        };
+
+        let outer_span = tracing::Span::current();
+        let mut leading = pretty;
+        let bom = self.bom.then(|| BOM.to_owned());
+        let root_marker = self.hydratable && self.root_marker;
+        let s = BufStream::new(move |mut w| async move {
+            let render_span = tracing::debug_span!("render_stream_item");
+            render_span.follows_from(outer_span);
+
+            if root_marker {
+                let _ = write!(w, "<!--{}-->", ROOT_OPEN_MARK);
+            }
+            let render = scope
+                .render_into_stream(&mut w, self.props.into(), None, &ctx)
+                .instrument(render_span);
+            match caught_panic {
+                // The writer is not dropped by the panic, so everything written before it is
+                // still emitted.
+                Some(caught_panic) => {
+                    if let Err(e) = futures::FutureExt::catch_unwind(AssertUnwindSafe(render)).await
+                    {
+                        caught_panic.set(Some(ServerRenderError::from_panic(e)));
+                        return;
+                    }
+                }
+                None => render.await,
+            }
+            if root_marker {
+                let _ = write!(w, "<!--{}-->", ROOT_CLOSE_MARK);
+            }
+        })
+        // The first block-level element is preceded by a line break when pretty-printing, it may
+        // be written after chunks that are empty.
+        .map(move |m| {
+            if !leading {
+                return m;
+            }
+
+            let m = m.trim_start_matches('\n');
+            leading = m.is_empty();
+            m.to_owned()
+        });
+
+        futures::stream::iter(bom).chain(s)
+    }
+}
+
+/// A Yew Server-side Renderer.
+///
+/// This renderer spawns the rendering task to a Yew [`Runtime`]. and receives result when
+/// the rendering process has finished.
+///
+/// See [`yew::platform`] for more information.
+///
+/// # Thread Safety
+///
+/// Components, their properties and the virtual DOM are built on [`Rc`] and must stay on the
+/// thread that created them. The renderer only sends the function that creates the properties to
+/// a thread of the runtime and receives the rendered result as strings. Hence, this renderer and
+/// the futures and streams returned by it are `Send`, and can be spawned onto a multi-threaded
+/// executor, e.g.: with `tokio::spawn`.
+#[cfg(feature = "ssr")]
+pub struct ServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+{
+    create_props: Box<dyn Send + FnOnce() -> COMP::Properties>,
+    hydratable: bool,
+    pretty: bool,
+    defer_suspense: bool,
+    xhtml: bool,
+    hydratable_children: bool,
+    root_marker: bool,
+    collapse_whitespace: bool,
+    validate: bool,
+    bom: bool,
+    cache: Option<SsrCache>,
+    suspense_cache: Option<Box<dyn Send + FnOnce() -> SuspenseCache>>,
+    url_rewriter: Option<UrlRewriter>,
+    flush_threshold: usize,
+    max_suspense_depth: usize,
+    id_prefix: Option<Cow<'static, str>>,
+    rt: Option<Runtime>,
+}
+
+impl<COMP> fmt::Debug for ServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ServerRenderer<_>")
+    }
+}
+
+impl<COMP> Default for ServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    fn default() -> Self {
+        Self::with_props(Default::default)
+    }
+}
+
+impl<COMP> ServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    /// Creates a [ServerRenderer] with default properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<COMP> ServerRenderer<COMP>
+where
+    COMP: BaseComponent,
+{
+    /// Creates a [ServerRenderer] with custom properties.
+    ///
+    /// # Note
+    ///
+    /// The properties does not have to implement `Send`.
+    /// However, the function to create properties needs to be `Send`.
+    pub fn with_props<F>(create_props: F) -> Self
+    where
+        F: 'static + Send + FnOnce() -> COMP::Properties,
+    {
+        Self {
+            create_props: Box::new(create_props),
+            hydratable: true,
+            pretty: false,
+            defer_suspense: false,
+            xhtml: false,
+            hydratable_children: false,
+            root_marker: false,
+            collapse_whitespace: false,
+            validate: false,
+            bom: false,
+            cache: None,
+            suspense_cache: None,
+            url_rewriter: None,
+            flush_threshold: DEFAULT_FLUSH_THRESHOLD,
+            max_suspense_depth: DEFAULT_MAX_SUSPENSE_DEPTH,
+            id_prefix: None,
+            rt: None,
+        }
+    }
+
+    /// Sets the runtime the ServerRenderer will run the rendering task with.
+    pub fn with_runtime(mut self, rt: Runtime) -> Self {
+        self.rt = Some(rt);
+
+        self
+    }
+
+    /// Sets whether an the rendered result is hydratable.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// When this is sets to `true`, the rendered artifact will include additional information
+    /// to assist with the hydration process.
+    pub fn hydratable(mut self, val: bool) -> Self {
+        self.hydratable = val;
+
+        self
+    }
+
+    /// Sets whether the rendered result is pretty-printed.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, block-level elements are put on their own lines and indented
+    /// by their depth, which makes the rendered result suitable for snapshot testing. The content
+    /// of whitespace-sensitive elements (e.g.: `<pre>` and `<textarea>`) is left untouched.
+    ///
+    /// This option is ignored if the rendered result is hydratable, as hydration expects the
+    /// exact nodes the application renders.
+    pub fn pretty(mut self, val: bool) -> Self {
+        self.pretty = val;
+
+        self
+    }
+
+    /// Sets whether suspense fallbacks are rendered instead of waiting for suspended children.
+    ///
+    /// See [`LocalServerRenderer::defer_suspense`] for more information.
+    pub fn defer_suspense(mut self, val: bool) -> Self {
+        self.defer_suspense = val;
+
+        self
+    }
+
+    /// Sets whether elements are serialized as XHTML.
+    ///
+    /// See [`LocalServerRenderer::xhtml`] for more information.
+    pub fn xhtml(mut self, val: bool) -> Self {
+        self.xhtml = val;
+
+        self
+    }
+
+    /// Sets whether only the children of the rendered component are hydratable.
+    ///
+    /// See [`LocalServerRenderer::hydratable_children`] for more information.
+    pub fn hydratable_children(mut self, val: bool) -> Self {
+        self.hydratable_children = val;
+
+        self
+    }
+
+    /// Sets whether the rendered result is wrapped in the markers of the root boundary.
+    ///
+    /// See [`LocalServerRenderer::root_marker`] for more information.
+    pub fn root_marker(mut self, val: bool) -> Self {
+        self.root_marker = val;
+
+        self
+    }
+
+    /// Sets whether runs of whitespace in texts are collapsed.
+    ///
+    /// See [`LocalServerRenderer::collapse_whitespace`] for more information.
+    pub fn collapse_whitespace(mut self, val: bool) -> Self {
+        self.collapse_whitespace = val;
+
+        self
+    }
+
+    /// Sets whether the rendered result is checked to be well-formed.
+    ///
+    /// See [`LocalServerRenderer::validate`] for more information.
+    pub fn validate(mut self, val: bool) -> Self {
+        self.validate = cfg!(debug_assertions) && val;
+
+        self
+    }
+
+    /// Sets whether the rendered result starts with a UTF-8 byte order mark (`U+FEFF`).
+    ///
+    /// See [`LocalServerRenderer::bom`] for more information.
+    pub fn bom(mut self, val: bool) -> Self {
+        self.bom = val;
+
+        self
+    }
+
+    /// Sets the cache of components rendered with
+    /// [`VChild::ssr_cached`](crate::virtual_dom::VChild::ssr_cached).
+    ///
+    /// See [`LocalServerRenderer::cache`] for more information.
+    pub fn cache(mut self, cache: SsrCache) -> Self {
+        self.cache = Some(cache);
+
+        self
+    }
+
+    /// Sets the function that creates the cache of the results of
+    /// [`use_future_with_key`](crate::suspense::use_future_with_key).
+    ///
+    /// The cache is not `Send`, so it is created by `create_cache` on the thread the application
+    /// is rendered on.
+    ///
+    /// See [`LocalServerRenderer::suspense_cache`] for more information.
+    pub fn suspense_cache<F>(mut self, create_cache: F) -> Self
+    where
+        F: 'static + Send + FnOnce() -> SuspenseCache,
+    {
+        self.suspense_cache = Some(Box::new(create_cache));
+
+        self
+    }
+
+    /// Sets the rewriter of URLs in the attributes of rendered elements.
+    ///
+    /// See [`LocalServerRenderer::url_rewriter`] for more information.
+    pub fn url_rewriter(mut self, rewriter: UrlRewriter) -> Self {
+        self.url_rewriter = Some(rewriter);
+
+        self
+    }
+
+    /// Sets the number of bytes [`render_stream`](Self::render_stream) buffers before it emits a
+    /// chunk.
+    ///
+    /// See [`LocalServerRenderer::flush_threshold`] for more information.
+    pub fn flush_threshold(mut self, bytes: usize) -> Self {
+        self.flush_threshold = bytes;
+
+        self
+    }
+
+    /// Sets the number of suspenses that may be nested in each other.
+    ///
+    /// See [`LocalServerRenderer::max_suspense_depth`] for more information.
+    pub fn max_suspense_depth(mut self, depth: usize) -> Self {
+        self.max_suspense_depth = depth;
+
+        self
+    }
+
+    /// Sets the prefix of the ids generated with [`use_id`](crate::functional::use_id).
+    ///
+    /// See [`LocalServerRenderer::id_prefix`] for more information.
+    pub fn id_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.id_prefix = Some(prefix.into());
+
+        self
+    }
+
+    /// Renders Yew Application.
+    pub async fn render(mut self) -> String {
+        let rt = self.rt.take();
+        let create_renderer = self.into_local();
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let create_task = move || async move {
+            let s = create_renderer().render().await;
+
+            let _ = tx.send(s);
+        };
+
+        Self::spawn_rendering_task(rt, create_task);
+
+        rx.await.expect("failed to render application")
+    }
+
+    /// Renders Yew Application, returning the output rendered so far if rendering fails.
+    ///
+    /// See [`LocalServerRenderer::try_render`] for more information.
+    pub async fn try_render(mut self) -> Result<String, (String, ServerRenderError)> {
+        let rt = self.rt.take();
+        let create_renderer = self.into_local();
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let create_task = move || async move {
+            let m = create_renderer().try_render().await;
+
+            let _ = tx.send(m);
+        };
+
+        Self::spawn_rendering_task(rt, create_task);
+
+        rx.await.expect("failed to render application")
+    }
+
+    /// Renders Yew Application and collects the prepared states of components separately.
+    ///
+    /// See [`LocalServerRenderer::render_with_hydration_data`] for more information.
+    pub async fn render_with_hydration_data(mut self) -> (String, HydrationData) {
+        let rt = self.rt.take();
+        let create_renderer = self.into_local();
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let create_task = move || async move {
+            let m = create_renderer().render_with_hydration_data().await;
+
+            let _ = tx.send(m);
+        };
+
+        Self::spawn_rendering_task(rt, create_task);
+
+        rx.await.expect("failed to render application")
+    }
+
+    /// Renders Yew Application and collects the styles used by its components separately.
+    ///
+    /// See [`LocalServerRenderer::render_with_styles`] for more information.
+    pub async fn render_with_styles(mut self) -> (String, String) {
+        let rt = self.rt.take();
+        let create_renderer = self.into_local();
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let create_task = move || async move {
+            let m = create_renderer().render_with_styles().await;
+
+            let _ = tx.send(m);
+        };
+
+        Self::spawn_rendering_task(rt, create_task);
+
+        rx.await.expect("failed to render application")
+    }
+
+    /// Renders Yew Application and returns the positions of its hydration markers.
+    ///
+    /// See [`LocalServerRenderer::render_with_markers`] for more information.
+    pub async fn render_with_markers(self) -> (String, Vec<HydrationMarker>) {
+        let html = self.render().await;
+        let markers = HydrationMarker::collect(&html);
+
+        (html, markers)
+    }
+
+    /// Renders Yew Application and returns its text content.
+    ///
+    /// See [`LocalServerRenderer::render_text_content`] for more information.
+    pub async fn render_text_content(mut self) -> String {
+        let rt = self.rt.take();
+        let create_renderer = self.into_local();
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let create_task = move || async move {
+            let s = create_renderer().render_text_content().await;
+
+            let _ = tx.send(s);
+        };
+
+        Self::spawn_rendering_task(rt, create_task);
+
+        rx.await.expect("failed to render application")
+    }
+
+    /// Renders Yew Application to a String.
+    ///
+    /// See [`LocalServerRenderer::render_to_string`] for more information.
+    pub async fn render_to_string(self, w: &mut String) {
+        // The stream checks the result if it is validated.
+        let mut s = self.render_stream();
+
+        while let Some(m) = s.next().await {
+            w.push_str(&m);
+        }
+    }
+
+    /// Renders Yew Application to a [`Write`](io::Write).
+    ///
+    /// See [`LocalServerRenderer::render_to_writer`] for more information.
+    pub async fn render_to_writer<W>(self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let mut s = self.render_stream();
+
+        while let Some(m) = s.next().await {
+            w.write_all(m.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn spawn_rendering_task<F, Fut>(rt: Option<Runtime>, create_task: F)
+    where
+        F: 'static + Send + FnOnce() -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        match rt {
+            // If a runtime is specified, spawn to the specified runtime.
+            Some(m) => m.spawn_pinned(create_task),
+            None => match LocalHandle::try_current() {
+                // If within a Yew Runtime, spawn to the current runtime.
+                Some(m) => m.spawn_local(create_task()),
+                // Outside of Yew Runtime, spawn to the default runtime.
+                None => Runtime::default().spawn_pinned(create_task),
+            },
+        }
+    }
+
+    /// Renders Yew Application into a string Stream.
+    pub fn render_stream(mut self) -> impl Send + Stream<Item = String> {
+        let rt = self.rt.take();
+        // The result is checked where the stream is consumed, so it panics there.
+        let validate = std::mem::take(&mut self.validate);
+        let create_renderer = self.into_local();
+
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let create_task = move || async move {
+            let s = create_renderer().render_stream();
+            pin_mut!(s);
+
+            while let Some(m) = s.next().await {
+                // The receiver has been dropped, rendering is aborted.
+                if tx.unbounded_send(m).is_err() {
+                    break;
+                }
+            }
+        };
+
+        Self::spawn_rendering_task(rt, create_task);
+
+        Box::pin(validate_stream(rx, validate))
+    }
+
+    /// Returns a function that creates a [`LocalServerRenderer`] with the options of this
+    /// renderer.
+    ///
+    /// The function is called in the rendering task, as the properties are not required to be
+    /// `Send`. The runtime is not passed on, it has to be taken before.
+    fn into_local(self) -> impl 'static + Send + FnOnce() -> LocalServerRenderer<COMP> {
+        let Self {
+            create_props,
+            hydratable,
+            pretty,
+            defer_suspense,
+            xhtml,
+            hydratable_children,
+            root_marker,
+            collapse_whitespace,
+            validate,
+            bom,
+            cache,
+            suspense_cache,
+            url_rewriter,
+            flush_threshold,
+            max_suspense_depth,
+            id_prefix,
+            rt: _,
+        } = self;
+
+        move || {
+            LocalServerRenderer::<COMP> {
+                cache,
+                suspense_cache: suspense_cache.map(|f| f()),
+                url_rewriter,
+                id_prefix,
+                ..LocalServerRenderer::with_props(create_props())
+            }
+            .hydratable(hydratable)
+            .pretty(pretty)
+            .defer_suspense(defer_suspense)
+            .xhtml(xhtml)
+            .hydratable_children(hydratable_children)
+            .root_marker(root_marker)
+            .collapse_whitespace(collapse_whitespace)
+            .validate(validate)
+            .bom(bom)
+            .flush_threshold(flush_threshold)
+            .max_suspense_depth(max_suspense_depth)
+        }
+    }
+}
+
+/// Renders a [`VNode`] as a child of an existing scope into `w`.
+///
+/// # Unstable
+///
+/// This is a low-level entry point for server frameworks that manage their own scope tree, e.g.:
+/// to render islands of a page separately. It is not covered by semver guarantees and may change
+/// in any release. Prefer [`LocalServerRenderer`] and [`ServerRenderer`], which render a
+/// component in a fresh root scope.
+///
+/// Components in `node` are created as children of `parent`, so they can find the contexts
+/// provided by its ancestors. The result is hydratable, it is hydrated on the client side by
+/// rendering the same node as a child of the matching component.
+///
+/// Like [`LocalServerRenderer`], this function has to be called within a
+/// [`Runtime`](crate::platform::Runtime) or a tokio
+/// [`LocalSet`](struct@tokio::task::LocalSet).
+#[cfg(feature = "ssr")]
+pub async fn render_node_to_string(
+    node: &VNode,
+    parent: &AnyScope,
+    w: &mut dyn fmt::Write,
+) -> fmt::Result {
+    let node = node.clone();
+    let parent = parent.clone();
+    let ctx = SsrContext {
+        hydratable: true,
+        indent: None,
+        has_block_children: None,
+        hydration_data: None,
+        defer_suspense: false,
+        xhtml: false,
+        hydratable_children: false,
+        raw_text_element: None,
+        cache: None,
+        url_rewriter: None,
+        suspense_depth: 0,
+        max_suspense_depth: DEFAULT_MAX_SUSPENSE_DEPTH,
+        collapse_whitespace: false,
+    };
+
+    let s = BufStream::new(move |mut w| async move {
+        node.render_into_stream(&mut w, &parent, &ctx).await;
+    });
+    pin_mut!(s);
+
+    while let Some(m) = s.next().await {
+        w.write_str(&m)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::prelude::*;
+    use crate::{HydrationMarkerKind, ServerRenderer};
+
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_render_is_send() {
+        #[function_component]
+        fn Comp() -> Html {
+            let s = use_state(|| "world".to_string());
+
+            html! { <div>{"Hello, "}{&*s}{"!"}</div> }
+        }
+
+        let s = tokio::spawn(ServerRenderer::<Comp>::new().hydratable(false).render())
+            .await
+            .expect("failed to render");
+
+        assert_eq!(s, "<div>Hello, world!</div>");
+
+        let (s, data) = tokio::spawn(
+            ServerRenderer::<Comp>::new()
+                .hydratable(false)
+                .render_with_hydration_data(),
+        )
+        .await
+        .expect("failed to render");
 
-            while let Some(m) = s.next().await {
-                let _ = tx.unbounded_send(m);
+        assert_eq!(s, "<div>Hello, world!</div>");
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    async fn test_render_with_markers() {
+        #[function_component]
+        fn Child() -> Html {
+            html! { <span>{"<!--<?>-->"}</span> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense>
+                    <Child />
+                </Suspense>
+            }
+        }
+
+        let (s, markers) = ServerRenderer::<Comp>::new().render_with_markers().await;
+
+        let kinds = markers
+            .iter()
+            .map(|m| (m.closing, &m.kind))
+            .collect::<Vec<_>>();
+        fn is_component(kind: &HydrationMarkerKind, name: &str) -> bool {
+            matches!(kind, HydrationMarkerKind::Component(Some(m)) if m.ends_with(name))
+        }
+
+        // Comp, Suspense, BaseSuspense, the suspense boundary and Child are opened, then closed
+        // in reverse order.
+        assert_eq!(kinds.len(), 10);
+        assert!(kinds[..5].iter().all(|(closing, _)| !closing));
+        assert!(kinds[5..].iter().all(|(closing, _)| *closing));
+        assert!(is_component(kinds[0].1, "Comp"));
+        assert_eq!(kinds[3].1, &HydrationMarkerKind::Suspense);
+        assert!(is_component(kinds[4].1, "Child"));
+        assert!(is_component(kinds[9].1, "Comp"));
+
+        for m in markers.iter() {
+            let marker = &s[m.offset..m.offset + m.len];
+            assert!(marker.starts_with("<!--") && marker.ends_with("-->"));
+        }
+
+        // the text of Child is escaped and is not mistaken for a marker.
+        assert!(s.contains("<span>&lt;!--&lt;?&gt;--&gt;</span>"));
+    }
+
+    #[test]
+    async fn test_render_to_writer() {
+        use std::io;
+
+        struct BrokenPipe {
+            writes: usize,
+        }
+
+        impl io::Write for BrokenPipe {
+            fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+                self.writes += 1;
+
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! { <div>{"Hello!"}</div> }
+        }
+
+        let mut w = Vec::new();
+        ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render_to_writer(&mut w)
+            .await
+            .expect("failed to render");
+
+        assert_eq!(w, b"<div>Hello!</div>");
+
+        let mut w = BrokenPipe { writes: 0 };
+        let e = ServerRenderer::<Comp>::new()
+            .render_to_writer(&mut w)
+            .await
+            .expect_err("writing should fail");
+
+        assert_eq!(e.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(w.writes, 1);
+    }
+
+    #[test]
+    async fn test_hydratable_children() {
+        use crate::suspense::{Suspension, SuspensionResult};
+
+        #[hook]
+        fn use_suspend_once() -> SuspensionResult<()> {
+            let suspended = use_state(|| false);
+
+            if *suspended {
+                return Ok(());
+            }
+
+            let (s, handle) = Suspension::new();
+            suspended.set(true);
+            handle.resume();
+
+            Err(s)
+        }
+
+        #[function_component]
+        fn Content() -> HtmlResult {
+            use_suspend_once()?;
+
+            Ok(html! { <div>{"content"}</div> })
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! {
+                <Suspense fallback={html! { {"loading"} }}>
+                    <Content />
+                </Suspense>
+            }
+        }
+
+        let (s, markers) = ServerRenderer::<App>::new()
+            .hydratable_children(true)
+            .render_with_markers()
+            .await;
+
+        assert!(!s.contains("::App]>-->"));
+        // the markers of the inner suspense are kept.
+        assert!(s.contains("<!--<?>--><!--<["));
+        assert!(markers
+            .iter()
+            .any(|m| m.kind == HydrationMarkerKind::Suspense && !m.closing));
+        assert!(!markers.iter().any(
+            |m| matches!(m.kind, HydrationMarkerKind::Component(Some(ref name)) if name.ends_with("::App"))
+        ));
+
+        // the option is ignored if the result is not hydratable.
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .hydratable_children(true)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>content</div>");
+    }
+
+    #[test]
+    async fn test_try_render() {
+        use crate::ServerRenderError;
+
+        #[function_component]
+        fn Broken() -> Html {
+            panic!("content is broken");
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! {
+                <main>
+                    <header>{"shell"}</header>
+                    <Broken />
+                    <footer>{"never rendered"}</footer>
+                </main>
+            }
+        }
+
+        let (html, e) = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .try_render()
+            .await
+            .expect_err("rendering should fail");
+
+        assert_eq!(html, "<main><header>shell</header>");
+        assert_eq!(
+            e,
+            ServerRenderError::Panicked {
+                message: "content is broken".to_owned()
+            }
+        );
+
+        #[function_component]
+        fn Working() -> Html {
+            html! { <div>{"content"}</div> }
+        }
+
+        let s = ServerRenderer::<Working>::new()
+            .hydratable(false)
+            .try_render()
+            .await;
+
+        assert_eq!(s, Ok("<div>content</div>".to_owned()));
+    }
+
+    #[test]
+    async fn test_render_with_styles() {
+        use crate::html::Style;
+
+        const CSS: &str = "color: red;";
+
+        #[function_component]
+        fn Label() -> Html {
+            let style = use_style(CSS);
+
+            html! { <span class={style}>{"label"}</span> }
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! { <div><Label /><Label /></div> }
+        }
+
+        let (html, styles) = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render_with_styles()
+            .await;
+
+        let style = Style::new(CSS);
+        assert_eq!(
+            html,
+            format!(
+                r#"<div><span class="{0}">label</span><span class="{0}">label</span></div>"#,
+                style.class_name()
+            )
+        );
+        assert_eq!(
+            styles,
+            format!(
+                r#"<style data-yew-style="{0}">.{0} {{ color: red; }}</style>"#,
+                style.class_name()
+            )
+        );
+    }
+
+    #[test]
+    async fn test_render_with_styles_and_cache() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use super::SsrCache;
+        use crate::html::Style;
+        use crate::virtual_dom::VChild;
+
+        const CSS: &str = "color: blue;";
+        static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+        #[function_component]
+        fn Label() -> Html {
+            RENDERS.fetch_add(1, Ordering::Relaxed);
+            let style = use_style(CSS);
+
+            html! { <span class={style}>{"label"}</span> }
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! { {VChild::<Label>::new((), None).ssr_cached()} }
+        }
+
+        let cache = SsrCache::new(8);
+        let html = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .cache(cache.clone())
+            .render()
+            .await;
+        assert_eq!(cache.len(), 1);
+
+        let (styled_html, styles) = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .cache(cache.clone())
+            .render_with_styles()
+            .await;
+
+        let style = Style::new(CSS);
+        assert_eq!(styled_html, html);
+        assert_eq!(
+            styles,
+            format!(
+                r#"<style data-yew-style="{0}">.{0} {{ color: blue; }}</style>"#,
+                style.class_name()
+            )
+        );
+        assert_eq!(RENDERS.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    async fn test_root_marker() {
+        #[function_component]
+        fn App() -> Html {
+            html! { <div>{"content"}</div> }
+        }
+
+        let (s, markers) = ServerRenderer::<App>::new()
+            .root_marker(true)
+            .render_with_markers()
+            .await;
+
+        assert!(s.starts_with("<!--<yew-root>--><!--<["));
+        assert!(s.ends_with("]>--><!--</yew-root>-->"));
+        // the root boundary is not a component or a suspense.
+        assert_eq!(markers.len(), 2);
+
+        // the option is ignored if the result is not hydratable.
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .root_marker(true)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>content</div>");
+    }
+
+    #[test]
+    async fn test_render_node_to_string() {
+        use tokio::task::LocalSet;
+
+        use crate::html::AnyScope;
+        use crate::render_node_to_string;
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! { <span>{"component"}</span> }
+        }
+
+        let node = html! { <div>{"island"}<Comp /></div> };
+        let parent = AnyScope::test();
+
+        let s = LocalSet::new()
+            .run_until(async move {
+                let mut s = String::new();
+                render_node_to_string(&node, &parent, &mut s)
+                    .await
+                    .expect("failed to render");
+                s
+            })
+            .await;
+
+        assert!(s.starts_with("<div>island<!--<["));
+        assert!(s.ends_with("]>--></div>"));
+        assert!(s.contains("<span>component</span>"));
+    }
+
+    #[test]
+    async fn test_validate() {
+        #[function_component]
+        fn Child() -> Html {
+            html! { <input value="a > b" /> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <>
+                    <script>{"if (1 < 2) { document.write('<p>'); }"}</script>
+                    <div>{"1 < 2"}<br /><Child />{Html::from_html_unchecked("<b>bold</b>".into())}</div>
+                    <Suspense fallback={html! {}}><span /></Suspense>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().validate(true).render().await;
+        assert!(s.contains("<br>"));
+
+        let s = ServerRenderer::<Comp>::new()
+            .xhtml(true)
+            .validate(true)
+            .render()
+            .await;
+        assert!(s.contains("<br/>"));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "element `<div>` at byte 11 does not match the element `<span>`")]
+    async fn test_validate_writer() {
+        use crate::LocalServerRenderer;
+
+        #[function_component]
+        fn Comp() -> Html {
+            Html::from_html_unchecked("<div><span></div>".into())
+        }
+
+        let mut w = Vec::new();
+        let _ = LocalServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .validate(true)
+            .render_to_writer(&mut w)
+            .await;
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    async fn test_validate_stream() {
+        use futures::{FutureExt, StreamExt};
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <p>{"valid"}</p>
+                    {Html::from_html_unchecked("<div><span></div>".into())}
+                </div>
+            }
+        }
+
+        // the chunks are emitted before the result is checked at the end of the stream.
+        let mut s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .validate(true)
+            .render_stream();
+        assert_eq!(
+            s.next().await.as_deref(),
+            Some("<div><p>valid</p><div><span></div></div>")
+        );
+
+        let end = std::panic::AssertUnwindSafe(s.next()).catch_unwind().await;
+        assert!(end.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "element `<div>` at byte 11 does not match the element `<span>`")]
+    async fn test_validate_unbalanced_element() {
+        super::validate_html("<div><span></div>");
+    }
+
+    #[test]
+    #[should_panic(expected = "boundary of component `app::Stray` at byte 4 was never opened")]
+    async fn test_validate_stray_marker() {
+        super::validate_html("<br><!--</[app::Stray]>-->");
+    }
+
+    #[test]
+    #[should_panic(expected = "the boundary of a suspense at byte 11 is never closed")]
+    async fn test_validate_unclosed_marker() {
+        super::validate_html("<div></div><!--<?>-->");
+    }
+
+    #[test]
+    async fn test_cache() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use super::SsrCache;
+        use crate::virtual_dom::VChild;
+
+        static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone, PartialEq, Hash, Properties)]
+        struct NavProps {
+            active: String,
+        }
+
+        #[function_component]
+        fn Nav(props: &NavProps) -> Html {
+            RENDERS.fetch_add(1, Ordering::SeqCst);
+
+            html! { <nav>{&props.active}</nav> }
+        }
+
+        #[derive(PartialEq, Properties)]
+        struct AppProps {
+            page: &'static str,
+        }
+
+        #[function_component]
+        fn App(props: &AppProps) -> Html {
+            let nav = VChild::<Nav>::new(
+                NavProps {
+                    active: props.page.to_owned(),
+                },
+                None,
+            );
+
+            html! {
+                <>
+                    {nav.ssr_cached()}
+                    <main>{props.page}</main>
+                </>
             }
+        }
+
+        let cache = SsrCache::new(8);
+        let render = |page, hydratable| {
+            ServerRenderer::<App>::with_props(move || AppProps { page })
+                .hydratable(hydratable)
+                .cache(cache.clone())
+                .render()
         };
 
-        Self::spawn_rendering_task(rt, create_task);
+        let s = render("home", false).await;
+        assert_eq!(s, "<nav>home</nav><main>home</main>");
+        assert_eq!(render("home", false).await, s);
+        assert_eq!(RENDERS.load(Ordering::SeqCst), 1);
+
+        // components with different properties are cached separately.
+        let s = render("about", false).await;
+        assert_eq!(s, "<nav>about</nav><main>about</main>");
+        assert_eq!(RENDERS.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.len(), 2);
+
+        // hydratable results are not cached.
+        render("home", true).await;
+        assert_eq!(RENDERS.load(Ordering::SeqCst), 3);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    async fn test_cache_compares_properties() {
+        use std::hash::{Hash, Hasher};
+
+        use super::SsrCache;
+        use crate::virtual_dom::VChild;
+
+        // all properties have the same hash.
+        #[derive(Clone, PartialEq, Properties)]
+        struct LabelProps {
+            text: String,
+        }
+
+        impl Hash for LabelProps {
+            fn hash<H: Hasher>(&self, _state: &mut H) {}
+        }
+
+        #[function_component]
+        fn Label(props: &LabelProps) -> Html {
+            html! { <span>{&props.text}</span> }
+        }
+
+        #[derive(PartialEq, Properties)]
+        struct AppProps {
+            text: &'static str,
+        }
+
+        #[function_component]
+        fn App(props: &AppProps) -> Html {
+            let label = VChild::<Label>::new(
+                LabelProps {
+                    text: props.text.to_owned(),
+                },
+                None,
+            );
+
+            html! { {label.ssr_cached()} }
+        }
+
+        let cache = SsrCache::new(8);
+        let render = |text| {
+            ServerRenderer::<App>::with_props(move || AppProps { text })
+                .hydratable(false)
+                .cache(cache.clone())
+                .render()
+        };
+
+        assert_eq!(render("a").await, "<span>a</span>");
+        assert_eq!(render("b").await, "<span>b</span>");
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    async fn test_cache_evicts_least_recently_used() {
+        use std::sync::Arc;
+
+        use super::{CachedComponent, SsrCache, SsrCacheKey};
+
+        let key = |n: u32| SsrCacheKey {
+            component: CachedComponent::new::<(), _>(&n),
+            options: (false, false, false, None),
+        };
+
+        let cache = SsrCache::new(2);
+        cache.insert(key(1), Arc::from("a"));
+        cache.insert(key(2), Arc::from("b"));
+        assert_eq!(cache.get(&key(1)).as_deref(), Some("a"));
+
+        cache.insert(key(3), Arc::from("c"));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&key(2)), None);
+        assert_eq!(cache.get(&key(1)).as_deref(), Some("a"));
+        assert_eq!(cache.get(&key(3)).as_deref(), Some("c"));
+
+        cache.clear();
+        assert!(cache.is_empty());
+
+        // a result that is inserted again is replaced and becomes the most recently used one.
+        let cache = SsrCache::new(3);
+        for (n, html) in [(1, "a"), (2, "b"), (3, "c"), (1, "d")] {
+            cache.insert(key(n), Arc::from(html));
+        }
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(&key(2)).as_deref(), Some("b"));
+
+        cache.insert(key(4), Arc::from("e"));
+        assert_eq!(cache.get(&key(3)), None);
+        assert_eq!(cache.get(&key(1)).as_deref(), Some("d"));
+
+        cache.insert(key(5), Arc::from("f"));
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(&key(2)), None);
+        assert_eq!(cache.get(&key(4)).as_deref(), Some("e"));
+        assert_eq!(cache.get(&key(1)).as_deref(), Some("d"));
+        assert_eq!(cache.get(&key(5)).as_deref(), Some("f"));
+    }
+
+    #[test]
+    async fn test_bom() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! { <p>{"héllo"}</p> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+        assert_eq!(s, "<p>héllo</p>");
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .bom(true)
+            .render()
+            .await;
+        assert!(s.as_bytes().starts_with(&[0xEF, 0xBB, 0xBF]));
+        assert_eq!(&s[3..], "<p>héllo</p>");
+
+        let mut s = String::new();
+        ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .bom(true)
+            .render_to_string(&mut s)
+            .await;
+        assert_eq!(s, "\u{feff}<p>héllo</p>");
+    }
+
+    #[test]
+    async fn test_flush_threshold() {
+        use std::time::Duration;
+
+        use futures::StreamExt;
+
+        use crate::platform::time::sleep;
+        use crate::suspense::use_future;
+
+        #[function_component]
+        fn List() -> Html {
+            html! { <ul>{ for (0..500).map(|m| html! { <li>{m}</li> }) }</ul> }
+        }
+
+        let html = ServerRenderer::<List>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        let chunks: Vec<String> = ServerRenderer::<List>::new()
+            .hydratable(false)
+            .flush_threshold(256)
+            .render_stream()
+            .collect()
+            .await;
+        assert!(chunks.len() > 1);
+        assert!(chunks[..chunks.len() - 1].iter().all(|m| m.len() >= 256));
+        assert_eq!(chunks.concat(), html);
+
+        // the page is smaller than the default threshold.
+        let chunks: Vec<String> = ServerRenderer::<List>::new()
+            .hydratable(false)
+            .render_stream()
+            .collect()
+            .await;
+        assert_eq!(chunks, vec![html]);
+
+        #[function_component]
+        fn Slow() -> HtmlResult {
+            use_future(|| sleep(Duration::from_millis(50)))?;
+
+            Ok(html! { <p>{"slow"}</p> })
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! {
+                <div>
+                    <p>{"fast"}</p>
+                    <Suspense fallback={Html::default()}>
+                        <Slow />
+                    </Suspense>
+                </div>
+            }
+        }
+
+        // the buffer is flushed while the rendering waits for the suspended component.
+        let chunks: Vec<String> = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .flush_threshold(usize::MAX)
+            .render_stream()
+            .collect()
+            .await;
+        assert_eq!(chunks[0], "<div><p>fast</p>");
+        assert_eq!(chunks.concat(), "<div><p>fast</p><p>slow</p></div>");
+    }
+
+    #[test]
+    async fn test_suspense_cache() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        use crate::platform::time::sleep;
+        use crate::suspense::{use_future_with_key, SuspenseCache};
+
+        static FETCHES: AtomicUsize = AtomicUsize::new(0);
+
+        #[function_component]
+        fn User() -> HtmlResult {
+            let name = use_future_with_key("user", || async {
+                FETCHES.fetch_add(1, Ordering::SeqCst);
+                sleep(Duration::from_millis(10)).await;
+
+                "Alice".to_owned()
+            })?;
+
+            Ok(html! { <span>{&*name}</span> })
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! {
+                <>
+                    <Suspense fallback={html! {"loading..."}}>
+                        <User />
+                    </Suspense>
+                    <Suspense fallback={html! {"loading..."}}>
+                        <User />
+                    </Suspense>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+        assert_eq!(s, "<span>Alice</span><span>Alice</span>");
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+        // the cache is scoped to a render.
+        ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+
+        // pre-seeded values are used without fetching.
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .suspense_cache(|| {
+                let cache = SuspenseCache::new();
+                cache.insert("user", "Bob".to_owned());
+                cache
+            })
+            .render()
+            .await;
+        assert_eq!(s, "<span>Bob</span><span>Bob</span>");
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    async fn test_non_hydratable_result_has_no_markers() {
+        #[derive(PartialEq, Properties)]
+        struct ItemProps {
+            value: u32,
+        }
+
+        #[function_component]
+        fn Item(props: &ItemProps) -> Html {
+            html! { <li>{props.value}</li> }
+        }
+
+        #[function_component]
+        fn List() -> Html {
+            html! {
+                <ul>
+                    { for (0..3).map(|i| html! { <Item key={i} value={i} /> }) }
+                </ul>
+            }
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! {
+                <div>
+                    <List />
+                    <Suspense fallback={html! {"loading..."}}>
+                        <List />
+                        <Suspense>
+                            <Item value=42 />
+                        </Suspense>
+                    </Suspense>
+                </div>
+            }
+        }
+
+        for defer_suspense in [false, true] {
+            let (hydratable, markers) = ServerRenderer::<App>::new()
+                .defer_suspense(defer_suspense)
+                .render_with_markers()
+                .await;
+            assert!(!markers.is_empty());
+
+            let mut expected = String::new();
+            let mut pos = 0;
+            for marker in markers {
+                expected.push_str(&hydratable[pos..marker.offset]);
+                pos = marker.offset + marker.len;
+            }
+            expected.push_str(&hydratable[pos..]);
+
+            let s = ServerRenderer::<App>::new()
+                .defer_suspense(defer_suspense)
+                .hydratable(false)
+                .render()
+                .await;
+
+            assert_eq!(s, expected);
+        }
+    }
+
+    #[test]
+    async fn test_id_prefix() {
+        #[function_component]
+        fn Field() -> Html {
+            let id = use_id();
+
+            html! { <input {id} /> }
+        }
+
+        #[function_component]
+        fn App() -> Html {
+            html! {
+                <>
+                    <Field />
+                    <Field />
+                </>
+            }
+        }
+
+        let render = |prefix: Option<&'static str>| {
+            let renderer = ServerRenderer::<App>::new().hydratable(false);
+            match prefix {
+                Some(m) => renderer.id_prefix(m).render(),
+                None => renderer.render(),
+            }
+        };
+
+        assert_eq!(
+            render(None).await,
+            r#"<input id="yew-0-0"><input id="yew-1-0">"#
+        );
+
+        // two roots rendered into the same page don't share any ids.
+        let first = render(Some("island-a")).await;
+        let second = render(Some("island-b")).await;
+        assert_eq!(
+            first,
+            r#"<input id="island-a-0-0"><input id="island-a-1-0">"#
+        );
+        assert_eq!(
+            second,
+            r#"<input id="island-b-0-0"><input id="island-b-1-0">"#
+        );
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod runtime_tests {
+    use futures::executor::{block_on, LocalPool};
+
+    use crate::prelude::*;
+    use crate::{LocalServerRenderer, ServerRenderer};
+
+    #[function_component]
+    fn Comp() -> Html {
+        let count = use_state(|| 0);
+
+        html! {
+            <div>
+                <Suspense fallback={html! {"loading..."}}>
+                    <span>{*count}</span>
+                </Suspense>
+            </div>
+        }
+    }
+
+    #[test]
+    fn test_local_renderer_without_tokio() {
+        let mut pool = LocalPool::new();
+        let s = pool.run_until(
+            LocalServerRenderer::<Comp>::new()
+                .hydratable(false)
+                .render(),
+        );
+
+        assert_eq!(s, "<div><span>0</span></div>");
+    }
+
+    #[test]
+    fn test_renderer_without_tokio() {
+        let s = block_on(ServerRenderer::<Comp>::new().hydratable(false).render());
 
-        rx
+        assert_eq!(s, "<div><span>0</span></div>");
     }
 }
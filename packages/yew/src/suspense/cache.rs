@@ -0,0 +1,158 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+
+use super::{Suspension, SuspensionResult};
+use crate::virtual_dom::AttrValue;
+
+/// The key of a value in a [`SuspenseCache`].
+///
+/// Values are only shared between hooks that use the same key and the same output type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SuspenseCacheKey(AttrValue);
+
+impl From<&'static str> for SuspenseCacheKey {
+    fn from(m: &'static str) -> Self {
+        Self(m.into())
+    }
+}
+
+impl From<String> for SuspenseCacheKey {
+    fn from(m: String) -> Self {
+        Self(m.into())
+    }
+}
+
+impl From<AttrValue> for SuspenseCacheKey {
+    fn from(m: AttrValue) -> Self {
+        Self(m)
+    }
+}
+
+type CachedValues = HashMap<(SuspenseCacheKey, TypeId), Rc<dyn Any>>;
+
+/// A cache of the results of [`use_future_with_key`](super::use_future_with_key), shared by all
+/// components of a server-side render.
+///
+/// Components requesting the same key await the same future, so the underlying work is only
+/// started once per render, even if the components are placed in different suspense boundaries.
+/// Each render creates an empty cache, unless one is passed to the renderer with
+/// [`LocalServerRenderer::suspense_cache`](crate::LocalServerRenderer::suspense_cache). Values
+/// inserted into the cache beforehand are used without suspending.
+///
+/// The cache is not used during client side rendering and hydration, where every component awaits
+/// its own future.
+#[derive(Clone, Default)]
+pub struct SuspenseCache {
+    inner: Rc<RefCell<CachedValues>>,
+}
+
+impl SuspenseCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a resolved value, replacing any value of the same type with the same key.
+    pub fn insert<K, O>(&self, key: K, value: O)
+    where
+        K: Into<SuspenseCacheKey>,
+        O: 'static,
+    {
+        self.inner.borrow_mut().insert(
+            (key.into(), TypeId::of::<O>()),
+            Rc::new(CacheEntry::from_value(value)),
+        );
+    }
+
+    /// Returns the number of cached values, including values that are not resolved yet.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+
+    /// Returns `true` if the cache contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
+
+    /// Returns the entry of `key`, starting the future returned by `init_f` if there is none.
+    pub(crate) fn get_or_insert_with<F, T, O>(
+        &self,
+        key: SuspenseCacheKey,
+        init_f: F,
+    ) -> CacheEntry<O>
+    where
+        F: FnOnce() -> T,
+        T: Future<Output = O> + 'static,
+        O: 'static,
+    {
+        let mut inner = self.inner.borrow_mut();
+        let entry = inner
+            .entry((key, TypeId::of::<O>()))
+            .or_insert_with(|| Rc::new(CacheEntry::from_future(init_f())));
+
+        entry
+            .downcast_ref::<CacheEntry<O>>()
+            .expect("the type of a cache entry matches its key")
+            .clone()
+    }
+}
+
+impl fmt::Debug for SuspenseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuspenseCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// A value of a [`SuspenseCache`], which might still be awaited.
+pub(crate) struct CacheEntry<O> {
+    output: Rc<RefCell<Option<Rc<O>>>>,
+    suspension: Suspension,
+}
+
+impl<O: 'static> CacheEntry<O> {
+    /// Creates an entry that resolves when `f` completes.
+    pub(crate) fn from_future(f: impl Future<Output = O> + 'static) -> Self {
+        let output = Rc::new(RefCell::new(None));
+        let suspension = {
+            let output = output.clone();
+            Suspension::from_future(async move {
+                *output.borrow_mut() = Some(Rc::new(f.await));
+            })
+        };
+
+        Self { output, suspension }
+    }
+
+    fn from_value(value: O) -> Self {
+        let (suspension, handle) = Suspension::new();
+        handle.resume();
+
+        Self {
+            output: Rc::new(RefCell::new(Some(Rc::new(value)))),
+            suspension,
+        }
+    }
+
+    /// Returns the value, or the suspension to wait for if it is not resolved yet.
+    pub(crate) fn result(&self) -> SuspensionResult<Rc<O>> {
+        self.output
+            .borrow()
+            .clone()
+            .ok_or_else(|| self.suspension.clone())
+    }
+}
+
+impl<O> Clone for CacheEntry<O> {
+    fn clone(&self) -> Self {
+        Self {
+            output: self.output.clone(),
+            suspension: self.suspension.clone(),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::html::{Children, Html, Properties};
 
 /// Properties for [Suspense].
@@ -10,6 +12,16 @@ pub struct SuspenseProps {
     /// The Fallback UI of the current Suspense Component.
     #[prop_or_default]
     pub fallback: Html,
+
+    /// How long the children have to be suspended before the fallback is shown.
+    ///
+    /// If the children resume before the delay has elapsed, the fallback is not shown at all.
+    /// Until then, the children stay in place and suspended components keep showing what they
+    /// rendered before, or nothing if they have not rendered yet. Defaults to no delay.
+    ///
+    /// The delay is ignored during server-side rendering, which always renders the children.
+    #[prop_or_default]
+    pub fallback_delay: Duration,
 }
 
 #[cfg(any(feature = "csr", feature = "ssr"))]
@@ -26,17 +38,26 @@ mod feat_csr_ssr {
     pub(crate) struct BaseSuspenseProps {
         pub children: Children,
         pub fallback: Option<Html>,
+        #[prop_or_default]
+        pub fallback_delay: Duration,
     }
 
     #[derive(Debug)]
     pub(crate) enum BaseSuspenseMsg {
         Suspend(Suspension),
         Resume(Suspension),
+        #[cfg(feature = "csr")]
+        DelayElapsed(usize),
     }
 
     #[derive(Debug)]
     pub(crate) struct BaseSuspense {
         suspensions: Vec<Suspension>,
+        /// Whether the fallback is shown while suspended.
+        show_fallback: bool,
+        /// Identifies the latest fallback delay, so earlier delays that elapse are ignored.
+        #[cfg(feature = "csr")]
+        delay_id: usize,
         #[cfg(feature = "hydration")]
         hydration_handle: Option<SuspensionHandle>,
     }
@@ -70,6 +91,10 @@ mod feat_csr_ssr {
 
             Self {
                 suspensions,
+                // Suspensions during hydration show the fallback without delay.
+                show_fallback: true,
+                #[cfg(feature = "csr")]
+                delay_id: 0,
                 #[cfg(feature = "hydration")]
                 hydration_handle,
             }
@@ -87,6 +112,10 @@ mod feat_csr_ssr {
                         return false;
                     }
 
+                    if self.suspensions.is_empty() {
+                        self.show_fallback = self.delay_fallback(ctx);
+                    }
+
                     self.suspensions.push(m);
 
                     true
@@ -97,11 +126,21 @@ mod feat_csr_ssr {
 
                     suspensions_len != self.suspensions.len()
                 }
+                #[cfg(feature = "csr")]
+                Self::Message::DelayElapsed(id) => {
+                    if id != self.delay_id || self.suspensions.is_empty() {
+                        return false;
+                    }
+
+                    !std::mem::replace(&mut self.show_fallback, true)
+                }
             }
         }
 
         fn view(&self, ctx: &Context<Self>) -> Html {
-            let BaseSuspenseProps { children, fallback } = (*ctx.props()).clone();
+            let BaseSuspenseProps {
+                children, fallback, ..
+            } = (*ctx.props()).clone();
             let children = html! {<>{children}</>};
 
             match fallback {
@@ -109,7 +148,7 @@ mod feat_csr_ssr {
                     let vsuspense = VSuspense::new(
                         children,
                         fallback,
-                        !self.suspensions.is_empty(),
+                        self.show_fallback && !self.suspensions.is_empty(),
                         // We don't need to key this as the key will be applied to the component.
                         None,
                     );
@@ -138,12 +177,41 @@ mod feat_csr_ssr {
         pub(crate) fn resume(scope: &Scope<Self>, s: Suspension) {
             scope.send_message(BaseSuspenseMsg::Resume(s));
         }
+
+        /// Starts the fallback delay, returns whether the fallback is shown right away.
+        fn delay_fallback(&mut self, ctx: &Context<Self>) -> bool {
+            let delay = ctx.props().fallback_delay;
+
+            if delay.is_zero() {
+                return true;
+            }
+
+            #[cfg(feature = "csr")]
+            {
+                use crate::platform::time::sleep;
+
+                self.delay_id += 1;
+                let id = self.delay_id;
+
+                ctx.link().send_future(async move {
+                    sleep(delay).await;
+
+                    BaseSuspenseMsg::DelayElapsed(id)
+                });
+            }
+
+            false
+        }
     }
 
     /// Suspend rendering and show a fallback UI until the underlying task completes.
     #[function_component]
     pub fn Suspense(props: &SuspenseProps) -> Html {
-        let SuspenseProps { children, fallback } = props.clone();
+        let SuspenseProps {
+            children,
+            fallback,
+            fallback_delay,
+        } = props.clone();
 
         let fallback = html! {
             <BaseSuspense fallback={None}>
@@ -152,7 +220,7 @@ mod feat_csr_ssr {
         };
 
         html! {
-            <BaseSuspense {fallback}>
+            <BaseSuspense {fallback} {fallback_delay}>
                 {children}
             </BaseSuspense>
         }
@@ -4,9 +4,13 @@ use std::future::Future;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use yew::functional::{Hook, HookContext};
 use yew::prelude::*;
 use yew::suspense::{Suspension, SuspensionResult};
 
+use super::cache::CacheEntry;
+use super::{SuspenseCache, SuspenseCacheKey};
+
 /// This hook is used to await a future in a suspending context.
 ///
 /// A [Suspension] is created from the passed future and the result of the future
@@ -124,3 +128,61 @@ where
         Err((*suspension).clone())
     }
 }
+
+/// Use the result of an async computation that is shared by all components requesting the same
+/// `key` during server-side rendering, suspending while waiting.
+///
+/// When rendered on the server side, the future returned from `init_f` is only awaited by the
+/// first component requesting `key`. Other components requesting `key` in the same render, even
+/// in different suspense boundaries, wait for the same result. See [`SuspenseCache`] for more
+/// information. On the client side, every component awaits its own future like [`use_future`].
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew::suspense::use_future_with_key;
+/// # async fn fetch_user_name() -> String {
+/// #     "Alice".to_owned()
+/// # }
+/// #[function_component]
+/// fn UserName() -> HtmlResult {
+///     let name = use_future_with_key("user", fetch_user_name)?;
+///
+///     Ok(html! { <span>{ &*name }</span> })
+/// }
+/// ```
+#[hook]
+pub fn use_future_with_key<K, F, T, O>(key: K, init_f: F) -> SuspensionResult<Rc<O>>
+where
+    K: Into<SuspenseCacheKey>,
+    F: FnOnce() -> T,
+    T: Future<Output = O> + 'static,
+    O: 'static,
+{
+    let cache = use_suspense_cache();
+    let entry = use_memo(
+        move |_| match cache {
+            Some(cache) => cache.get_or_insert_with(key.into(), init_f),
+            None => CacheEntry::from_future(init_f()),
+        },
+        (),
+    );
+
+    entry.result()
+}
+
+/// Returns the [`SuspenseCache`] of the current server-side render.
+fn use_suspense_cache() -> impl Hook<Output = Option<SuspenseCache>> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = Option<SuspenseCache>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            ctx.scope.suspense_cache()
+        }
+    }
+
+    HookProvider
+}
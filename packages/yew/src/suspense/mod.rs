@@ -1,9 +1,11 @@
 //! This module provides suspense support.
 
+mod cache;
 mod component;
 mod hooks;
 mod suspension;
 
+pub use cache::{SuspenseCache, SuspenseCacheKey};
 #[cfg(any(feature = "csr", feature = "ssr"))]
 pub(crate) use component::BaseSuspense;
 pub use component::{Suspense, SuspenseProps};
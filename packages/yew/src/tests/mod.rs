@@ -1 +1,16 @@
+//! Utilities for testing Yew applications.
+//!
+//! This module is only available with the `test-util` feature.
+
+#[cfg(feature = "csr")]
+#[cfg(test)]
 pub mod layout_tests;
+#[cfg(feature = "test-util")]
+mod render_count;
+#[cfg(feature = "test-util")]
+mod ssr;
+
+#[cfg(feature = "test-util")]
+pub use render_count::*;
+#[cfg(feature = "test-util")]
+pub use ssr::*;
@@ -0,0 +1,54 @@
+//! A hook that counts the renders of a function component.
+
+use std::cell::Cell;
+
+use crate::functional::{Effect, Hook, HookContext};
+
+struct RenderCount {
+    count: Cell<usize>,
+}
+
+impl Effect for RenderCount {
+    fn rendered(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+}
+
+/// This hook is used to count how many times a function component has been rendered.
+///
+/// The count is incremented after each render that is committed to the DOM, at the same time the
+/// effects of the component are run. It returns the number of renders committed before the
+/// current one, i.e.: `0` during the first render. Renders of a suspended component are not
+/// counted, as they are not committed. Components rendered on the server side are never
+/// committed, so the count stays `0`.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::tests::use_render_count;
+///
+/// #[function_component]
+/// fn Counted() -> Html {
+///     let renders = use_render_count();
+///
+///     html! { <div>{ renders }</div> }
+/// }
+/// ```
+pub fn use_render_count() -> impl Hook<Output = usize> {
+    struct HookProvider;
+
+    impl Hook for HookProvider {
+        type Output = usize;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let state = ctx.next_effect(|_| RenderCount {
+                count: Cell::new(0),
+            });
+
+            state.count.get()
+        }
+    }
+
+    HookProvider
+}
@@ -0,0 +1,413 @@
+//! Helpers for testing the server-side rendered output of components.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+use crate::html::BaseComponent;
+use crate::{HydrationMarker, ServerRenderer};
+
+const COMP_STATE_OPEN: &str = r#"<script type="application/x-yew-comp-state""#;
+const COMP_STATE_CLOSE: &str = "</script>";
+
+/// Removes the artifacts of hydratable rendering from a server-side rendered result.
+///
+/// This strips the comments marking the boundaries of components and suspenses, e.g.:
+/// `<!--<[app::App]>-->`, `<!--</[app::App]>-->`, `<!--<?>-->` and `<!--</?>-->`, and the
+/// `<script type="application/x-yew-comp-state">` elements holding prepared states. Everything
+/// else, including other comments, is left unchanged.
+pub fn strip_hydration_markers(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut pos = 0;
+
+    for marker in HydrationMarker::collect(html) {
+        // markers inside of a stripped state script have already been removed.
+        if marker.offset < pos {
+            continue;
+        }
+
+        push_without_comp_states(&mut result, &html[pos..marker.offset]);
+        pos = marker.offset + marker.len;
+    }
+
+    push_without_comp_states(&mut result, &html[pos..]);
+
+    result
+}
+
+fn push_without_comp_states(result: &mut String, mut s: &str) {
+    while let Some(start) = s.find(COMP_STATE_OPEN) {
+        let end = match s[start..].find(COMP_STATE_CLOSE) {
+            Some(m) => start + m + COMP_STATE_CLOSE.len(),
+            None => break,
+        };
+
+        result.push_str(&s[..start]);
+        s = &s[end..];
+    }
+
+    result.push_str(s);
+}
+
+/// Renders `COMP` on the server side and asserts that the result equals `expected`.
+///
+/// The component is rendered twice, once as hydratable and once as non-hydratable, with
+/// properties created by `props`. [`strip_hydration_markers`] is applied to both results before
+/// they are compared, so `expected` should not contain any hydration markers.
+///
+/// # Panics
+///
+/// Panics with a description of the first difference if either result does not equal
+/// `expected`.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::tests::assert_ssr_eq;
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! { <div>{"Hello, World!"}</div> }
+/// }
+///
+/// # async fn test() {
+/// assert_ssr_eq::<App, _>(|| (), "<div>Hello, World!</div>").await;
+/// # }
+/// ```
+pub async fn assert_ssr_eq<COMP, F>(props: F, expected: &str)
+where
+    COMP: BaseComponent,
+    F: 'static + Send + Clone + FnOnce() -> COMP::Properties,
+{
+    for hydratable in [true, false] {
+        let rendered = ServerRenderer::<COMP>::with_props(props.clone())
+            .hydratable(hydratable)
+            .render()
+            .await;
+
+        let actual = strip_hydration_markers(&rendered);
+
+        if actual != expected {
+            panic!(
+                "server-side rendered result (hydratable: {}) does not match\n{}",
+                hydratable,
+                describe_diff(expected, &actual)
+            );
+        }
+    }
+}
+
+/// The environment variable that makes [`assert_ssr_golden`] update golden files instead of
+/// failing when they do not match.
+pub const UPDATE_GOLDEN_ENV: &str = "YEW_UPDATE_GOLDEN";
+
+/// Options of [`assert_ssr_golden`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldenOptions {
+    /// Whether hydration markers and prepared states are ignored, see
+    /// [`strip_hydration_markers`].
+    ///
+    /// Defaults to `true`.
+    pub ignore_hydration_markers: bool,
+    /// Whether differences in whitespace are ignored.
+    ///
+    /// Whitespace before or after a tag is removed and every other run of whitespace is treated
+    /// as a single space, so a golden file can be indented.
+    ///
+    /// Defaults to `false`.
+    pub ignore_whitespace: bool,
+}
+
+impl Default for GoldenOptions {
+    fn default() -> Self {
+        Self {
+            ignore_hydration_markers: true,
+            ignore_whitespace: false,
+        }
+    }
+}
+
+impl GoldenOptions {
+    /// Applies the normalization rules of the options to `html`.
+    fn normalize(&self, html: &str) -> String {
+        let html = if self.ignore_hydration_markers {
+            strip_hydration_markers(html)
+        } else {
+            html.to_owned()
+        };
+
+        if self.ignore_whitespace {
+            collapse_whitespace(&html)
+        } else {
+            html
+        }
+    }
+}
+
+/// Removes whitespace next to tags and collapses all other runs of whitespace into a space.
+fn collapse_whitespace(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut pending_space = false;
+
+    for c in html.trim().chars() {
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+
+        if pending_space && !result.ends_with('>') && c != '<' {
+            result.push(' ');
+        }
+        pending_space = false;
+        result.push(c);
+    }
+
+    result
+}
+
+/// Renders `COMP` on the server side and asserts that the result matches the golden file at
+/// `path`.
+///
+/// The component is rendered as hydratable with properties created by `props`. Both the result
+/// and the content of the golden file are normalized according to `options` before they are
+/// compared. A relative `path` is resolved against the current directory, which is the root of
+/// the package when tests are run by cargo.
+///
+/// If the environment variable [`YEW_UPDATE_GOLDEN`](UPDATE_GOLDEN_ENV) is set, a golden file
+/// that is missing or does not match is written instead, with the hydration markers stripped if
+/// they are ignored.
+///
+/// # Panics
+///
+/// Panics with a description of the first difference if the result does not match the golden
+/// file, or if the golden file does not exist and is not updated.
+///
+/// # Example
+///
+/// ```no_run
+/// # use yew::prelude::*;
+/// use yew::tests::{assert_ssr_golden, GoldenOptions};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! { <div>{"Hello, World!"}</div> }
+/// }
+///
+/// # async fn test() {
+/// assert_ssr_golden::<App, _>(|| (), "tests/golden/app.html", GoldenOptions::default()).await;
+/// # }
+/// ```
+pub async fn assert_ssr_golden<COMP, F>(props: F, path: impl AsRef<Path>, options: GoldenOptions)
+where
+    COMP: BaseComponent,
+    F: 'static + Send + FnOnce() -> COMP::Properties,
+{
+    let rendered = ServerRenderer::<COMP>::with_props(props).render().await;
+    let update = env::var_os(UPDATE_GOLDEN_ENV).is_some();
+
+    compare_golden(path.as_ref(), &rendered, &options, update);
+}
+
+/// Compares `rendered` with the golden file at `path`, updating it on mismatch if `update` is
+/// set.
+fn compare_golden(path: &Path, rendered: &str, options: &GoldenOptions, update: bool) {
+    let golden = fs::read_to_string(path).ok();
+
+    let actual = options.normalize(rendered);
+    if let Some(ref golden) = golden {
+        if options.normalize(golden) == actual {
+            return;
+        }
+    }
+
+    if update {
+        let content = if options.ignore_hydration_markers {
+            strip_hydration_markers(rendered)
+        } else {
+            rendered.to_owned()
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create directory of golden file");
+        }
+        fs::write(path, content).expect("failed to write golden file");
+
+        return;
+    }
+
+    match golden {
+        Some(golden) => panic!(
+            "server-side rendered result does not match golden file {}\n{}\n\nset {} to update \
+             the golden file.",
+            path.display(),
+            describe_diff(&options.normalize(&golden), &actual),
+            UPDATE_GOLDEN_ENV
+        ),
+        None => panic!(
+            "golden file {} does not exist, set {} to create it.",
+            path.display(),
+            UPDATE_GOLDEN_ENV
+        ),
+    }
+}
+
+/// Describes the first difference between `expected` and `actual`.
+fn describe_diff(expected: &str, actual: &str) -> String {
+    const CONTEXT: usize = 40;
+
+    let offset = expected
+        .char_indices()
+        .zip(actual.chars())
+        .find(|((_, l), r)| l != r)
+        .map(|((i, _), _)| i)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+
+    let excerpt = |s: &str| {
+        let mut start = offset.saturating_sub(CONTEXT);
+        while !s.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = (offset + CONTEXT).min(s.len());
+        while !s.is_char_boundary(end) {
+            end += 1;
+        }
+
+        let prefix = if start > 0 { "..." } else { "" };
+        let suffix = if end < s.len() { "..." } else { "" };
+
+        (
+            format!("{}{}{}", prefix, &s[start..end], suffix),
+            prefix.len() + s[start..offset.min(s.len())].chars().count(),
+        )
+    };
+
+    let (expected_excerpt, caret) = excerpt(expected);
+    let (actual_excerpt, _) = excerpt(actual);
+
+    let mut s = String::new();
+    let _ = writeln!(s, "first difference at byte {}:", offset);
+    let _ = writeln!(s, "expected: {}", expected_excerpt);
+    let _ = writeln!(s, "  actual: {}", actual_excerpt);
+    let _ = writeln!(s, "          {}^", " ".repeat(caret));
+    let _ = writeln!(s);
+    let _ = writeln!(s, "expected (full): {}", expected);
+    let _ = write!(s, "  actual (full): {}", actual);
+
+    s
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[function_component]
+    fn Child() -> Html {
+        html! { <span>{"child"}</span> }
+    }
+
+    #[function_component]
+    fn Comp() -> Html {
+        html! {
+            <div>
+                <Child />
+                <Suspense>
+                    <Child />
+                </Suspense>
+                {"<!--not a marker-->"}
+            </div>
+        }
+    }
+
+    #[test]
+    async fn test_strip_hydration_markers() {
+        let s = ServerRenderer::<Comp>::new().render().await;
+
+        assert_eq!(
+            strip_hydration_markers(&s),
+            "<div><span>child</span><span>child</span>&lt;!--not a marker--&gt;</div>"
+        );
+
+        assert_eq!(
+            strip_hydration_markers(
+                r#"<div><!--<[a]>--><script type="application/x-yew-comp-state" data-id="0"></script><!--</[a]>--><!-- other --></div>"#
+            ),
+            "<div><!-- other --></div>"
+        );
+    }
+
+    #[test]
+    async fn test_assert_ssr_eq() {
+        assert_ssr_eq::<Comp, _>(
+            || (),
+            "<div><span>child</span><span>child</span>&lt;!--not a marker--&gt;</div>",
+        )
+        .await;
+    }
+
+    #[test]
+    #[should_panic(expected = "first difference at byte 10")]
+    async fn test_assert_ssr_eq_mismatch() {
+        assert_ssr_eq::<Child, _>(|| (), "<span>chilD</span>").await;
+    }
+
+    #[test]
+    async fn test_describe_diff() {
+        let expected = [
+            "first difference at byte 3:",
+            "expected: <p>a</p>",
+            "  actual: <p>b</p>",
+            "             ^",
+            "",
+            "expected (full): <p>a</p>",
+            "  actual (full): <p>b</p>",
+        ];
+
+        assert_eq!(describe_diff("<p>a</p>", "<p>b</p>"), expected.join("\n"));
+    }
+
+    #[test]
+    async fn test_collapse_whitespace() {
+        assert_eq!(
+            collapse_whitespace("\n<ul>\n  <li>a  b</li>\n  <li>\n    c\n  </li>\n</ul>\n"),
+            "<ul><li>a b</li><li>c</li></ul>"
+        );
+    }
+
+    #[test]
+    async fn test_golden_file() {
+        let dir = env::temp_dir().join(format!("yew-golden-{}", std::process::id()));
+        let path = dir.join("comp.html");
+        let rendered = ServerRenderer::<Comp>::new().render().await;
+        let options = GoldenOptions {
+            ignore_whitespace: true,
+            ..GoldenOptions::default()
+        };
+
+        // a missing golden file is created when updating.
+        compare_golden(&path, &rendered, &options, true);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "<div><span>child</span><span>child</span>&lt;!--not a marker--&gt;</div>"
+        );
+
+        fs::write(
+            &path,
+            "<div>\n  <span>child</span>\n  <span>child</span>\n  &lt;!--not a \
+             marker--&gt;\n</div>\n",
+        )
+        .unwrap();
+        compare_golden(&path, &rendered, &options, false);
+
+        let mismatch = std::panic::catch_unwind(|| {
+            compare_golden(&path, "<div>other</div>", &options, false);
+        });
+        assert!(mismatch.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,6 +3,7 @@
 use std::marker::PhantomData;
 
 use yew::html::ChildrenRenderer;
+use yew::virtual_dom::{VList, VNode};
 
 /// Map `IntoIterator<Item = Into<T>>` to `Iterator<Item = T>`
 pub fn into_node_iter<IT, T, R>(it: IT) -> impl Iterator<Item = R>
@@ -32,6 +33,18 @@ impl<IN: Into<OUT>, OUT> From<Option<IN>> for NodeSeq<IN, OUT> {
     }
 }
 
+/// `Ok` yields the contained value, `Err` yields the error converted into the same output type.
+impl<IN: Into<OUT>, E: Into<OUT>, OUT> From<Result<IN, E>> for NodeSeq<IN, OUT> {
+    fn from(val: Result<IN, E>) -> Self {
+        let node = match val {
+            Ok(m) => m.into(),
+            Err(e) => e.into(),
+        };
+
+        Self(vec![node], PhantomData::default())
+    }
+}
+
 impl<IN: Into<OUT>, OUT> From<Vec<IN>> for NodeSeq<IN, OUT> {
     fn from(val: Vec<IN>) -> Self {
         Self(
@@ -41,6 +54,15 @@ impl<IN: Into<OUT>, OUT> From<Vec<IN>> for NodeSeq<IN, OUT> {
     }
 }
 
+impl<IN: Into<OUT>, OUT, const N: usize> From<[IN; N]> for NodeSeq<IN, OUT> {
+    fn from(val: [IN; N]) -> Self {
+        Self(
+            IntoIterator::into_iter(val).map(|x| x.into()).collect(),
+            PhantomData::default(),
+        )
+    }
+}
+
 impl<IN: Into<OUT>, OUT> From<ChildrenRenderer<IN>> for NodeSeq<IN, OUT> {
     fn from(val: ChildrenRenderer<IN>) -> Self {
         Self(
@@ -59,6 +81,20 @@ impl<IN, OUT> IntoIterator for NodeSeq<IN, OUT> {
     }
 }
 
+/// A sequence of exactly one node is converted into that node, any other sequence is wrapped in
+/// a [`VList`]. In particular, an empty sequence (e.g.: from `None`) becomes `VList::default()`.
+impl<IN> From<NodeSeq<IN, VNode>> for VNode {
+    fn from(val: NodeSeq<IN, VNode>) -> Self {
+        let mut nodes = val.0;
+
+        if nodes.len() == 1 {
+            nodes.pop().unwrap()
+        } else {
+            VList::with_children(nodes, None).into()
+        }
+    }
+}
+
 /// Hack to force type mismatch compile errors in yew-macro.
 // TODO: replace with `compile_error!`, when `type_name_of_val` is stabilised (https://github.com/rust-lang/rust/issues/66359).
 #[doc(hidden)]
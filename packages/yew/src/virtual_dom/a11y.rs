@@ -0,0 +1,218 @@
+//! Accessibility lints of elements rendered on the server side.
+//!
+//! The lints only catch obvious mistakes in the attributes of a single element:
+//!
+//! - `role` has to be a WAI-ARIA role that is not abstract. A list of fallback roles separated by
+//!   whitespace is allowed, every role in the list has to be valid.
+//! - `aria-hidden="true"` must not be set on an element that can be focused with the keyboard, as
+//!   the element is hidden from assistive technologies but can still be reached.
+
+/// The non-abstract roles of WAI-ARIA 1.2.
+const ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Returns whether an element can be focused with the keyboard.
+fn is_focusable<'a>(tag: &str, attr: impl Fn(&str) -> Option<&'a str>) -> bool {
+    if let Some(m) = attr("tabindex") {
+        return m.trim().parse::<i32>().map_or(false, |m| m >= 0);
+    }
+
+    let is_form_control = matches!(tag, "button" | "input" | "select" | "textarea");
+    if is_form_control && attr("disabled").is_some() {
+        return false;
+    }
+
+    match tag {
+        "a" | "area" => attr("href").is_some(),
+        "input" => !attr("type").map_or(false, |m| m.eq_ignore_ascii_case("hidden")),
+        "button" | "select" | "textarea" | "iframe" | "summary" => true,
+        _ => attr("contenteditable").map_or(false, |m| m.is_empty() || m == "true"),
+    }
+}
+
+/// Returns the accessibility mistakes of an element.
+fn check_attributes<'a>(tag: &str, attrs: impl Iterator<Item = (&'a str, &'a str)>) -> Vec<String> {
+    let tag = tag.to_ascii_lowercase();
+    let attrs: Vec<_> = attrs.collect();
+    let attr = |name: &str| {
+        attrs
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| *v)
+    };
+
+    let mut mistakes = Vec::new();
+
+    if let Some(role) = attr("role") {
+        for m in role.split_ascii_whitespace() {
+            if !ROLES.iter().any(|r| r.eq_ignore_ascii_case(m)) {
+                mistakes.push(format!(
+                    "<{}> has role=\"{}\", which is not a valid WAI-ARIA role.",
+                    tag, m
+                ));
+            }
+        }
+    }
+
+    let is_hidden = attr("aria-hidden").map_or(false, |m| m.eq_ignore_ascii_case("true"));
+    if is_hidden && is_focusable(&tag, attr) {
+        mistakes.push(format!(
+            "<{}> has aria-hidden=\"true\" but can be focused, it is hidden from assistive \
+             technologies but can still be reached with the keyboard.",
+            tag
+        ));
+    }
+
+    mistakes
+}
+
+/// Warns about the accessibility mistakes of an element.
+pub(crate) fn warn_attributes<'a>(tag: &str, attrs: impl Iterator<Item = (&'a str, &'a str)>) {
+    for m in check_attributes(tag, attrs) {
+        tracing::warn!("{}", m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(tag: &str, attrs: &[(&'static str, &'static str)]) -> Vec<String> {
+        check_attributes(tag, attrs.iter().copied())
+    }
+
+    #[test]
+    fn valid_roles() {
+        assert!(check("div", &[("role", "button")]).is_empty());
+        assert!(check("div", &[("role", "switch checkbox")]).is_empty());
+        assert!(check("DIV", &[("role", "Navigation")]).is_empty());
+    }
+
+    #[test]
+    fn invalid_roles() {
+        let mistakes = check("div", &[("role", "buton")]);
+        assert_eq!(mistakes.len(), 1);
+        assert!(mistakes[0].contains("role=\"buton\""));
+
+        // abstract roles can't be used.
+        assert_eq!(check("div", &[("role", "widget")]).len(), 1);
+        assert_eq!(check("div", &[("role", "button input")]).len(), 1);
+    }
+
+    #[test]
+    fn hidden_focusable_elements() {
+        assert_eq!(check("button", &[("aria-hidden", "true")]).len(), 1);
+        assert_eq!(
+            check("a", &[("href", "/"), ("aria-hidden", "true")]).len(),
+            1
+        );
+        assert_eq!(
+            check("div", &[("tabindex", "0"), ("aria-hidden", "true")]).len(),
+            1
+        );
+        assert_eq!(
+            check(
+                "span",
+                &[("contenteditable", "true"), ("aria-hidden", "true")]
+            )
+            .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn hidden_elements() {
+        assert!(check("span", &[("aria-hidden", "true")]).is_empty());
+        assert!(check("a", &[("aria-hidden", "true")]).is_empty());
+        assert!(check("button", &[("aria-hidden", "false")]).is_empty());
+        assert!(check(
+            "button",
+            &[("disabled", "disabled"), ("aria-hidden", "true")]
+        )
+        .is_empty());
+        assert!(check("button", &[("tabindex", "-1"), ("aria-hidden", "true")]).is_empty());
+        assert!(check("input", &[("type", "hidden"), ("aria-hidden", "true")]).is_empty());
+    }
+}
@@ -0,0 +1,319 @@
+//! This module contains a fluent API to build [`VNode`]s without the `html!` macro.
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use super::{AttrValue, DataAttributes, Key, Listener, VChild, VList, VNode, VTag, VText};
+use crate::html::{BaseComponent, IntoPropValue, NodeRef};
+
+impl VNode {
+    /// Starts building an element with the given tag name.
+    ///
+    /// The builder creates the same [`VTag`] as the `html!` macro, so both can be mixed freely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use yew::prelude::*;
+    ///
+    /// let built: Html = Html::tag("div")
+    ///     .attr("class", "greeting")
+    ///     .child(Html::text("Hello, "))
+    ///     .child(Html::tag("b").child(Html::text("World")))
+    ///     .build();
+    /// ```
+    pub fn tag(tag: impl Into<Cow<'static, str>>) -> TagBuilder {
+        TagBuilder {
+            tag: VTag::new(tag),
+        }
+    }
+
+    /// Creates a text node.
+    ///
+    /// Text nodes cannot have a key. The text is escaped when rendered, like text in the `html!`
+    /// macro.
+    pub fn text(text: impl Into<AttrValue>) -> VNode {
+        VText::new(text).into()
+    }
+
+    /// Starts building a list of nodes, the equivalent of a fragment (`<>...</>`) in the `html!`
+    /// macro.
+    pub fn list() -> ListBuilder {
+        ListBuilder {
+            children: Vec::new(),
+            key: None,
+        }
+    }
+
+    /// Starts building a component with the given properties.
+    pub fn component<COMP>(props: COMP::Properties) -> ComponentBuilder<COMP>
+    where
+        COMP: BaseComponent,
+    {
+        ComponentBuilder { props, key: None }
+    }
+}
+
+/// A builder of an element, created with [`Html::tag`](VNode::tag).
+///
+/// Children can be any value that converts into a [`VNode`], including other builders.
+/// Attributes are set in the order they are added, an attribute added twice keeps the last value.
+#[derive(Debug)]
+pub struct TagBuilder {
+    tag: VTag,
+}
+
+impl TagBuilder {
+    /// Sets an attribute.
+    pub fn attr(mut self, key: &'static str, value: impl Into<AttrValue>) -> Self {
+        self.tag.add_attribute(key, value);
+
+        self
+    }
+
+    /// Sets an attribute if the value is [`Some`], like an optional attribute in the `html!`
+    /// macro.
+    pub fn attr_opt(self, key: &'static str, value: Option<impl Into<AttrValue>>) -> Self {
+        match value {
+            Some(value) => self.attr(key, value),
+            None => self,
+        }
+    }
+
+    /// Sets `data-*` attributes, see [`DataAttributes`] for more information.
+    pub fn data(mut self, data: DataAttributes) -> Self {
+        self.tag.add_data_attributes(data);
+
+        self
+    }
+
+    /// Sets the value of an `<input>` or a `<textarea>`.
+    ///
+    /// The value is ignored for any other element.
+    pub fn value(mut self, value: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.tag.set_value(value);
+
+        self
+    }
+
+    /// Sets whether an `<input>` is checked.
+    ///
+    /// The value is ignored for any other element.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.tag.set_checked(checked);
+
+        self
+    }
+
+    /// Adds an event listener, e.g.:
+    /// `Rc::new(yew::html::onclick::Wrapper::new(callback))`.
+    pub fn listener(mut self, listener: Rc<dyn Listener>) -> Self {
+        self.tag.add_listener(listener);
+
+        self
+    }
+
+    /// Sets the key of the element.
+    ///
+    /// Like in the `html!` macro, the children of a list are matched by their keys if every child
+    /// has a key.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.tag.key = Some(key.into());
+
+        self
+    }
+
+    /// Sets the [`NodeRef`] of the element.
+    pub fn node_ref(mut self, node_ref: NodeRef) -> Self {
+        self.tag.node_ref = node_ref;
+
+        self
+    }
+
+    /// Adds a child.
+    ///
+    /// Children of `<input>` and `<textarea>` are ignored.
+    pub fn child(mut self, child: impl Into<VNode>) -> Self {
+        self.tag.add_child(child.into());
+
+        self
+    }
+
+    /// Adds multiple children.
+    pub fn children<T: Into<VNode>>(mut self, children: impl IntoIterator<Item = T>) -> Self {
+        self.tag.add_children(children.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Finishes building the element.
+    pub fn build(self) -> VNode {
+        self.tag.into()
+    }
+}
+
+impl From<TagBuilder> for VNode {
+    fn from(builder: TagBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// A builder of a list of nodes, created with [`Html::list`](VNode::list).
+#[derive(Debug)]
+pub struct ListBuilder {
+    children: Vec<VNode>,
+    key: Option<Key>,
+}
+
+impl ListBuilder {
+    /// Sets the key of the list.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.key = Some(key.into());
+
+        self
+    }
+
+    /// Adds a child.
+    pub fn child(mut self, child: impl Into<VNode>) -> Self {
+        self.children.push(child.into());
+
+        self
+    }
+
+    /// Adds multiple children.
+    pub fn children<T: Into<VNode>>(mut self, children: impl IntoIterator<Item = T>) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Finishes building the list.
+    pub fn build(self) -> VNode {
+        VList::with_children(self.children, self.key).into()
+    }
+}
+
+impl From<ListBuilder> for VNode {
+    fn from(builder: ListBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// A builder of a component, created with [`Html::component`](VNode::component).
+///
+/// The properties are passed as they are, so children of the component are set through its
+/// properties, e.g.: with a field of type [`Children`](crate::html::Children).
+pub struct ComponentBuilder<COMP>
+where
+    COMP: BaseComponent,
+{
+    props: COMP::Properties,
+    key: Option<Key>,
+}
+
+impl<COMP> ComponentBuilder<COMP>
+where
+    COMP: BaseComponent,
+{
+    /// Sets the key of the component.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.key = Some(key.into());
+
+        self
+    }
+
+    /// Finishes building the component.
+    pub fn build(self) -> VNode {
+        VChild::<COMP>::new(self.props, self.key).into()
+    }
+}
+
+impl<COMP> From<ComponentBuilder<COMP>> for VNode
+where
+    COMP: BaseComponent,
+{
+    fn from(builder: ComponentBuilder<COMP>) -> Self {
+        builder.build()
+    }
+}
+
+impl<COMP> std::fmt::Debug for ComponentBuilder<COMP>
+where
+    COMP: BaseComponent,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ComponentBuilder<_>")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "ssr")]
+#[cfg(test)]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[derive(PartialEq, Properties)]
+    struct ItemProps {
+        name: AttrValue,
+    }
+
+    #[function_component]
+    fn Item(props: &ItemProps) -> Html {
+        html! { <li class="item">{props.name.clone()}</li> }
+    }
+
+    #[derive(PartialEq, Properties)]
+    struct ListProps {
+        builder: bool,
+    }
+
+    #[function_component]
+    fn List(props: &ListProps) -> Html {
+        let names = ["a", "b"];
+
+        if props.builder {
+            return Html::tag("div")
+                .attr("id", "root")
+                .attr("data-kind", "list")
+                .child(Html::tag("h1").child(Html::text("Items")))
+                .child(Html::tag("ul").children(names.iter().map(|&name| {
+                    Html::component::<Item>(ItemProps { name: name.into() }).key(name)
+                })))
+                .child(
+                    Html::list()
+                        .child(Html::tag("input").value("x"))
+                        .child(Html::text("text")),
+                )
+                .build();
+        }
+
+        html! {
+            <div id="root" data-kind="list">
+                <h1>{"Items"}</h1>
+                <ul>
+                    { for names.iter().map(|&name| html! { <Item key={name} name={name} /> }) }
+                </ul>
+                <>
+                    <input value="x" />
+                    {"text"}
+                </>
+            </div>
+        }
+    }
+
+    #[test]
+    async fn test_same_output_as_macro() {
+        for hydratable in [false, true] {
+            let render = |builder| {
+                ServerRenderer::<List>::with_props(move || ListProps { builder })
+                    .hydratable(hydratable)
+                    .render()
+            };
+
+            assert_eq!(render(true).await, render(false).await);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! This module contains the implementation of `data-*` attributes.
+
+use std::rc::Rc;
+
+use super::AttrValue;
+
+/// A list of `data-*` attributes, which can be added to a [`VTag`](super::VTag) with
+/// [`VTag::add_data_attributes`](super::VTag::add_data_attributes).
+///
+/// Keys are converted to kebab-case and prefixed with `data-`, so they match the names of the
+/// [`dataset`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dataset) of the
+/// element, e.g.: `userId` becomes `data-user-id`. Values are converted to strings with
+/// [`IntoDataValue`]. Attributes keep the order in which they were inserted.
+///
+/// The [`data_attrs!`](crate::data_attrs!) macro creates a list from key-value pairs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataAttributes {
+    attrs: Vec<(AttrValue, AttrValue)>,
+}
+
+impl DataAttributes {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an attribute, replacing an attribute with the same name.
+    ///
+    /// If the value converts to [`None`], the attribute is removed instead.
+    pub fn insert(&mut self, key: &str, value: impl IntoDataValue) {
+        let name = AttrValue::from(data_attribute_name(key));
+        let value = value.into_data_value();
+
+        match self.attrs.iter().position(|(m, _)| *m == name) {
+            Some(index) => match value {
+                Some(value) => self.attrs[index].1 = value,
+                None => {
+                    self.attrs.remove(index);
+                }
+            },
+            None => {
+                if let Some(value) = value {
+                    self.attrs.push((name, value));
+                }
+            }
+        }
+    }
+
+    /// Returns the number of attributes.
+    pub fn len(&self) -> usize {
+        self.attrs.len()
+    }
+
+    /// Returns `true` if the list contains no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attrs.is_empty()
+    }
+
+    /// Returns an iterator over the names, including the `data-` prefix, and the values of the
+    /// attributes.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attrs.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl IntoIterator for DataAttributes {
+    type IntoIter = std::vec::IntoIter<(AttrValue, AttrValue)>;
+    type Item = (AttrValue, AttrValue);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.attrs.into_iter()
+    }
+}
+
+/// Converts a key to the name of a `data-*` attribute.
+///
+/// Upper case letters are lowered and preceded by a dash, underscores are replaced by dashes.
+fn data_attribute_name(key: &str) -> String {
+    let mut name = String::with_capacity(key.len() + 8);
+    name.push_str("data-");
+
+    for c in key.chars() {
+        match c {
+            'A'..='Z' => {
+                name.push('-');
+                name.push(c.to_ascii_lowercase());
+            }
+            '_' => name.push('-'),
+            _ => name.push(c),
+        }
+    }
+
+    name
+}
+
+/// A value of a `data-*` attribute.
+///
+/// Numbers and strings are written as they are. Booleans are written as `"true"` and `"false"`,
+/// as `data-*` attributes only hold strings and an attribute set to `"false"` is read back as
+/// `"false"` from the dataset. To omit an attribute, use an [`Option`], which removes the attribute
+/// if it is [`None`].
+pub trait IntoDataValue {
+    /// Converts the value to the string of the attribute, or [`None`] if it is omitted.
+    fn into_data_value(self) -> Option<AttrValue>;
+}
+
+impl IntoDataValue for &'static str {
+    fn into_data_value(self) -> Option<AttrValue> {
+        Some(AttrValue::Static(self))
+    }
+}
+
+impl IntoDataValue for String {
+    fn into_data_value(self) -> Option<AttrValue> {
+        Some(AttrValue::Rc(Rc::from(self)))
+    }
+}
+
+impl IntoDataValue for Rc<str> {
+    fn into_data_value(self) -> Option<AttrValue> {
+        Some(AttrValue::Rc(self))
+    }
+}
+
+impl IntoDataValue for AttrValue {
+    fn into_data_value(self) -> Option<AttrValue> {
+        Some(self)
+    }
+}
+
+impl<T: IntoDataValue> IntoDataValue for Option<T> {
+    fn into_data_value(self) -> Option<AttrValue> {
+        self.and_then(IntoDataValue::into_data_value)
+    }
+}
+
+macro_rules! impl_into_data_value {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoDataValue for $ty {
+                fn into_data_value(self) -> Option<AttrValue> {
+                    Some(AttrValue::Rc(Rc::from(self.to_string())))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_data_value!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+/// Creates [`DataAttributes`](crate::virtual_dom::DataAttributes) from key-value pairs.
+///
+/// See [`DataAttributes`](crate::virtual_dom::DataAttributes) for how keys and values are
+/// converted.
+///
+/// # Example
+///
+/// ```
+/// use yew::data_attrs;
+/// use yew::virtual_dom::VTag;
+///
+/// let mut tag = VTag::new("div");
+/// tag.add_data_attributes(data_attrs!({ "userId" => 5, "active" => true }));
+/// ```
+#[macro_export]
+macro_rules! data_attrs {
+    ({ $($key:expr => $value:expr),* $(,)? }) => {{
+        #[allow(unused_mut)]
+        let mut data = $crate::virtual_dom::DataAttributes::new();
+        $(data.insert($key, $value);)*
+        data
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn converts_keys_to_kebab_case() {
+        let data = data_attrs!({
+            "userId" => 5,
+            "user_name" => "Alice",
+            "active" => true,
+            "already-kebab" => 'x',
+        });
+
+        assert_eq!(
+            data.iter().collect::<Vec<_>>(),
+            vec![
+                ("data-user-id", "5"),
+                ("data-user-name", "Alice"),
+                ("data-active", "true"),
+                ("data-already-kebab", "x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn replaces_and_removes_attributes() {
+        let mut data = data_attrs!({ "a" => 1, "b" => false, "c" => 3 });
+        data.insert("b", "replaced");
+        data.insert("a", None::<i32>);
+        data.insert("d", None::<i32>);
+
+        assert_eq!(
+            data.iter().collect::<Vec<_>>(),
+            vec![("data-b", "replaced"), ("data-c", "3")]
+        );
+    }
+}
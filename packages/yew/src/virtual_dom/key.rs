@@ -14,6 +14,16 @@ pub struct Key {
     key: Rc<str>,
 }
 
+impl Key {
+    /// Returns the key as a string slice.
+    ///
+    /// Keys created from numbers are stored in their decimal form, e.g.: the key `42_u32` is
+    /// returned as `"42"`. This does not allocate.
+    pub fn as_str(&self) -> &str {
+        &self.key
+    }
+}
+
 impl Display for Key {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.key.fmt(f)
@@ -75,6 +85,7 @@ mod test {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
 
+    use super::Key;
     use crate::html;
 
     #[cfg(target_arch = "wasm32")]
@@ -102,4 +113,13 @@ mod test {
             </>
         };
     }
+
+    #[test]
+    fn key_as_str() {
+        assert_eq!(Key::from("string").as_str(), "string");
+        assert_eq!(Key::from(42_u32).as_str(), "42");
+        assert_eq!(Key::from(-7_i64).as_str(), "-7");
+        assert_eq!(Key::from('a').to_string(), "a");
+        assert_eq!(format!("{}", Key::from(11_usize)), "11");
+    }
 }
@@ -1,5 +1,11 @@
 //! This module contains Yew's implementation of a reactive virtual DOM.
 
+#[cfg(all(feature = "a11y-lints", debug_assertions))]
+mod a11y;
+#[doc(hidden)]
+pub mod builder;
+#[doc(hidden)]
+pub mod data;
 #[doc(hidden)]
 pub mod key;
 #[doc(hidden)]
@@ -25,6 +31,10 @@ use std::hint::unreachable_unchecked;
 
 use indexmap::IndexMap;
 
+#[doc(inline)]
+pub use self::builder::{ComponentBuilder, ListBuilder, TagBuilder};
+#[doc(inline)]
+pub use self::data::{DataAttributes, IntoDataValue};
 #[doc(inline)]
 pub use self::key::Key;
 #[doc(inline)]
@@ -47,6 +57,12 @@ pub use self::vtag::VTag;
 pub use self::vtext::VText;
 
 /// Attribute value
+///
+/// An attribute value is either borrowed for `'static` with [`AttrValue::Static`] or shared with
+/// [`AttrValue::Rc`]. Constructing a static value stores the borrowed `&'static str` without
+/// allocating or copying, string literals in the [`html!`](crate::html!) macro are always turned
+/// into static values. Use [`as_str`](implicit_clone::unsync::IString::as_str) to borrow the
+/// string of either variant. Values compare equal by their content, regardless of the variant.
 pub type AttrValue = implicit_clone::unsync::IString;
 
 #[cfg(any(feature = "ssr", feature = "hydration"))]
@@ -59,6 +75,9 @@ mod feat_ssr_hydration {
     #[cfg(feature = "hydration")]
     use std::borrow::Cow;
 
+    #[cfg(feature = "hydration")]
+    use super::Key;
+
     /// A collectable.
     ///
     /// This indicates a kind that can be collected from fragment to be processed at a later time
@@ -67,6 +86,54 @@ mod feat_ssr_hydration {
         Suspense,
     }
 
+    /// The mark written between the open start mark and the end mark of a suspense, it tells
+    /// whether the children of the suspense were rendered on the server side.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SuspenseMark {
+        /// The children were resolved and rendered on the server side, i.e.: `<!--<?>-->`.
+        ///
+        /// The children are hydrated with the states prepared on the server side, so they do
+        /// not have to suspend again.
+        Resolved,
+        /// The children were deferred to the client side and the fallback was rendered, i.e.:
+        /// `<!--<?defer>-->`.
+        Deferred,
+    }
+
+    /// The mark that separates the name of a keyed component from its key in the open tag, i.e.:
+    /// `<!--<[app::Item#key]>-->`.
+    pub const KEY_MARK: char = '#';
+
+    /// The text of the comment that opens the root boundary of an application, i.e.:
+    /// `<!--<yew-root>-->`.
+    pub const ROOT_OPEN_MARK: &str = "<yew-root>";
+
+    /// The text of the comment that closes the root boundary of an application, i.e.:
+    /// `<!--</yew-root>-->`.
+    pub const ROOT_CLOSE_MARK: &str = "</yew-root>";
+
+    impl SuspenseMark {
+        pub fn as_str(self) -> &'static str {
+            match self {
+                Self::Resolved => "",
+                Self::Deferred => "defer",
+            }
+        }
+
+        /// Returns the mark of a suspense from the text of its open tag.
+        #[cfg(feature = "hydration")]
+        pub fn from_open_tag(comment_text: &str) -> Option<Self> {
+            let suspense = Collectable::Suspense;
+            let mark = comment_text
+                .strip_prefix(suspense.open_start_mark())?
+                .strip_suffix(suspense.end_mark())?;
+
+            [Self::Resolved, Self::Deferred]
+                .into_iter()
+                .find(|m| m.as_str() == mark)
+        }
+    }
+
     impl Collectable {
         pub fn for_component<T: 'static>() -> Self {
             #[cfg(debug_assertions)]
@@ -97,6 +164,58 @@ mod feat_ssr_hydration {
             }
         }
 
+        /// Returns the key of a keyed component from the text of its open tag.
+        #[cfg(feature = "hydration")]
+        pub fn key_from_open_tag(comment_text: &str) -> Option<Key> {
+            let component = Self::Component(Default::default());
+            let body = comment_text
+                .strip_prefix(component.open_start_mark())?
+                .strip_suffix(component.end_mark())?;
+            let (_, encoded) = body.split_once(KEY_MARK)?;
+
+            let mut key = String::with_capacity(encoded.len());
+            let mut rest = encoded;
+            while let Some(pos) = rest.find('%') {
+                key.push_str(&rest[..pos]);
+                let code = rest.get(pos + 1..pos + 3)?;
+                key.push(char::from(u8::from_str_radix(code, 16).ok()?));
+                rest = &rest[pos + 3..];
+            }
+            key.push_str(rest);
+
+            Some(key.into())
+        }
+
+        /// Returns whether the text of a comment is the open tag of this component.
+        ///
+        /// The names of components are only written in debug builds. In release builds, the open
+        /// tag of any component matches.
+        #[cfg(feature = "hydration")]
+        pub fn is_component_open_tag(&self, comment_text: &str) -> bool {
+            let body = match comment_text
+                .strip_prefix(self.open_start_mark())
+                .and_then(|m| m.strip_suffix(self.end_mark()))
+            {
+                Some(m) => m,
+                None => return false,
+            };
+
+            match self {
+                #[cfg(debug_assertions)]
+                Self::Component(type_name) => {
+                    // The name is followed by the key of keyed components.
+                    let name = body.split_once(KEY_MARK).map_or(body, |(m, _)| m);
+                    name == *type_name
+                }
+                #[cfg(not(debug_assertions))]
+                Self::Component(_) => {
+                    let _ = body;
+                    true
+                }
+                Self::Suspense => false,
+            }
+        }
+
         #[cfg(feature = "hydration")]
         pub fn name(&self) -> Cow<'static, str> {
             match self {
@@ -115,10 +234,76 @@ pub(crate) use feat_ssr_hydration::*;
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::cell::{Cell, RefCell};
     use std::fmt::Write;
+    use std::rc::Rc;
 
     use super::*;
+    use crate::html::HydrationData;
     use crate::platform::fmt::BufWriter;
+    use crate::server_renderer::{SsrCache, UrlRewriter};
+
+    /// The state threaded through the server-side rendering of a virtual DOM tree.
+    #[derive(Debug, Clone)]
+    pub(crate) struct SsrContext {
+        /// Whether the rendered result includes hydration markers.
+        pub hydratable: bool,
+        /// The indentation depth of the current node, if the result is pretty-printed.
+        pub indent: Option<usize>,
+        /// Set when a block-level element is written into the current element, if the result is
+        /// pretty-printed.
+        pub has_block_children: Option<Rc<Cell<bool>>>,
+        /// Collects prepared states instead of embedding them into the rendered result.
+        pub hydration_data: Option<Rc<RefCell<HydrationData>>>,
+        /// Whether suspense fallbacks are rendered instead of the children.
+        pub defer_suspense: bool,
+        /// Whether elements are serialized as XHTML.
+        pub xhtml: bool,
+        /// Whether the markers of the next rendered component are omitted, while its children
+        /// keep their markers.
+        pub hydratable_children: bool,
+        /// The name of the raw text element, i.e.: `script` or `style`, whose text is rendered,
+        /// if any. Its text is written without escaping.
+        pub raw_text_element: Option<&'static str>,
+        /// The cache of components rendered with [`VChild::ssr_cached`], if any.
+        pub cache: Option<SsrCache>,
+        /// The rewriter of URLs in attributes, if any.
+        pub url_rewriter: Option<UrlRewriter>,
+        /// The number of suspenses the current node is nested in.
+        pub suspense_depth: usize,
+        /// The number of suspenses that may be nested before rendering is aborted.
+        pub max_suspense_depth: usize,
+        /// Whether runs of whitespace in texts are collapsed into a single space.
+        pub collapse_whitespace: bool,
+    }
+
+    /// Characters of a key that are percent-encoded in an open tag, as they would either end the
+    /// comment, the tag or be mistaken for the key mark.
+    const KEY_ESCAPED_CHARS: &[char] = &['%', KEY_MARK, '-', '<', '>', ']'];
+
+    impl SsrContext {
+        /// Writes a line break and the indentation of the current depth, if pretty-printed.
+        pub(crate) fn write_indent(&self, w: &mut BufWriter) {
+            if let Some(depth) = self.indent {
+                write_line_break(w, depth);
+            }
+        }
+
+        /// Writes a line break and the indentation of the element the current node is a child
+        /// of, if pretty-printed.
+        pub(crate) fn write_close_indent(&self, w: &mut BufWriter) {
+            if let Some(depth) = self.indent {
+                write_line_break(w, depth.saturating_sub(1));
+            }
+        }
+    }
+
+    fn write_line_break(w: &mut BufWriter, depth: usize) {
+        let _ = w.write_char('\n');
+        for _ in 0..depth {
+            let _ = w.write_str("  ");
+        }
+    }
 
     impl Collectable {
         pub(crate) fn write_open_tag(&self, w: &mut BufWriter) {
@@ -137,6 +322,41 @@ mod feat_ssr {
             let _ = w.write_str("-->");
         }
 
+        /// Writes the open tag of a component that is rendered with a key.
+        ///
+        /// The key is written after the name of the component, so keyed children can be matched
+        /// to the nodes rendered on the server side during hydration.
+        pub(crate) fn write_keyed_open_tag(&self, w: &mut BufWriter, key: &Key) {
+            let _ = w.write_str("<!--");
+            let _ = w.write_str(self.open_start_mark());
+
+            #[cfg(debug_assertions)]
+            if let Self::Component(type_name) = self {
+                let _ = w.write_str(type_name);
+            }
+
+            let _ = w.write_char(KEY_MARK);
+            for c in key.chars() {
+                if KEY_ESCAPED_CHARS.contains(&c) {
+                    let _ = write!(w, "%{:02X}", c as u32);
+                } else {
+                    let _ = w.write_char(c);
+                }
+            }
+
+            let _ = w.write_str(self.end_mark());
+            let _ = w.write_str("-->");
+        }
+
+        /// Writes the open tag of a suspense with its mark.
+        pub(crate) fn write_suspense_open_tag(&self, w: &mut BufWriter, mark: SuspenseMark) {
+            let _ = w.write_str("<!--");
+            let _ = w.write_str(self.open_start_mark());
+            let _ = w.write_str(mark.as_str());
+            let _ = w.write_str(self.end_mark());
+            let _ = w.write_str("-->");
+        }
+
         pub(crate) fn write_close_tag(&self, w: &mut BufWriter) {
             let _ = w.write_str("<!--");
             let _ = w.write_str(self.close_start_mark());
@@ -155,6 +375,9 @@ mod feat_ssr {
     }
 }
 
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::*;
+
 /// Defines if the [`Attributes`] is set as element's attribute or property
 #[allow(missing_docs)]
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
@@ -164,6 +387,12 @@ pub enum ApplyAttributeAs {
 }
 
 /// A collection of attributes for an element
+///
+/// Attributes are iterated, and rendered during server-side rendering, in a deterministic order.
+/// The `html!` macro lists the attributes of an element in the order they are written, followed
+/// by boolean attributes such as `hidden` and finally `class`. Attributes added at runtime are
+/// kept in the order in which they were first inserted into the [`IndexMap`], overwriting the
+/// value of an attribute does not change its position.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum Attributes {
     /// Static list of attributes.
@@ -197,7 +426,7 @@ impl Attributes {
         Self::default()
     }
 
-    /// Return iterator over attribute key-value pairs.
+    /// Return iterator over attribute key-value pairs in the order described in [`Attributes`].
     /// This function is suboptimal and does not inline well. Avoid on hot paths.
     pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a str, &'a str)> + 'a> {
         match self {
@@ -273,3 +502,34 @@ impl Default for Attributes {
         Self::Static(&[])
     }
 }
+
+#[cfg(feature = "hydration")]
+#[cfg(test)]
+mod tests {
+    use super::Collectable;
+
+    struct Comp;
+
+    #[test]
+    fn component_open_tag() {
+        let collectable = Collectable::for_component::<Comp>();
+        let name = std::any::type_name::<Comp>();
+
+        assert!(!collectable.is_component_open_tag(&format!("</[{}]>", name)));
+        assert!(!collectable.is_component_open_tag("<?>"));
+
+        #[cfg(debug_assertions)]
+        {
+            assert!(collectable.is_component_open_tag(&format!("<[{}]>", name)));
+            assert!(collectable.is_component_open_tag(&format!("<[{}#key]>", name)));
+            assert!(!collectable.is_component_open_tag("<[other::Comp]>"));
+        }
+
+        // Release builds do not write the names of components, so any component matches.
+        #[cfg(not(debug_assertions))]
+        {
+            assert!(collectable.is_component_open_tag("<[]>"));
+            assert!(collectable.is_component_open_tag("<[#key]>"));
+        }
+    }
+}
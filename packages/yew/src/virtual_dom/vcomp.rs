@@ -1,7 +1,8 @@
 //! This module contains the implementation of a virtual component (`VComp`).
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
 use std::fmt;
+use std::hash::Hash;
 use std::rc::Rc;
 
 #[cfg(feature = "ssr")]
@@ -21,12 +22,20 @@ use crate::html::{AnyScope, Scope};
 use crate::html::{NodeRef, Scoped};
 #[cfg(feature = "ssr")]
 use crate::platform::fmt::BufWriter;
+#[cfg(feature = "ssr")]
+use crate::server_renderer::CachedComponent;
+#[cfg(feature = "ssr")]
+use crate::virtual_dom::SsrContext;
 
 /// A virtual component.
 pub struct VComp {
     pub(crate) type_id: TypeId,
     pub(crate) mountable: Box<dyn Mountable>,
     pub(crate) key: Option<Key>,
+    /// The type and the properties of the component, if its rendered result is cached in the
+    /// [`SsrCache`](crate::SsrCache).
+    #[cfg(feature = "ssr")]
+    pub(crate) ssr_cached: Option<CachedComponent>,
     // for some reason, this reduces the bundle size by ~2-3 KBs
     _marker: u32,
 }
@@ -47,6 +56,8 @@ impl Clone for VComp {
             type_id: self.type_id,
             mountable: self.mountable.copy(),
             key: self.key.clone(),
+            #[cfg(feature = "ssr")]
+            ssr_cached: self.ssr_cached.clone(),
             _marker: 0,
         }
     }
@@ -55,6 +66,12 @@ impl Clone for VComp {
 pub(crate) trait Mountable {
     fn copy(&self) -> Box<dyn Mountable>;
 
+    /// Returns the properties of the component as [`Any`].
+    fn props_any(&self) -> &dyn Any;
+
+    /// Returns `true` if `other` mounts a component with equal properties.
+    fn props_eq(&self, other: &dyn Mountable) -> bool;
+
     #[cfg(feature = "csr")]
     fn mount(
         self: Box<Self>,
@@ -73,7 +90,15 @@ pub(crate) trait Mountable {
         &'a self,
         w: &'a mut BufWriter,
         parent_scope: &'a AnyScope,
-        hydratable: bool,
+        key: Option<&'a Key>,
+        ctx: &'a SsrContext,
+    ) -> LocalBoxFuture<'a, ()>;
+
+    #[cfg(feature = "ssr")]
+    fn collect_text_content<'a>(
+        &'a self,
+        w: &'a mut String,
+        parent_scope: &'a AnyScope,
     ) -> LocalBoxFuture<'a, ()>;
 
     #[cfg(feature = "hydration")]
@@ -105,6 +130,19 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         Box::new(wrapper)
     }
 
+    fn props_any(&self) -> &dyn Any {
+        self.props.as_ref()
+    }
+
+    fn props_eq(&self, other: &dyn Mountable) -> bool {
+        match other.props_any().downcast_ref::<COMP::Properties>() {
+            Some(other_props) => {
+                std::ptr::eq(self.props.as_ref(), other_props) || *self.props == *other_props
+            }
+            None => false,
+        }
+    }
+
     #[cfg(feature = "csr")]
     fn mount(
         self: Box<Self>,
@@ -115,7 +153,14 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         next_sibling: NodeRef,
     ) -> Box<dyn Scoped> {
         let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
-        scope.mount_in_place(root.clone(), parent, next_sibling, internal_ref, self.props);
+        scope.mount_in_place(
+            root.clone(),
+            parent,
+            next_sibling,
+            internal_ref,
+            self.props,
+            None,
+        );
 
         Box::new(scope)
     }
@@ -131,18 +176,33 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         &'a self,
         w: &'a mut BufWriter,
         parent_scope: &'a AnyScope,
-        hydratable: bool,
+        key: Option<&'a Key>,
+        ctx: &'a SsrContext,
     ) -> LocalBoxFuture<'a, ()> {
         let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
 
         async move {
             scope
-                .render_into_stream(w, self.props.clone(), hydratable)
+                .render_into_stream(w, self.props.clone(), key, ctx)
                 .await;
         }
         .boxed_local()
     }
 
+    #[cfg(feature = "ssr")]
+    fn collect_text_content<'a>(
+        &'a self,
+        w: &'a mut String,
+        parent_scope: &'a AnyScope,
+    ) -> LocalBoxFuture<'a, ()> {
+        let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
+
+        async move {
+            scope.collect_text_content(w, self.props.clone()).await;
+        }
+        .boxed_local()
+    }
+
     #[cfg(feature = "hydration")]
     fn hydrate(
         self: Box<Self>,
@@ -153,7 +213,7 @@ impl<COMP: BaseComponent> Mountable for PropsWrapper<COMP> {
         fragment: &mut Fragment,
     ) -> Box<dyn Scoped> {
         let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
-        scope.hydrate_in_place(root, parent, fragment, internal_ref, self.props);
+        scope.hydrate_in_place(root, parent, fragment, internal_ref, self.props, None);
 
         Box::new(scope)
     }
@@ -181,7 +241,7 @@ where
     COMP::Properties: PartialEq,
 {
     fn eq(&self, other: &VChild<COMP>) -> bool {
-        self.props == other.props
+        self.ptr_eq(other) || self.props == other.props
     }
 }
 
@@ -196,6 +256,53 @@ where
             key,
         }
     }
+
+    /// Creates a child component from properties that are already shared.
+    ///
+    /// A component is not re-rendered if it receives the same `Rc` it was rendered with, its
+    /// properties are not compared in this case. This allows to skip re-renders of components
+    /// whose properties cannot be meaningfully compared, e.g.: because they contain closures.
+    /// Such properties can implement [`PartialEq`] to always return `false`, so the component is
+    /// re-rendered whenever it receives different properties.
+    pub fn from_rc(props: Rc<COMP::Properties>, key: Option<Key>) -> Self {
+        Self { props, key }
+    }
+
+    /// Returns `true` if both children share the same properties.
+    ///
+    /// Unlike [`PartialEq`], this does not compare the properties and does not require them to
+    /// implement [`PartialEq`] meaningfully.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.props, &other.props)
+    }
+}
+
+impl<COMP> VChild<COMP>
+where
+    COMP: BaseComponent,
+    COMP::Properties: 'static + Hash + Clone + Send + Sync,
+{
+    /// Converts the child into a component whose server-side rendered result is cached.
+    ///
+    /// When the component is rendered by a server renderer with an [`SsrCache`](crate::SsrCache),
+    /// its result is reused for components of the same type with equal properties. The cache
+    /// keeps a copy of the properties, which is why they have to be [`Clone`] and thread-safe.
+    /// See [`SsrCache`](crate::SsrCache) for more information.
+    ///
+    /// On the client side, this is the same as converting the child into a [`VComp`].
+    pub fn ssr_cached(self) -> VComp {
+        #[cfg(feature = "ssr")]
+        let ssr_cached = CachedComponent::new::<COMP, _>(self.props.as_ref());
+
+        #[allow(unused_mut)]
+        let mut comp = VComp::from(self);
+        #[cfg(feature = "ssr")]
+        {
+            comp.ssr_cached = Some(ssr_cached);
+        }
+
+        comp
+    }
 }
 
 impl<COMP> From<VChild<COMP>> for VComp
@@ -217,14 +324,36 @@ impl VComp {
             type_id: TypeId::of::<COMP>(),
             mountable: Box::new(PropsWrapper::<COMP>::new(props)),
             key,
+            #[cfg(feature = "ssr")]
+            ssr_cached: None,
             _marker: 0,
         }
     }
+
+    /// Returns the key of the component.
+    pub fn key(&self) -> Option<&Key> {
+        self.key.as_ref()
+    }
+
+    /// Sets the key of the component.
+    ///
+    /// Keyed components that are rendered on the server side write their key into their
+    /// hydration marker, so children of a keyed list are hydrated from the nodes rendered for
+    /// the same key.
+    pub fn with_key(mut self, key: impl Into<Key>) -> Self {
+        self.key = Some(key.into());
+
+        self
+    }
 }
 
 impl PartialEq for VComp {
+    /// Two [`VComp`]s are equal if they mount the same component type with equal properties and
+    /// the same key.
     fn eq(&self, other: &VComp) -> bool {
         self.type_id == other.type_id
+            && self.key == other.key
+            && self.mountable.props_eq(other.mountable.as_ref())
     }
 }
 
@@ -236,8 +365,15 @@ impl<COMP: BaseComponent> fmt::Debug for VChild<COMP> {
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::fmt::Write;
+
+    use futures::join;
+    use futures::stream::StreamExt;
+
     use super::*;
     use crate::html::AnyScope;
+    use crate::platform::fmt;
+    use crate::server_renderer::SsrCacheKey;
 
     impl VComp {
         #[inline]
@@ -245,16 +381,144 @@ mod feat_ssr {
             &self,
             w: &mut BufWriter,
             parent_scope: &AnyScope,
-            hydratable: bool,
+            ctx: &SsrContext,
         ) {
+            // hydration markers and indentation depend on where the component is rendered, and a
+            // cached result does not register the styles of the components it contains.
+            let cache = match (&self.ssr_cached, &ctx.cache) {
+                (Some(component), Some(cache))
+                    if !ctx.hydratable
+                        && ctx.indent.is_none()
+                        && ctx.raw_text_element.is_none()
+                        && parent_scope.style_registry().is_none() =>
+                {
+                    Some((SsrCacheKey::new(component.clone(), ctx), cache))
+                }
+                _ => None,
+            };
+
+            let (key, cache) = match cache {
+                Some(m) => m,
+                None => {
+                    self.mountable
+                        .as_ref()
+                        .render_into_stream(w, parent_scope, self.key.as_ref(), ctx)
+                        .await;
+                    return;
+                }
+            };
+
+            if let Some(html) = cache.get(&key) {
+                let _ = w.write_str(&html);
+                return;
+            }
+
+            let (mut buf_w, buf_r) = fmt::buffer();
+            let render_fur = async move {
+                self.mountable
+                    .as_ref()
+                    .render_into_stream(&mut buf_w, parent_scope, self.key.as_ref(), ctx)
+                    .await;
+            };
+            let (_, html) = join!(render_fur, buf_r.collect::<String>());
+
+            let _ = w.write_str(&html);
+            cache.insert(key, html.into());
+        }
+
+        #[inline]
+        pub(crate) async fn collect_text_content(&self, w: &mut String, parent_scope: &AnyScope) {
             self.mountable
                 .as_ref()
-                .render_into_stream(w, parent_scope, hydratable)
+                .collect_text_content(w, parent_scope)
                 .await;
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[cfg(target_arch = "wasm32")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct NameProps {
+        name: String,
+    }
+
+    #[function_component]
+    fn Greeting(props: &NameProps) -> Html {
+        html! { <div>{"Hello, "}{&props.name}{"!"}</div> }
+    }
+
+    #[function_component]
+    fn Farewell(props: &NameProps) -> Html {
+        html! { <div>{"Bye, "}{&props.name}{"!"}</div> }
+    }
+
+    fn vcomp<COMP>(name: &str) -> VComp
+    where
+        COMP: BaseComponent<Properties = NameProps>,
+    {
+        keyed_vcomp::<COMP>(name, None)
+    }
+
+    fn keyed_vcomp<COMP>(name: &str, key: Option<&str>) -> VComp
+    where
+        COMP: BaseComponent<Properties = NameProps>,
+    {
+        VComp::new::<COMP>(
+            Rc::new(NameProps {
+                name: name.to_owned(),
+            }),
+            key.map(Key::from),
+        )
+    }
+
+    #[test]
+    fn eq_considers_props() {
+        assert_eq!(vcomp::<Greeting>("Jane"), vcomp::<Greeting>("Jane"));
+        assert_ne!(vcomp::<Greeting>("Jane"), vcomp::<Greeting>("John"));
+        assert_ne!(vcomp::<Greeting>("Jane"), vcomp::<Farewell>("Jane"));
+
+        let comp = vcomp::<Greeting>("Josh");
+        assert_eq!(comp, comp.clone());
+        assert_eq!(Html::from(comp.clone()), Html::from(comp));
+    }
+
+    #[test]
+    fn eq_considers_keys() {
+        assert_eq!(
+            keyed_vcomp::<Greeting>("Jane", Some("1")),
+            keyed_vcomp::<Greeting>("Jane", Some("1"))
+        );
+        assert_ne!(
+            keyed_vcomp::<Greeting>("Jane", Some("1")),
+            keyed_vcomp::<Greeting>("Jane", Some("2"))
+        );
+        assert_ne!(
+            keyed_vcomp::<Greeting>("Jane", Some("1")),
+            vcomp::<Greeting>("Jane")
+        );
+
+        // reordering keyed components with equal properties moves their instances.
+        let list = |keys: [&str; 2]| {
+            Html::from_iter(
+                keys.into_iter()
+                    .map(|m| keyed_vcomp::<Greeting>("Jane", Some(m))),
+            )
+        };
+        assert_eq!(list(["1", "2"]), list(["1", "2"]));
+        assert_ne!(list(["1", "2"]), list(["2", "1"]));
+    }
+}
+
 #[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
 mod ssr_tests {
     use tokio::test;
@@ -294,5 +558,209 @@ mod ssr_tests {
             s,
             "<div><div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div></div>"
         );
+
+        let s = ServerRenderer::<Comp>::new().render_text_content().await;
+
+        assert_eq!(s, "Hello, Jane!Hello, John!Hello, Josh!");
+
+        // Components are not rendered by the shallow text content.
+        let html = html! { <div>{"Hello, "}<Child name="Jane" />{"!"}</div> };
+        assert_eq!(html.text_content(), "Hello, !");
+    }
+
+    #[test]
+    async fn test_keyed_markers() {
+        use crate::virtual_dom::{Key, VComp};
+
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            name: String,
+        }
+
+        #[function_component]
+        fn Child(props: &ChildProps) -> Html {
+            html! { <div>{&props.name}</div> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <>
+                    <Child key="jane" name="Jane" />
+                    <Child key="<!--#-->" name="John" />
+                    <Child name="Josh" />
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new().render().await;
+
+        assert!(s.contains("::Child#jane]>--><div>Jane</div>"));
+        assert!(s.contains("::Child#%3C!%2D%2D%23%2D%2D%3E]>--><div>John</div>"));
+        assert!(s.contains("::Child]>--><div>Josh</div>"));
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Jane</div><div>John</div><div>Josh</div>");
+
+        let vcomp = VComp::new::<Child>(
+            ChildProps {
+                name: "Jane".to_string(),
+            }
+            .into(),
+            None,
+        );
+        assert_eq!(vcomp.key(), None);
+        assert_eq!(vcomp.with_key("jane").key(), Some(&Key::from("jane")));
+    }
+
+    #[test]
+    async fn test_ssr_fallback() {
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChartProps {
+            points: u32,
+        }
+
+        #[function_component]
+        fn Chart(props: &ChartProps) -> Html {
+            html! { <canvas data-points={props.points.to_string()}></canvas> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <Chart points={3} ssr_fallback={html! { <p>{"Loading..."}</p> }} />
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div><p>Loading...</p></div>");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    async fn test_component_spans() {
+        use std::fmt;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        use tokio::task::LocalSet;
+        use tracing::field::Field;
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        use crate::LocalServerRenderer;
+
+        /// Records the parent of each span, and the name of the component for component spans.
+        #[derive(Default)]
+        struct SpanRecorder {
+            next_id: AtomicU64,
+            stack: Mutex<Vec<u64>>,
+            spans: Mutex<Vec<(u64, Option<u64>, String)>>,
+        }
+
+        impl SpanRecorder {
+            fn has_ancestor(&self, id: u64, ancestor: u64) -> bool {
+                let spans = self.spans.lock().unwrap();
+                let parent_of = |id| spans.iter().find(|m| m.0 == id).and_then(|m| m.1);
+
+                std::iter::successors(parent_of(id), |m| parent_of(*m)).any(|m| m == ancestor)
+            }
+        }
+
+        impl Subscriber for SpanRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+
+                let mut name = span.metadata().name().to_owned();
+                span.record(&mut |field: &Field, value: &dyn fmt::Debug| {
+                    if field.name() == "component.name" {
+                        name = format!("{:?}", value);
+                    }
+                });
+
+                let parent = self.stack.lock().unwrap().last().copied();
+                self.spans.lock().unwrap().push((id, parent, name));
+
+                Id::from_u64(id)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, _event: &Event<'_>) {}
+
+            fn enter(&self, span: &Id) {
+                self.stack.lock().unwrap().push(span.into_u64());
+            }
+
+            fn exit(&self, _span: &Id) {
+                self.stack.lock().unwrap().pop();
+            }
+        }
+
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            name: String,
+        }
+
+        #[function_component]
+        fn Child(props: &ChildProps) -> Html {
+            html! { <div>{"Hello, "}{&props.name}{"!"}</div> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <Child name="Jane" />
+                    <Child name="John" />
+                </div>
+            }
+        }
+
+        let recorder = Arc::new(SpanRecorder::default());
+        let _guard = tracing::dispatcher::set_default(&recorder.clone().into());
+
+        LocalSet::new()
+            .run_until(async move {
+                LocalServerRenderer::<Comp>::new()
+                    .hydratable(false)
+                    .render()
+                    .await
+            })
+            .await;
+
+        let find_spans = |name: &str| -> Vec<u64> {
+            let spans = recorder.spans.lock().unwrap();
+            spans
+                .iter()
+                .filter(|m| m.2.contains(name))
+                .map(|m| m.0)
+                .collect()
+        };
+
+        let comp_spans = find_spans("Comp");
+        let child_spans = find_spans("Child");
+        assert_eq!(comp_spans.len(), 1);
+        assert_eq!(child_spans.len(), 2);
+
+        for child_span in child_spans {
+            assert!(recorder.has_ancestor(child_span, comp_spans[0]));
+        }
     }
 }
@@ -1,7 +1,7 @@
 //! This module contains fragments implementation.
 use std::ops::{Deref, DerefMut};
 
-use super::{Key, VNode};
+use super::{Key, VNode, VText};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum FullyKeyedState {
@@ -14,7 +14,7 @@ enum FullyKeyedState {
 #[derive(Clone, Debug)]
 pub struct VList {
     /// The list of child [VNode]s
-    pub(crate) children: Vec<VNode>,
+    pub(crate) children: VListChildren,
 
     /// All [VNode]s in the VList have keys
     fully_keyed: FullyKeyedState,
@@ -38,14 +38,75 @@ impl Deref for VList {
     type Target = Vec<VNode>;
 
     fn deref(&self) -> &Self::Target {
-        &self.children
+        &self.children.0
     }
 }
 
 impl DerefMut for VList {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.fully_keyed = FullyKeyedState::Unknown;
-        &mut self.children
+        &mut self.children.0
+    }
+}
+
+/// The children of a [VList].
+///
+/// The children of nested elements and lists are dropped iteratively, so dropping deep trees does
+/// not overflow the stack.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct VListChildren(Vec<VNode>);
+
+impl VListChildren {
+    /// Takes the children, leaving an empty list behind.
+    pub(crate) fn take(&mut self) -> Vec<VNode> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Deref for VListChildren {
+    type Target = Vec<VNode>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for VListChildren {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Drop for VListChildren {
+    fn drop(&mut self) {
+        // The children of nested elements and lists are moved into a single list before they
+        // are dropped.
+        let mut nodes = self.take();
+
+        while let Some(node) = nodes.pop() {
+            match node {
+                VNode::VTag(mut m) => {
+                    if let Some(children) = m.children_mut() {
+                        nodes.append(&mut children.children.0);
+                    }
+                }
+                VNode::VList(mut m) => nodes.append(&mut m.children.0),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Extend<VNode> for VList {
+    fn extend<T: IntoIterator<Item = VNode>>(&mut self, iter: T) {
+        self.add_children(iter);
+    }
+}
+
+/// The nodes become the children of the list, their keys are preserved.
+impl From<Vec<VNode>> for VList {
+    fn from(children: Vec<VNode>) -> Self {
+        Self::with_children(children, None)
     }
 }
 
@@ -53,7 +114,16 @@ impl VList {
     /// Creates a new empty [VList] instance.
     pub const fn new() -> Self {
         Self {
-            children: Vec::new(),
+            children: VListChildren(Vec::new()),
+            key: None,
+            fully_keyed: FullyKeyedState::KnownFullyKeyed,
+        }
+    }
+
+    /// Creates a new empty [VList] instance with space for at least `capacity` children.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            children: VListChildren(Vec::with_capacity(capacity)),
             key: None,
             fully_keyed: FullyKeyedState::KnownFullyKeyed,
         }
@@ -63,7 +133,7 @@ impl VList {
     pub fn with_children(children: Vec<VNode>, key: Option<Key>) -> Self {
         let mut vlist = VList {
             fully_keyed: FullyKeyedState::Unknown,
-            children,
+            children: VListChildren(children),
             key,
         };
         vlist.fully_keyed = if vlist.fully_keyed() {
@@ -74,6 +144,43 @@ impl VList {
         vlist
     }
 
+    /// Concatenates adjacent text nodes into a single text node.
+    ///
+    /// The `html!` macro uses this for children in which text blocks are placed next to each
+    /// other, e.g.: `{"current count: "}{count}`. Only [`VNode::VText`] siblings within the same
+    /// list are concatenated.
+    #[doc(hidden)]
+    pub fn __concat_adjacent_text(children: Vec<VNode>) -> Vec<VNode> {
+        let has_adjacent_text = children
+            .windows(2)
+            .any(|m| matches!(m, [VNode::VText(_), VNode::VText(_)]));
+
+        if !has_adjacent_text {
+            return children;
+        }
+
+        let mut merged = Vec::with_capacity(children.len());
+        let mut texts = Vec::new();
+
+        for child in children {
+            match child {
+                VNode::VText(m) => texts.push(m),
+                child => {
+                    if !texts.is_empty() {
+                        merged.push(VNode::VText(VText::concat(texts.drain(..))));
+                    }
+                    merged.push(child);
+                }
+            }
+        }
+
+        if !texts.is_empty() {
+            merged.push(VNode::VText(VText::concat(texts)));
+        }
+
+        merged
+    }
+
     /// Add [VNode] child.
     pub fn add_child(&mut self, child: VNode) {
         if self.fully_keyed == FullyKeyedState::KnownFullyKeyed && !child.has_key() {
@@ -152,96 +259,72 @@ mod test {
             "key state should be unknown, since it was potentially modified through children"
         );
     }
-}
 
-#[cfg(feature = "ssr")]
-mod feat_ssr {
-    use std::fmt::Write;
-    use std::task::Poll;
+    #[test]
+    fn extend_with_capacity() {
+        let mut vlist = VList::with_capacity(3);
+        assert!(vlist.capacity() >= 3);
 
-    use futures::stream::StreamExt;
-    use futures::{join, pin_mut, poll, FutureExt};
+        vlist.extend((0..3u32).map(|i| {
+            let mut tag = VTag::new("a");
+            tag.key = Some(i.into());
+            VNode::VTag(tag.into())
+        }));
+        assert_eq!(vlist.len(), 3, "should contain 3 children");
+        assert_eq!(
+            vlist.fully_keyed,
+            FullyKeyedState::KnownFullyKeyed,
+            "should still be fully keyed"
+        );
 
-    use super::*;
-    use crate::html::AnyScope;
-    use crate::platform::fmt::{self, BufWriter};
-
-    impl VList {
-        pub(crate) async fn render_into_stream(
-            &self,
-            w: &mut BufWriter,
-            parent_scope: &AnyScope,
-            hydratable: bool,
-        ) {
-            match &self.children[..] {
-                [] => {}
-                [child] => {
-                    child.render_into_stream(w, parent_scope, hydratable).await;
-                }
-                _ => {
-                    async fn render_child_iter<'a, I>(
-                        mut children: I,
-                        w: &mut BufWriter,
-                        parent_scope: &AnyScope,
-                        hydratable: bool,
-                    ) where
-                        I: Iterator<Item = &'a VNode>,
-                    {
-                        let mut w = w;
-                        while let Some(m) = children.next() {
-                            let child_fur = async move {
-                                // Rust's Compiler does not release the mutable reference to
-                                // BufWriter until the end of the loop, regardless of whether an
-                                // await statement has dropped the child_fur.
-                                //
-                                // We capture and return the mutable reference to avoid this.
-
-                                m.render_into_stream(w, parent_scope, hydratable).await;
-                                w
-                            };
-                            pin_mut!(child_fur);
-
-                            match poll!(child_fur.as_mut()) {
-                                Poll::Pending => {
-                                    let (mut next_w, next_r) = fmt::buffer();
-                                    // Move buf writer into an async block for it to be dropped at
-                                    // the end of the future.
-                                    let rest_render_fur = async move {
-                                        render_child_iter(
-                                            children,
-                                            &mut next_w,
-                                            parent_scope,
-                                            hydratable,
-                                        )
-                                        .await;
-                                    }
-                                    // boxing to avoid recursion
-                                    .boxed_local();
-
-                                    let transfer_fur = async move {
-                                        let w = child_fur.await;
-
-                                        pin_mut!(next_r);
-                                        while let Some(m) = next_r.next().await {
-                                            let _ = w.write_str(m.as_str());
-                                        }
-                                    };
-
-                                    join!(rest_render_fur, transfer_fur);
-                                    break;
-                                }
-                                Poll::Ready(w_) => {
-                                    w = w_;
-                                }
-                            }
-                        }
-                    }
+        vlist.extend([VNode::VText(VText::new("lorem ipsum"))]);
+        assert_eq!(vlist.len(), 4, "should contain 4 children");
+        assert_eq!(
+            vlist.fully_keyed,
+            FullyKeyedState::KnownMissingKeys,
+            "should not be fully keyed, text tags have no key"
+        );
+    }
 
-                    let children = self.children.iter();
-                    render_child_iter(children, w, parent_scope, hydratable).await;
-                }
-            }
+    #[test]
+    fn concat_adjacent_text() {
+        let count = 3;
+        let node = crate::html! {
+            <p>{"current count: "} {count}</p>
+        };
+        let vtag = match node {
+            VNode::VTag(m) => m,
+            _ => panic!("expected a VTag"),
+        };
+
+        match &vtag.children()[..] {
+            [VNode::VText(m)] => assert_eq!(&*m.text, "current count: 3"),
+            children => panic!("expected a single text node, got {:?}", children),
         }
+
+        // text nodes that are separated by other nodes are not concatenated.
+        let node = crate::html! {
+            <>{"a"}{"b"}<br />{"c"}</>
+        };
+        let vlist = match node {
+            VNode::VList(m) => m,
+            _ => panic!("expected a VList"),
+        };
+
+        assert_eq!(vlist.len(), 3);
+        assert!(matches!(&vlist[0], VNode::VText(m) if &*m.text == "ab"));
+        assert!(matches!(&vlist[2], VNode::VText(m) if &*m.text == "c"));
+    }
+
+    #[test]
+    fn concat_trusted_text() {
+        let text = VText::concat([VText::new_trusted("1"), VText::new_trusted("2")]);
+        assert_eq!(&*text.text, "12");
+        assert!(text.trusted);
+
+        let text = VText::concat([VText::new_trusted("1"), VText::new("<2>")]);
+        assert_eq!(&*text.text, "1<2>");
+        assert!(!text.trusted);
     }
 }
 
@@ -249,10 +332,58 @@ mod feat_ssr {
 #[cfg(feature = "ssr")]
 #[cfg(test)]
 mod ssr_tests {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use tokio::task::LocalSet;
     use tokio::test;
 
+    use crate::platform::time::sleep;
     use crate::prelude::*;
-    use crate::ServerRenderer;
+    use crate::suspense::Suspension;
+    use crate::{LocalServerRenderer, ServerRenderer};
+
+    thread_local! {
+        static RESOLVED: RefCell<Vec<&'static str>> = RefCell::default();
+    }
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct DelayedProps {
+        name: &'static str,
+        delay_ms: u64,
+    }
+
+    /// A component that is suspended for `delay_ms` and records when it is resolved.
+    #[function_component]
+    fn Delayed(props: &DelayedProps) -> HtmlResult {
+        let delay = Duration::from_millis(props.delay_ms);
+        let s = use_state(|| Suspension::from_future(sleep(delay)));
+
+        if !s.resumed() {
+            return Err((*s).clone().into());
+        }
+
+        RESOLVED.with(|m| m.borrow_mut().push(props.name));
+        Ok(html! { <div>{props.name}</div> })
+    }
+
+    async fn render_delayed<COMP>() -> (String, Vec<&'static str>)
+    where
+        COMP: BaseComponent,
+        COMP::Properties: Default,
+    {
+        RESOLVED.with(|m| m.borrow_mut().clear());
+
+        let s = LocalSet::new()
+            .run_until(
+                LocalServerRenderer::<COMP>::new()
+                    .hydratable(false)
+                    .render(),
+            )
+            .await;
+
+        (s, RESOLVED.with(|m| m.take()))
+    }
 
     #[test]
     async fn test_text_back_to_back() {
@@ -304,4 +435,179 @@ mod ssr_tests {
             "<div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div>"
         );
     }
+
+    #[test]
+    async fn test_option_node() {
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            #[prop_or_default]
+            name: Option<String>,
+        }
+
+        #[function_component]
+        fn Child(props: &ChildProps) -> Html {
+            let node = props.name.as_ref().map(|m| html! { <div>{m}</div> });
+
+            html! { {node} }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            let missing: Option<Html> = None;
+
+            html! {
+                <>
+                    <Child name="Jane" />
+                    <Child />
+                    <span>{missing}</span>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Jane</div><span></span>");
+    }
+
+    #[test]
+    async fn test_result_node() {
+        #[derive(PartialEq, Properties, Debug)]
+        struct ChildProps {
+            name: Result<String, String>,
+        }
+
+        #[function_component]
+        fn Child(props: &ChildProps) -> Html {
+            let node = props.name.clone().map(|m| html! { <div>{m}</div> });
+
+            html! { {node} }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            let failed: Result<Html, String> = Err("failed".to_string());
+
+            html! {
+                <>
+                    <Child name={Ok("Jane".to_string())} />
+                    <Child name={Err("not found".to_string())} />
+                    <span>{failed}</span>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Jane</div>not found<span>failed</span>");
+    }
+
+    #[test]
+    async fn test_suspended_siblings_in_order() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <>
+                    {"static 1"}
+                    <Suspense fallback={html! {"loading..."}}>
+                        <Delayed name="A" delay_ms=40 />
+                    </Suspense>
+                    {"static 2"}
+                    <Suspense fallback={html! {"loading..."}}>
+                        <Delayed name="B" delay_ms=10 />
+                    </Suspense>
+                </>
+            }
+        }
+
+        let (s, resolved) = render_delayed::<Comp>().await;
+
+        assert_eq!(resolved, vec!["B", "A"]);
+        assert_eq!(s, "static 1<div>A</div>static 2<div>B</div>");
+    }
+
+    #[test]
+    async fn test_nested_suspended_children_in_order() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"loading..."}}>
+                    <>
+                        <Delayed name="A" delay_ms=50 />
+                        {"static 1"}
+                        <>
+                            <Delayed name="B" delay_ms=30 />
+                            <Suspense fallback={html! {"loading..."}}>
+                                <Delayed name="C" delay_ms=10 />
+                            </Suspense>
+                        </>
+                    </>
+                    <span>
+                        <Delayed name="D" delay_ms=40 />
+                        {"static 2"}
+                    </span>
+                    <Delayed name="E" delay_ms=20 />
+                </Suspense>
+            }
+        }
+
+        let (s, resolved) = render_delayed::<Comp>().await;
+
+        assert_eq!(resolved, vec!["C", "E", "B", "D", "A"]);
+        assert_eq!(
+            s,
+            concat!(
+                "<div>A</div>static 1<div>B</div><div>C</div>",
+                "<span><div>D</div>static 2</span><div>E</div>",
+            )
+        );
+    }
+
+    #[test]
+    async fn test_spliced_nodes() {
+        #[function_component]
+        fn Comp() -> Html {
+            let items: Vec<Html> = ["a", "b", "c"]
+                .iter()
+                .map(|m| html! { <li key={*m}>{m}</li> })
+                .collect();
+            let tail = [html! { <li>{"d"}</li> }, html! { <li>{"e"}</li> }];
+
+            html! {
+                <ul>
+                    {items}
+                    {tail}
+                </ul>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<ul><li>a</li><li>b</li><li>c</li><li>d</li><li>e</li></ul>"
+        );
+    }
+
+    #[test]
+    async fn test_list_from_vec_keeps_keys() {
+        use crate::virtual_dom::{VList, VNode};
+
+        let items: Vec<Html> = (0..3).map(|m| html! { <li key={m}>{m}</li> }).collect();
+        let list = VList::from(items);
+
+        assert_eq!(list.len(), 3);
+        assert!(list
+            .iter()
+            .all(|m| matches!(m, VNode::VTag(m) if m.key.is_some())));
+        assert!(list.fully_keyed());
+    }
 }
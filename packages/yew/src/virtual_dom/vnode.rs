@@ -86,6 +86,105 @@ impl VNode {
     pub fn from_html_unchecked(html: AttrValue) -> Self {
         VNode::VRaw(VRaw { html })
     }
+
+    /// Returns the text content of this node and its descendants, analogous to the DOM's
+    /// [`textContent`](https://developer.mozilla.org/en-US/docs/Web/API/Node/textContent).
+    ///
+    /// This is a shallow operation, components are not rendered and do not contribute any text.
+    /// The text content of a rendered component tree can be obtained with
+    /// [`ServerRenderer::render_text_content`](crate::ServerRenderer::render_text_content).
+    ///
+    /// - Tags and attributes are skipped, only the text of their children is collected.
+    /// - For a suspended [`VSuspense`], the text of the fallback is collected.
+    /// - Raw HTML strings and portals do not contribute any text.
+    /// - The text content of a [`VNode::VRef`] is read from the DOM. On targets other than
+    ///   `wasm32`, there is no DOM and it does not contribute any text.
+    pub fn text_content(&self) -> String {
+        fn collect_text_content(node: &VNode, w: &mut String) {
+            match node {
+                VNode::VTag(vtag) => {
+                    for child in vtag.children().iter() {
+                        collect_text_content(child, w);
+                    }
+                }
+                VNode::VText(vtext) => w.push_str(&vtext.text),
+                VNode::VList(vlist) => {
+                    for child in vlist.iter() {
+                        collect_text_content(child, w);
+                    }
+                }
+                VNode::VRef(node) => {
+                    #[cfg(target_arch = "wasm32")]
+                    w.push_str(&node.text_content().unwrap_or_default());
+                    #[cfg(not(target_arch = "wasm32"))]
+                    let _ = node;
+                }
+                VNode::VSuspense(vsuspense) => match vsuspense.suspended {
+                    true => collect_text_content(&vsuspense.fallback, w),
+                    false => collect_text_content(&vsuspense.children, w),
+                },
+                VNode::VComp(_) | VNode::VPortal(_) | VNode::VRaw(_) => {}
+            }
+        }
+
+        let mut s = String::new();
+        collect_text_content(self, &mut s);
+        s
+    }
+
+    /// Returns the depth of the tree of this node, i.e.: the number of nodes on the longest path
+    /// from this node to any of its descendants, including this node.
+    ///
+    /// This is a shallow operation, components are not rendered and count as a single node. The
+    /// children and the fallback of a [`VSuspense`] are both included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use yew::html;
+    ///
+    /// let node = html! { <div><p>{"Hello"}</p></div> };
+    /// assert_eq!(node.max_depth(), 3);
+    /// ```
+    pub fn max_depth(&self) -> usize {
+        let mut max_depth = 0;
+        self.walk(|depth| max_depth = max_depth.max(depth));
+        max_depth
+    }
+
+    /// Returns the number of nodes in the tree of this node, including this node.
+    ///
+    /// Like [`VNode::max_depth`], this is a shallow operation.
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        self.walk(|_| count += 1);
+        count
+    }
+
+    /// Calls `f` with the depth of each node in the tree of this node.
+    ///
+    /// The tree is walked with an explicit stack, so deep trees do not overflow the stack.
+    fn walk(&self, mut f: impl FnMut(usize)) {
+        let mut stack = vec![(self, 1)];
+
+        while let Some((node, depth)) = stack.pop() {
+            f(depth);
+
+            match node {
+                VNode::VTag(vtag) => stack.extend(vtag.children().iter().map(|m| (m, depth + 1))),
+                VNode::VList(vlist) => stack.extend(vlist.iter().map(|m| (m, depth + 1))),
+                VNode::VSuspense(vsuspense) => {
+                    stack.push((&vsuspense.children, depth + 1));
+                    stack.push((&vsuspense.fallback, depth + 1));
+                }
+                VNode::VText(_)
+                | VNode::VComp(_)
+                | VNode::VRef(_)
+                | VNode::VPortal(_)
+                | VNode::VRaw(_) => {}
+            }
+        }
+    }
 }
 
 impl Default for VNode {
@@ -182,8 +281,7 @@ impl PartialEq for VNode {
             (VNode::VText(a), VNode::VText(b)) => a == b,
             (VNode::VList(a), VNode::VList(b)) => a == b,
             (VNode::VRef(a), VNode::VRef(b)) => a == b,
-            // TODO: Need to improve PartialEq for VComp before enabling.
-            (VNode::VComp(_), VNode::VComp(_)) => false,
+            (VNode::VComp(a), VNode::VComp(b)) => a == b,
             (VNode::VRaw(a), VNode::VRaw(b)) => a.html == b.html,
             _ => false,
         }
@@ -192,36 +290,138 @@ impl PartialEq for VNode {
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::fmt::Write;
+    use std::rc::Rc;
+    use std::task::Poll;
+
     use futures::future::{FutureExt, LocalBoxFuture};
+    use futures::stream::StreamExt;
+    use futures::{join, pin_mut, poll};
 
     use super::*;
     use crate::html::AnyScope;
-    use crate::platform::fmt::BufWriter;
+    use crate::platform::fmt::{self, BufWriter};
+    use crate::virtual_dom::SsrContext;
+
+    /// A step of rendering nodes with [`render_nodes_into_stream`].
+    enum Work<'a> {
+        /// Renders a node in a context.
+        Node(&'a VNode, Rc<SsrContext>),
+        /// Writes the closing tag of an element, after its children have been rendered.
+        Close(&'a VTag, Rc<SsrContext>),
+    }
+
+    /// Renders `stack` into `w`, starting with its last item.
+    ///
+    /// Elements and lists are walked with the explicit stack, so deep trees do not overflow the
+    /// stack. Only components, suspenses and references are awaited, as boxed futures. If one of
+    /// them is suspended, the rest of the stack is rendered into a buffer in the meantime, which is
+    /// written once the suspended node is rendered.
+    async fn render_nodes_into_stream<'a>(
+        mut stack: Vec<Work<'a>>,
+        w: &mut BufWriter,
+        parent_scope: &'a AnyScope,
+    ) {
+        let mut w = w;
+        while let Some(work) = stack.pop() {
+            let (node, ctx) = match work {
+                Work::Node(VNode::VTag(vtag), ctx) => {
+                    if let Some(children_ctx) = vtag.write_open_tag(w, parent_scope, &ctx) {
+                        let children_ctx = Rc::new(children_ctx);
+
+                        stack.push(Work::Close(vtag, children_ctx.clone()));
+                        stack.extend(
+                            vtag.children()
+                                .iter()
+                                .rev()
+                                .map(|m| Work::Node(m, children_ctx.clone())),
+                        );
+                    }
+                    continue;
+                }
+                Work::Node(VNode::VList(vlist), ctx) => {
+                    stack.extend(vlist.iter().rev().map(|m| Work::Node(m, ctx.clone())));
+                    continue;
+                }
+                Work::Node(VNode::VText(vtext), ctx) => {
+                    vtext.write_into(w, &ctx);
+                    continue;
+                }
+                Work::Node(VNode::VRaw(vraw), _) => {
+                    let _ = w.write_str(&vraw.html);
+                    continue;
+                }
+                // Portals are not rendered.
+                Work::Node(VNode::VPortal(_), _) => continue,
+                Work::Close(vtag, children_ctx) => {
+                    vtag.write_close_tag(w, &children_ctx);
+                    continue;
+                }
+                Work::Node(node, ctx) => (node, ctx),
+            };
+
+            // Whether the parent element has block-level children is only known once its
+            // children are rendered, so pretty-printed nodes are rendered one after another.
+            let pretty = ctx.indent.is_some();
+            let node_fur = async move {
+                // Rust's Compiler does not release the mutable reference to BufWriter until the
+                // end of the loop, regardless of whether an await statement has dropped the
+                // node_fur.
+                //
+                // We capture and return the mutable reference to avoid this.
+                node.render_into_stream(w, parent_scope, &ctx).await;
+                w
+            };
+            pin_mut!(node_fur);
+
+            match poll!(node_fur.as_mut()) {
+                Poll::Ready(w_) => w = w_,
+                Poll::Pending if pretty => w = node_fur.await,
+                Poll::Pending => {
+                    let (mut next_w, next_r) = fmt::buffer();
+                    // Move buf writer into an async block for it to be dropped at the end of the
+                    // future.
+                    let rest_render_fur = async move {
+                        render_nodes_into_stream(stack, &mut next_w, parent_scope).await;
+                    }
+                    // boxing to avoid recursion
+                    .boxed_local();
+
+                    let transfer_fur = async move {
+                        let w = node_fur.await;
+
+                        pin_mut!(next_r);
+                        while let Some(m) = next_r.next().await {
+                            let _ = w.write_str(m.as_str());
+                        }
+                    };
+
+                    join!(rest_render_fur, transfer_fur);
+                    break;
+                }
+            }
+        }
+    }
 
     impl VNode {
         pub(crate) fn render_into_stream<'a>(
             &'a self,
             w: &'a mut BufWriter,
             parent_scope: &'a AnyScope,
-            hydratable: bool,
+            ctx: &'a SsrContext,
         ) -> LocalBoxFuture<'a, ()> {
             async fn render_into_stream_(
                 this: &VNode,
                 w: &mut BufWriter,
                 parent_scope: &AnyScope,
-                hydratable: bool,
+                ctx: &SsrContext,
             ) {
                 match this {
-                    VNode::VTag(vtag) => vtag.render_into_stream(w, parent_scope, hydratable).await,
-                    VNode::VText(vtext) => {
-                        vtext.render_into_stream(w, parent_scope, hydratable).await
-                    }
-                    VNode::VComp(vcomp) => {
-                        vcomp.render_into_stream(w, parent_scope, hydratable).await
-                    }
-                    VNode::VList(vlist) => {
-                        vlist.render_into_stream(w, parent_scope, hydratable).await
+                    VNode::VTag(_) | VNode::VList(_) | VNode::VText(_) | VNode::VRaw(_) => {
+                        let stack = vec![Work::Node(this, Rc::new(ctx.clone()))];
+                        render_nodes_into_stream(stack, w, parent_scope).await
                     }
+                    VNode::VComp(vcomp) => vcomp.render_into_stream(w, parent_scope, ctx).await,
                     // We are pretty safe here as it's not possible to get a web_sys::Node without
                     // DOM support in the first place.
                     //
@@ -232,18 +432,69 @@ mod feat_ssr {
                     }
                     // Portals are not rendered.
                     VNode::VPortal(_) => {}
+                    VNode::VSuspense(vsuspense) => {
+                        vsuspense.render_into_stream(w, parent_scope, ctx).await
+                    }
+                }
+            }
+
+            async move { render_into_stream_(self, w, parent_scope, ctx).await }.boxed_local()
+        }
+
+        /// Collects the text content of this node, rendering components.
+        ///
+        /// Unlike [`VNode::text_content`], the content of a [`VSuspense`] is always collected.
+        pub(crate) fn collect_text_content<'a>(
+            &'a self,
+            w: &'a mut String,
+            parent_scope: &'a AnyScope,
+        ) -> LocalBoxFuture<'a, ()> {
+            async fn collect_text_content_(this: &VNode, w: &mut String, parent_scope: &AnyScope) {
+                match this {
+                    VNode::VTag(vtag) => {
+                        for child in vtag.children().iter() {
+                            child.collect_text_content(w, parent_scope).await;
+                        }
+                    }
+                    VNode::VText(vtext) => w.push_str(&vtext.text),
+                    VNode::VComp(vcomp) => vcomp.collect_text_content(w, parent_scope).await,
+                    VNode::VList(vlist) => {
+                        for child in vlist.iter() {
+                            child.collect_text_content(w, parent_scope).await;
+                        }
+                    }
+                    VNode::VRef(_) => {
+                        panic!("VRef is not possible to be rendered in to a string.")
+                    }
+                    // Suspense always renders its children on the server side.
                     VNode::VSuspense(vsuspense) => {
                         vsuspense
-                            .render_into_stream(w, parent_scope, hydratable)
+                            .children
+                            .collect_text_content(w, parent_scope)
                             .await
                     }
-
-                    VNode::VRaw(vraw) => vraw.render_into_stream(w, parent_scope, hydratable).await,
+                    VNode::VPortal(_) | VNode::VRaw(_) => {}
                 }
             }
 
-            async move { render_into_stream_(self, w, parent_scope, hydratable).await }
-                .boxed_local()
+            async move { collect_text_content_(self, w, parent_scope).await }.boxed_local()
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen::{JsCast, JsValue};
+
+    use super::*;
+    use crate::html;
+
+    #[test]
+    fn text_content_skips_refs() {
+        let node: Node = JsValue::NULL.unchecked_into();
+        let html = html! { <div>{"a"}{VNode::VRef(node)}{"b"}</div> };
+
+        assert_eq!(html.text_content(), "ab");
+    }
+}
@@ -11,23 +11,3 @@ impl From<AttrValue> for VRaw {
         Self { html }
     }
 }
-
-#[cfg(feature = "ssr")]
-mod feat_ssr {
-    use std::fmt::Write;
-
-    use super::*;
-    use crate::html::AnyScope;
-    use crate::platform::fmt::BufWriter;
-
-    impl VRaw {
-        pub(crate) async fn render_into_stream(
-            &self,
-            w: &mut BufWriter,
-            _parent_scope: &AnyScope,
-            _hydratable: bool,
-        ) {
-            let _ = w.write_str(self.html.as_ref());
-        }
-    }
-}
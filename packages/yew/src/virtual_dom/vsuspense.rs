@@ -29,29 +29,57 @@ mod feat_ssr {
     use super::*;
     use crate::html::AnyScope;
     use crate::platform::fmt::BufWriter;
-    use crate::virtual_dom::Collectable;
+    use crate::virtual_dom::{Collectable, SsrContext, SuspenseMark};
 
     impl VSuspense {
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(level = tracing::Level::DEBUG, name = "render_suspense", skip_all)
+        )]
         pub(crate) async fn render_into_stream(
             &self,
             w: &mut BufWriter,
             parent_scope: &AnyScope,
-            hydratable: bool,
+            ctx: &SsrContext,
         ) {
-            let collectable = Collectable::Suspense;
+            let suspense_depth = ctx.suspense_depth + 1;
+            assert!(
+                suspense_depth <= ctx.max_suspense_depth,
+                "suspenses are nested deeper than the limit of {}, a component may render the \
+                 suspense it is rendered in. The limit can be raised with \
+                 `ServerRenderer::max_suspense_depth`.",
+                ctx.max_suspense_depth
+            );
+            let ctx = &SsrContext {
+                suspense_depth,
+                ..ctx.clone()
+            };
+
+            // the children of a deferred suspense are rendered on the client side, see
+            // ServerRenderer::defer_suspense. Otherwise, children are always rendered on the server
+            // side.
+            let content = if ctx.defer_suspense {
+                &self.fallback
+            } else {
+                &self.children
+            };
 
-            if hydratable {
-                collectable.write_open_tag(w);
+            if !ctx.hydratable {
+                content.render_into_stream(w, parent_scope, ctx).await;
+                return;
             }
 
-            // always render children on the server side.
-            self.children
-                .render_into_stream(w, parent_scope, hydratable)
-                .await;
+            let collectable = Collectable::Suspense;
+            let mark = if ctx.defer_suspense {
+                SuspenseMark::Deferred
+            } else {
+                SuspenseMark::Resolved
+            };
+            collectable.write_suspense_open_tag(w, mark);
 
-            if hydratable {
-                collectable.write_close_tag(w);
-            }
+            content.render_into_stream(w, parent_scope, ctx).await;
+
+            collectable.write_close_tag(w);
         }
     }
 }
@@ -153,4 +181,105 @@ mod ssr_tests {
             "<div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div>"
         );
     }
+
+    #[test]
+    async fn test_defer_suspense() {
+        #[function_component]
+        fn Child() -> HtmlResult {
+            let s = use_state(|| Suspension::from_future(sleep(Duration::from_secs(60))));
+
+            if s.resumed() {
+                Ok(html! { <div>{"Hello!"}</div> })
+            } else {
+                Err((*s).clone().into())
+            }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <div>
+                    <Suspense {fallback}>
+                        <Child />
+                    </Suspense>
+                </div>
+            }
+        }
+
+        let render = |hydratable| {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                ServerRenderer::<Comp>::new()
+                    .hydratable(hydratable)
+                    .defer_suspense(true)
+                    .render(),
+            )
+        };
+
+        let s = render(false)
+            .await
+            .expect("deferred suspense delayed the render");
+        assert_eq!(s, "<div>loading...</div>");
+
+        let s = render(true)
+            .await
+            .expect("deferred suspense delayed the render");
+        assert!(s.contains("<!--<?defer>-->"));
+        assert!(s.contains("loading..."));
+        assert!(!s.contains("<!--<?>-->"));
+    }
+
+    #[test]
+    #[should_panic(expected = "suspenses are nested deeper than the limit of 8")]
+    async fn test_max_suspense_depth() {
+        use crate::LocalServerRenderer;
+
+        #[function_component]
+        fn Nested() -> Html {
+            html! {
+                <Suspense>
+                    <Nested />
+                </Suspense>
+            }
+        }
+
+        let local = LocalSet::new();
+        local
+            .run_until(
+                LocalServerRenderer::<Nested>::new()
+                    .max_suspense_depth(8)
+                    .render(),
+            )
+            .await;
+    }
+
+    #[test]
+    async fn test_max_suspense_depth_is_inclusive() {
+        #[derive(PartialEq, Properties)]
+        struct NestedProps {
+            depth: usize,
+        }
+
+        #[function_component]
+        fn Nested(props: &NestedProps) -> Html {
+            match props.depth {
+                0 => html! { {"done"} },
+                depth => html! {
+                    <Suspense>
+                        <Nested depth={depth - 1} />
+                    </Suspense>
+                },
+            }
+        }
+
+        let s = ServerRenderer::<Nested>::with_props(|| NestedProps { depth: 8 })
+            .hydratable(false)
+            .max_suspense_depth(8)
+            .render()
+            .await;
+
+        assert_eq!(s, "done");
+    }
 }
@@ -9,12 +9,17 @@ use std::rc::Rc;
 
 use web_sys::{HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement};
 
-use super::{ApplyAttributeAs, AttrValue, Attributes, Key, Listener, Listeners, VList, VNode};
+use super::{
+    ApplyAttributeAs, AttrValue, Attributes, DataAttributes, Key, Listener, Listeners, VList, VNode,
+};
 use crate::html::{IntoPropValue, NodeRef};
 
 /// SVG namespace string used for creating svg elements
 pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
 
+/// MathML namespace string used for creating MathML elements
+pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
 /// Default namespace for html elements
 pub const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
 
@@ -127,6 +132,12 @@ pub struct VTag {
     pub node_ref: NodeRef,
     /// List of attributes.
     pub attributes: Attributes,
+    /// The key of this tag.
+    ///
+    /// An existing element is only reused if it has the same tag name and the same key, otherwise
+    /// it is recreated. In a list where every child has a key, children are matched by their key
+    /// instead of their position, so an element keeps its DOM node, including its focus and
+    /// state, when siblings are inserted, removed or reordered around it.
     pub key: Option<Key>,
 }
 
@@ -240,6 +251,17 @@ impl VTag {
         )
     }
 
+    /// Converts the name of a dynamic tag, i.e.: `<@{name}>`, into the tag of a [VTag].
+    ///
+    /// Static names are borrowed, shared names are copied into the tag.
+    #[doc(hidden)]
+    pub fn __dyn_tag_name(name: AttrValue) -> Cow<'static, str> {
+        match name {
+            AttrValue::Static(m) => Cow::Borrowed(m),
+            AttrValue::Rc(m) => Cow::Owned(m.to_string()),
+        }
+    }
+
     /// Constructs a [VTag] from [VTagInner] and fields common to all [VTag] kinds
     #[inline]
     #[allow(clippy::too_many_arguments)]
@@ -378,6 +400,16 @@ impl VTag {
         );
     }
 
+    /// Adds `data-*` attributes, see [`DataAttributes`] for more information.
+    ///
+    /// The attributes are added after the attributes that are already set.
+    pub fn add_data_attributes(&mut self, data: DataAttributes) {
+        let attributes = self.attributes.get_mut_index_map();
+        for (key, value) in data {
+            attributes.insert(key, (value, ApplyAttributeAs::Attribute));
+        }
+    }
+
     /// Set the given key as property on the element
     ///
     /// [`js_sys::Reflect`] is used for setting properties.
@@ -449,92 +481,370 @@ impl PartialEq for VTag {
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
     use std::fmt::Write;
 
     use super::*;
     use crate::html::AnyScope;
     use crate::platform::fmt::BufWriter;
-    use crate::virtual_dom::VText;
+    use crate::virtual_dom::{SsrContext, VText};
 
     // Elements that cannot have any child elements.
-    static VOID_ELEMENTS: &[&str; 14] = &[
+    pub(crate) static VOID_ELEMENTS: &[&str; 14] = &[
         "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param",
         "source", "track", "wbr",
     ];
 
+    // Attributes that are only present or absent, they are given their name as value in XHTML.
+    //
+    // From: https://html.spec.whatwg.org/#attributes-3, where `Value` = Boolean attribute
+    static BOOLEAN_ATTRIBUTES: &[&str; 25] = &[
+        "allowfullscreen",
+        "async",
+        "autofocus",
+        "autoplay",
+        "checked",
+        "controls",
+        "default",
+        "defer",
+        "disabled",
+        "formnovalidate",
+        "hidden",
+        "ismap",
+        "itemscope",
+        "loop",
+        "multiple",
+        "muted",
+        "nomodule",
+        "novalidate",
+        "open",
+        "playsinline",
+        "readonly",
+        "required",
+        "reversed",
+        "selected",
+        "truespeed",
+    ];
+
+    // Elements that are put on their own line when pretty-printing.
+    //
+    // `<pre>` is left out, as it is whitespace-sensitive and is written as is.
+    static BLOCK_ELEMENTS: &[&str; 43] = &[
+        "address",
+        "article",
+        "aside",
+        "blockquote",
+        "body",
+        "dd",
+        "details",
+        "dialog",
+        "div",
+        "dl",
+        "dt",
+        "fieldset",
+        "figcaption",
+        "figure",
+        "footer",
+        "form",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "head",
+        "header",
+        "hgroup",
+        "hr",
+        "html",
+        "li",
+        "main",
+        "nav",
+        "ol",
+        "p",
+        "section",
+        "summary",
+        "table",
+        "tbody",
+        "td",
+        "tfoot",
+        "th",
+        "thead",
+        "title",
+        "tr",
+        "ul",
+    ];
+
+    // Elements whose text is written verbatim.
+    pub(crate) static RAW_TEXT_ELEMENTS: &[&str; 2] = &["script", "style"];
+
+    // Elements whose content must not be altered when pretty-printing or collapsing whitespace.
+    static WHITESPACE_SENSITIVE_ELEMENTS: &[&str; 4] = &["pre", "textarea", "script", "style"];
+
+    // Attributes that hint how the resource of an element is fetched, e.g.: `loading="lazy"`.
+    //
+    // They are written before all other attributes, so a browser parsing a streamed response sees
+    // the hints before the `src` that starts the fetch.
+    static FETCH_HINT_ATTRIBUTES: &[&str; 3] = &["loading", "fetchpriority", "decoding"];
+
+    type StaticAttributes = &'static [(&'static str, &'static str, ApplyAttributeAs)];
+
+    /// Returns the attributes in the order they are written: fetch hints first, then the other
+    /// attributes. The order is otherwise preserved.
+    fn ordered_attrs<'a, F, I>(attrs: F) -> impl Iterator<Item = (&'a str, &'a str)>
+    where
+        F: Fn() -> I,
+        I: Iterator<Item = (&'a str, &'a str)>,
+    {
+        let is_hint = |(k, _): &(&str, &str)| FETCH_HINT_ATTRIBUTES.contains(k);
+
+        attrs()
+            .filter(is_hint)
+            .chain(attrs().filter(move |m| !is_hint(m)))
+    }
+
+    thread_local! {
+        // Serialized static attribute lists, keyed by the address and length of the list and
+        // whether they are serialized as XHTML.
+        //
+        // The html! macro creates one static attribute list per element, which is shared by every
+        // node it renders (e.g.: all rows of a list), so each list only has to be escaped once.
+        static SERIALIZED_STATIC_ATTRIBUTES: RefCell<HashMap<(usize, usize, bool), Rc<str>>> =
+            RefCell::default();
+    }
+
+    /// Returns the value an attribute is written with.
+    ///
+    /// XHTML does not allow attributes without a value, so boolean attributes are written with
+    /// their name as value, e.g.: `disabled="disabled"`.
+    fn attr_value<'a>(name: &'a str, val: Option<&'a str>, xhtml: bool) -> Option<&'a str> {
+        if xhtml && (val.is_none() || BOOLEAN_ATTRIBUTES.contains(&name)) {
+            Some(name)
+        } else {
+            val
+        }
+    }
+
+    fn write_attr(w: &mut BufWriter, name: &str, val: Option<&str>, xhtml: bool) {
+        let val = attr_value(name, val, xhtml);
+
+        let _ = w.write_str(" ");
+        let _ = w.write_str(name);
+
+        if let Some(m) = val {
+            let _ = w.write_str("=\"");
+            let _ = w.write_str(&html_escape::encode_double_quoted_attribute(m));
+            let _ = w.write_str("\"");
+        }
+    }
+
+    fn write_static_attrs(w: &mut BufWriter, attrs: StaticAttributes, xhtml: bool) {
+        let cache_key = (attrs.as_ptr() as usize, attrs.len(), xhtml);
+
+        let serialized = SERIALIZED_STATIC_ATTRIBUTES.with(|m| {
+            m.borrow_mut()
+                .entry(cache_key)
+                .or_insert_with(|| {
+                    let mut s = String::new();
+                    for (k, v) in ordered_attrs(|| attrs.iter().map(|(k, v, _)| (*k, *v))) {
+                        let v = attr_value(k, Some(v), xhtml).unwrap_or(v);
+                        let _ = write!(
+                            s,
+                            r#" {}="{}""#,
+                            k,
+                            html_escape::encode_double_quoted_attribute(v)
+                        );
+                    }
+
+                    s.into()
+                })
+                .clone()
+        });
+
+        let _ = w.write_str(&serialized);
+    }
+
+    /// Warns that the event listeners of elements rendered by the component of `parent_scope`
+    /// are not attached without hydration.
+    ///
+    /// The warning is only emitted once per component type on each thread.
+    #[cfg(debug_assertions)]
+    fn warn_listeners_without_hydration(parent_scope: &AnyScope) {
+        use std::any::TypeId;
+        use std::collections::HashSet;
+
+        thread_local! {
+            static WARNED_COMPONENTS: RefCell<HashSet<TypeId>> = RefCell::default();
+        }
+
+        let is_first =
+            WARNED_COMPONENTS.with(|m| m.borrow_mut().insert(*parent_scope.get_type_id()));
+
+        if is_first {
+            tracing::warn!(
+                "{} renders elements with event listeners in a non-hydratable server-side render. \
+                 These listeners are not serialized and only work after the result is hydrated.",
+                parent_scope.get_type_name()
+            );
+        }
+    }
+
     impl VTag {
-        pub(crate) async fn render_into_stream(
+        /// Writes the opening tag, returns the context of the children if the element has
+        /// children and a closing tag.
+        ///
+        /// The value and the closing tag of a `<textarea>` are written as well.
+        pub(crate) fn write_open_tag(
             &self,
             w: &mut BufWriter,
             parent_scope: &AnyScope,
-            hydratable: bool,
-        ) {
-            let _ = w.write_str("<");
-            let _ = w.write_str(self.tag());
+            ctx: &SsrContext,
+        ) -> Option<SsrContext> {
+            #[cfg(debug_assertions)]
+            if !ctx.hydratable {
+                if let Listeners::Pending(ref m) = self.listeners {
+                    if m.iter().any(Option::is_some) {
+                        warn_listeners_without_hydration(parent_scope);
+                    }
+                }
+            }
+            #[cfg(not(debug_assertions))]
+            let _ = parent_scope;
 
-            let write_attr = |w: &mut BufWriter, name: &str, val: Option<&str>| {
-                let _ = w.write_str(" ");
-                let _ = w.write_str(name);
+            let is_block = BLOCK_ELEMENTS.contains(&self.tag());
 
-                if let Some(m) = val {
-                    let _ = w.write_str("=\"");
-                    let _ = w.write_str(&html_escape::encode_double_quoted_attribute(m));
-                    let _ = w.write_str("\"");
+            if is_block {
+                if let Some(ref m) = ctx.has_block_children {
+                    m.set(true);
                 }
-            };
+                ctx.write_indent(w);
+            }
+
+            #[cfg(all(feature = "a11y-lints", debug_assertions))]
+            crate::virtual_dom::a11y::warn_attributes(self.tag(), self.attributes.iter());
+
+            let _ = w.write_str("<");
+            let _ = w.write_str(self.tag());
 
             if let VTagInner::Input(_) = self.inner {
                 if let Some(m) = self.value() {
-                    write_attr(w, "value", Some(m));
+                    write_attr(w, "value", Some(m), ctx.xhtml);
                 }
 
                 // Setting is as an attribute sets the `defaultChecked` property. Only emit this
                 // if it's explicitly set to checked.
                 if self.checked() == Some(true) {
-                    write_attr(w, "checked", None);
+                    write_attr(w, "checked", None, ctx.xhtml);
                 }
             }
 
-            for (k, v) in self.attributes.iter() {
-                write_attr(w, k, Some(v));
+            match (&self.attributes, &ctx.url_rewriter) {
+                // the serialized static attributes are shared by all renderers.
+                (Attributes::Static(m), None) => write_static_attrs(w, m, ctx.xhtml),
+                (_, Some(rewriter)) => {
+                    for (k, v) in ordered_attrs(|| self.attributes.iter()) {
+                        write_attr(w, k, Some(&rewriter.rewrite(k, v)), ctx.xhtml);
+                    }
+                }
+                _ => {
+                    for (k, v) in ordered_attrs(|| self.attributes.iter()) {
+                        write_attr(w, k, Some(v), ctx.xhtml);
+                    }
+                }
             }
 
-            let _ = w.write_str(">");
+            let is_void = match self.inner {
+                VTagInner::Input(_) => true,
+                VTagInner::Textarea { .. } => false,
+                // dynamic tags keep the case they are written in.
+                VTagInner::Other { ref tag, .. } => {
+                    VOID_ELEMENTS.iter().any(|m| m.eq_ignore_ascii_case(tag))
+                }
+            };
+
+            if is_void && ctx.xhtml {
+                let _ = w.write_str("/>");
+            } else {
+                let _ = w.write_str(">");
+            }
 
             match self.inner {
-                VTagInner::Input(_) => {}
+                VTagInner::Input(_) => None,
                 VTagInner::Textarea { .. } => {
                     if let Some(m) = self.value() {
-                        VText::new(m.to_owned())
-                            .render_into_stream(w, parent_scope, hydratable)
-                            .await;
+                        let ctx = SsrContext {
+                            collapse_whitespace: false,
+                            ..ctx.clone()
+                        };
+                        VText::new(m.to_owned()).write_into(w, &ctx);
                     }
 
                     let _ = w.write_str("</textarea>");
+
+                    None
                 }
                 VTagInner::Other {
                     ref tag,
                     ref children,
                     ..
                 } => {
-                    if !VOID_ELEMENTS.contains(&tag.as_ref()) {
-                        children
-                            .render_into_stream(w, parent_scope, hydratable)
-                            .await;
-
-                        let _ = w.write_str("</");
-                        let _ = w.write_str(tag);
-                        let _ = w.write_str(">");
-                    } else {
+                    if is_void {
                         // We don't write children of void elements nor closing tags.
                         debug_assert!(children.is_empty(), "{} cannot have any children!", tag);
+
+                        return None;
                     }
+
+                    let is_whitespace_sensitive =
+                        WHITESPACE_SENSITIVE_ELEMENTS.contains(&tag.as_ref());
+
+                    let indent = match ctx.indent {
+                        _ if is_whitespace_sensitive => None,
+                        Some(depth) if is_block => Some(depth + 1),
+                        indent => indent,
+                    };
+
+                    Some(SsrContext {
+                        indent,
+                        has_block_children: indent.map(|_| Rc::default()),
+                        collapse_whitespace: ctx.collapse_whitespace && !is_whitespace_sensitive,
+                        raw_text_element: RAW_TEXT_ELEMENTS
+                            .iter()
+                            .find(|m| m.eq_ignore_ascii_case(tag))
+                            .copied(),
+                        ..ctx.clone()
+                    })
                 }
             }
         }
+
+        /// Writes the closing tag of an element, after its children have been written with
+        /// `children_ctx`.
+        pub(crate) fn write_close_tag(&self, w: &mut BufWriter, children_ctx: &SsrContext) {
+            let is_block = BLOCK_ELEMENTS.contains(&self.tag());
+
+            // Whitespace at the end of a block is not rendered. Whether a block-level element has
+            // been written is only known after the children, which may be components, are
+            // rendered.
+            let has_block_children = children_ctx
+                .has_block_children
+                .as_ref()
+                .map_or(false, |m| m.get());
+            if is_block && has_block_children {
+                children_ctx.write_close_indent(w);
+            }
+
+            let _ = w.write_str("</");
+            let _ = w.write_str(self.tag());
+            let _ = w.write_str(">");
+        }
     }
 }
 
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::{RAW_TEXT_ELEMENTS, VOID_ELEMENTS};
+
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(feature = "ssr")]
 #[cfg(test)]
@@ -574,6 +884,258 @@ mod ssr_tests {
         assert_eq!(s, r#"<div class="abc"></div>"#);
     }
 
+    #[test]
+    async fn test_optional_attrs() {
+        #[derive(PartialEq, Properties)]
+        struct Props {
+            href: Option<AttrValue>,
+        }
+
+        #[function_component]
+        fn Comp(props: &Props) -> Html {
+            let title: Option<AttrValue> = None;
+
+            html! { <a href={props.href.clone()} {title} target="_blank">{"link"}</a> }
+        }
+
+        let render = |href: Option<&'static str>| {
+            ServerRenderer::<Comp>::with_props(move || Props {
+                href: href.map(AttrValue::from),
+            })
+            .hydratable(false)
+            .render()
+        };
+
+        assert_eq!(render(None).await, r#"<a target="_blank">link</a>"#);
+        assert_eq!(
+            render(Some("/about")).await,
+            r#"<a href="/about" target="_blank">link</a>"#
+        );
+    }
+
+    #[test]
+    async fn test_data_attributes() {
+        use crate::virtual_dom::VTag;
+
+        #[function_component]
+        fn Comp() -> Html {
+            let mut tag = VTag::new("div");
+            tag.add_attribute("class", "user");
+            tag.add_data_attributes(crate::data_attrs!({
+                "userId" => 5,
+                "displayName" => "Alice",
+                "active" => true,
+                "admin" => false,
+                "email" => None::<String>,
+            }));
+
+            tag.into()
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            concat!(
+                r#"<div class="user" data-user-id="5" data-display-name="Alice" "#,
+                r#"data-active="true" data-admin="false"></div>"#
+            )
+        );
+    }
+
+    #[test]
+    async fn test_url_rewriter() {
+        use std::borrow::Cow;
+
+        use crate::UrlRewriter;
+
+        #[function_component]
+        fn Comp() -> Html {
+            let href = "/about";
+
+            html! {
+                <>
+                    <img src="/a.png" alt="/a.png" />
+                    <a href={href}>{"/about"}</a>
+                    <img srcset="/a.png 1x" src="relative.png" />
+                </>
+            }
+        }
+
+        let rewriter = UrlRewriter::new(|_, value| match value.strip_prefix('/') {
+            Some(path) => Cow::Owned(format!("https://cdn/{}", path)),
+            None => Cow::Borrowed(value),
+        });
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .url_rewriter(rewriter.clone())
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            concat!(
+                r#"<img src="https://cdn/a.png" alt="/a.png">"#,
+                r#"<a href="https://cdn/about">/about</a>"#,
+                r#"<img srcset="https://cdn/a.png 1x" src="relative.png">"#,
+            )
+        );
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .url_rewriter(rewriter.attributes(["href"]))
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            concat!(
+                r#"<img src="/a.png" alt="/a.png">"#,
+                r#"<a href="https://cdn/about">/about</a>"#,
+                r#"<img srcset="/a.png 1x" src="relative.png">"#,
+            )
+        );
+    }
+
+    #[test]
+    async fn test_foreign_content() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <p>
+                    <math display="block">
+                        <mfrac>
+                            <mi>{"a"}</mi>
+                            <mn>{"2"}</mn>
+                        </mfrac>
+                    </math>
+                    <svg viewBox="0 0 10 10">
+                        <foreignObject width="10" height="10">
+                            <div>{"text"}</div>
+                        </foreignObject>
+                    </svg>
+                </p>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            concat!(
+                r#"<p><math display="block"><mfrac><mi>a</mi><mn>2</mn></mfrac></math>"#,
+                r#"<svg viewBox="0 0 10 10"><foreignObject width="10" height="10">"#,
+                r#"<div>text</div></foreignObject></svg></p>"#
+            )
+        );
+    }
+
+    #[test]
+    async fn test_deduplicated_classes() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! { <div class={classes!("a", Some("b"), "a", "\"c\"")}></div> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, r#"<div class="a b &quot;c&quot;"></div>"#);
+    }
+
+    #[test]
+    async fn test_static_attrs_in_list() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <tr>
+                    { for (0..3).map(|i| html! { <td class="cell" title="a\"b">{i}</td> }) }
+                </tr>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<tr><td title="a&quot;b" class="cell">0</td><td title="a&quot;b" class="cell">1</td><td title="a&quot;b" class="cell">2</td></tr>"#
+        );
+    }
+
+    #[test]
+    async fn test_attribute_order() {
+        #[function_component]
+        fn Comp() -> Html {
+            use crate::virtual_dom::VTag;
+
+            let title = "title";
+            let mut tag = VTag::new("span");
+            tag.add_attribute("z", "1");
+            tag.add_attribute("a", "2");
+            tag.add_attribute("m", "3");
+            tag.add_attribute("z", "4");
+
+            html! {
+                <div id="d" class={classes!("b", "a")} data-z={title} hidden={false} aria-label="l">
+                    {Html::from(tag)}
+                </div>
+            }
+        }
+
+        let render = || async {
+            ServerRenderer::<Comp>::new()
+                .hydratable(false)
+                .render()
+                .await
+        };
+
+        let s = render().await;
+
+        assert_eq!(
+            s,
+            r#"<div id="d" data-z="title" aria-label="l" class="b a"><span z="4" a="2" m="3"></span></div>"#
+        );
+        assert_eq!(s, render().await);
+    }
+
+    #[test]
+    async fn test_declarative_shadow_root() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <my-card>
+                    <template shadowrootmode="open">
+                        <slot name="title">{"Untitled"}</slot>
+                        <slot></slot>
+                    </template>
+                    <span slot="title">{"Hello"}</span>
+                </my-card>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<my-card><template shadowrootmode="open"><slot name="title">Untitled</slot><slot></slot></template><span slot="title">Hello</span></my-card>"#
+        );
+    }
+
     #[test]
     async fn test_simple_tag_with_content() {
         #[function_component]
@@ -618,4 +1180,477 @@ mod ssr_tests {
 
         assert_eq!(s, r#"<textarea>teststring</textarea>"#);
     }
+
+    #[test]
+    async fn test_pretty() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <p>{"Hello, "}<b>{"world"}</b></p>
+                    <pre>{"  keep\n me"}</pre>
+                    <span>{"inline"}</span>
+                    <ul><li>{"item"}</li></ul>
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .pretty(true)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<div>\n  <p>Hello, <b>world</b></p><pre>  keep\n me</pre><span>inline</span>\n  \
+             <ul>\n    <li>item</li>\n  </ul>\n</div>"
+        );
+
+        // Hydratable results are never pretty-printed.
+        let s = ServerRenderer::<Comp>::new().pretty(true).render().await;
+
+        assert_eq!(s, ServerRenderer::<Comp>::new().render().await);
+    }
+
+    #[test]
+    async fn test_pretty_components() {
+        #[function_component]
+        fn Inline() -> Html {
+            html! { <b>{"b"}</b> }
+        }
+
+        #[function_component]
+        fn Block() -> Html {
+            html! { <p>{"block"}</p> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <p>{"a "}<Inline />{" c"}</p>
+                    <section><Block /></section>
+                    <pre><div>{"raw"}</div></pre>
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .pretty(true)
+            .render()
+            .await;
+
+        // Components are indented by the elements they render.
+        assert_eq!(
+            s,
+            "<div>\n  <p>a <b>b</b> c</p>\n  <section>\n    <p>block</p>\n  \
+             </section><pre><div>raw</div></pre>\n</div>"
+        );
+    }
+
+    #[test]
+    async fn test_dynamic_tag() {
+        use crate::virtual_dom::AttrValue;
+
+        #[derive(PartialEq, Properties)]
+        struct HeadingProps {
+            level: u8,
+        }
+
+        #[function_component]
+        fn Heading(props: &HeadingProps) -> Html {
+            let tag = AttrValue::from(format!("h{}", props.level));
+            html! { <@{tag}>{"Title"}</@> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <>
+                    <Heading level=1 />
+                    <Heading level=3 />
+                    <@{"BR"} />
+                    <@{"input"} checked=true />
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        // void elements are detected regardless of the case of a dynamic tag.
+        assert_eq!(s, "<h1>Title</h1><h3>Title</h3><BR><input checked>");
+    }
+
+    #[test]
+    async fn test_xhtml() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <img src="a.png" />
+                    <br />
+                    <input type="checkbox" checked=true />
+                    <textarea value="text" />
+                    <p></p>
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .xhtml(true)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div><img src="a.png"/><br/><input checked="checked" type="checkbox"/><textarea>text</textarea><p></p></div>"#
+        );
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div><img src="a.png"><br><input checked type="checkbox"><textarea>text</textarea><p></p></div>"#
+        );
+    }
+
+    #[test]
+    async fn test_xhtml_boolean_attributes() {
+        #[function_component]
+        fn Comp() -> Html {
+            let selected = true;
+
+            // Attributes set on the element directly can be empty.
+            let mut option = crate::virtual_dom::VTag::new("option");
+            option.add_attribute("selected", "");
+            option.add_attribute("value", "");
+
+            html! {
+                <form novalidate=true>
+                    <input disabled=true readonly=true />
+                    <select multiple=true>
+                        <option {selected}></option>
+                        {option}
+                    </select>
+                </form>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .xhtml(true)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<form novalidate="novalidate"><input disabled="disabled" readonly="readonly"/><select multiple="multiple"><option selected="selected"></option><option selected="selected" value=""></option></select></form>"#
+        );
+    }
+
+    #[test]
+    async fn test_raw_text_elements() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <>
+                    <style>{"a > b { content: \"&\"; }"}</style>
+                    <script>{"if (a < b && c) { s = '</script>'; }"}</script>
+                    <div>{"a < b && c"}</div>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<style>a > b { content: "&"; }</style><script>if (a < b && c) { s = '<\/script>'; }</script><div>a &lt; b &amp;&amp; c</div>"#
+        );
+    }
+
+    #[test]
+    async fn test_fetch_hints_before_src() {
+        #[function_component]
+        fn Comp() -> Html {
+            let src = "b.png";
+
+            html! {
+                <>
+                    <img src="a.png" alt="a" loading="lazy" decoding="async" />
+                    <iframe src={src} fetchpriority="low" loading="lazy"></iframe>
+                </>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<img loading="lazy" decoding="async" src="a.png" alt="a"><iframe fetchpriority="low" loading="lazy" src="b.png"></iframe>"#
+        );
+    }
+
+    #[test]
+    async fn test_deep_tree() {
+        const DEPTH: usize = 10_000;
+
+        fn deep_tree() -> Html {
+            let mut node = html! { <span>{"deep"}</span> };
+            for _ in 0..DEPTH {
+                node = html! { <div>{node}</div> };
+            }
+
+            node
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            deep_tree()
+        }
+
+        let node = deep_tree();
+        assert_eq!(node.max_depth(), DEPTH + 2);
+        assert_eq!(node.node_count(), DEPTH + 2);
+        drop(node);
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            format!(
+                "{}<span>deep</span>{}",
+                "<div>".repeat(DEPTH),
+                "</div>".repeat(DEPTH)
+            )
+        );
+    }
+
+    #[test]
+    async fn test_deep_tree_with_component() {
+        const DEPTH: usize = 10_000;
+
+        #[function_component]
+        fn Leaf() -> Html {
+            html! { <span>{"leaf"}</span> }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            let mut node = html! { <Leaf /> };
+            for _ in 0..DEPTH {
+                node = html! { <div>{node}</div> };
+            }
+
+            node
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            format!(
+                "{}<span>leaf</span>{}",
+                "<div>".repeat(DEPTH),
+                "</div>".repeat(DEPTH)
+            )
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    mod warning_recorder {
+        use std::sync::Mutex;
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Level, Metadata, Subscriber};
+
+        /// Records the messages of warnings.
+        #[derive(Default)]
+        pub(super) struct WarningRecorder {
+            pub warnings: Mutex<Vec<String>>,
+        }
+
+        impl Subscriber for WarningRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                struct Message(String);
+
+                impl Visit for Message {
+                    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                        if field.name() == "message" {
+                            self.0 = format!("{:?}", value);
+                        }
+                    }
+                }
+
+                if *event.metadata().level() == Level::WARN {
+                    let mut message = Message(String::new());
+                    event.record(&mut message);
+                    self.warnings.lock().unwrap().push(message.0);
+                }
+            }
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    use warning_recorder::WarningRecorder;
+
+    #[cfg(debug_assertions)]
+    #[test]
+    async fn test_listeners_without_hydration_warning() {
+        use std::sync::Arc;
+
+        use tokio::task::LocalSet;
+
+        use crate::LocalServerRenderer;
+
+        #[function_component]
+        fn Button() -> Html {
+            html! {
+                <>
+                    <button onclick={Callback::from(|_| ())}>{"1"}</button>
+                    <button onclick={Callback::from(|_| ())}>{"2"}</button>
+                </>
+            }
+        }
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <Button />
+                    <Button />
+                    <span onclick={None::<Callback<MouseEvent>>}></span>
+                </div>
+            }
+        }
+
+        let recorder = Arc::new(WarningRecorder::default());
+        let _guard = tracing::dispatcher::set_default(&recorder.clone().into());
+
+        let local = LocalSet::new();
+        let render = |hydratable| {
+            local.run_until(async move {
+                LocalServerRenderer::<Comp>::new()
+                    .hydratable(hydratable)
+                    .render()
+                    .await
+            })
+        };
+
+        render(true).await;
+        assert!(recorder.warnings.lock().unwrap().is_empty());
+
+        let s = render(false).await;
+        assert_eq!(
+            s,
+            "<div><button>1</button><button>2</button><button>1</button><button>2</button><span></\
+             span></div>"
+        );
+
+        render(false).await;
+
+        let warnings = recorder.warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Button"));
+        assert!(!warnings[0].contains("Comp"));
+    }
+
+    #[cfg(all(feature = "a11y-lints", debug_assertions))]
+    #[test]
+    async fn test_a11y_warnings() {
+        use std::sync::Arc;
+
+        use tokio::task::LocalSet;
+
+        use crate::LocalServerRenderer;
+
+        #[function_component]
+        fn Valid() -> Html {
+            html! {
+                <nav role="navigation" aria-label="Main">
+                    <span aria-hidden="true">{"*"}</span>
+                    <button aria-hidden="true" tabindex="-1">{"x"}</button>
+                </nav>
+            }
+        }
+
+        #[function_component]
+        fn Invalid() -> Html {
+            html! {
+                <div role="buton">
+                    <a href="/" aria-hidden="true">{"home"}</a>
+                </div>
+            }
+        }
+
+        let recorder = Arc::new(WarningRecorder::default());
+        let _guard = tracing::dispatcher::set_default(&recorder.clone().into());
+
+        LocalSet::new()
+            .run_until(async move {
+                LocalServerRenderer::<Valid>::new()
+                    .hydratable(false)
+                    .render()
+                    .await
+            })
+            .await;
+        assert!(recorder.warnings.lock().unwrap().is_empty());
+
+        let s = LocalSet::new()
+            .run_until(async move {
+                LocalServerRenderer::<Invalid>::new()
+                    .hydratable(false)
+                    .render()
+                    .await
+            })
+            .await;
+        // the attributes are rendered as they are.
+        assert_eq!(
+            s,
+            r#"<div role="buton"><a href="/" aria-hidden="true">home</a></div>"#
+        );
+
+        let warnings = recorder.warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains(r#"role="buton""#));
+        assert!(warnings[1].contains("<a> has aria-hidden"));
+    }
 }
@@ -7,16 +7,91 @@ use super::AttrValue;
 /// A type for a virtual
 /// [`TextNode`](https://developer.mozilla.org/en-US/docs/Web/API/Document/createTextNode)
 /// representation.
+///
+/// A `VText` can't be created with a struct literal, as it also tracks whether its text needs to
+/// be escaped, use [`VText::new`] or [`VText::new_trusted`] instead.
 #[derive(Clone)]
 pub struct VText {
     /// Contains a text of the node.
     pub text: AttrValue,
+    /// Whether the text is known to contain no characters that need to be escaped.
+    pub(crate) trusted: bool,
 }
 
 impl VText {
     /// Creates new virtual text node with a content.
     pub fn new(text: impl Into<AttrValue>) -> Self {
-        VText { text: text.into() }
+        VText {
+            text: text.into(),
+            trusted: false,
+        }
+    }
+
+    /// Creates new virtual text node with a content that contains no `<`, `>` or `&`.
+    ///
+    /// The content is written without escaping during server-side rendering, which saves
+    /// scanning text that is known to be safe, e.g.: numbers or the names of enum variants. It
+    /// behaves like [`VText::new`] otherwise.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the content contains `<`, `>` or `&`. In release builds, such
+    /// content is written to the server-side rendered result as is.
+    pub fn new_trusted(text: impl Into<AttrValue>) -> Self {
+        let text = text.into();
+
+        debug_assert!(
+            !text.contains(['<', '>', '&']),
+            "trusted text contains characters that need to be escaped: {:?}",
+            text
+        );
+
+        VText {
+            text,
+            trusted: true,
+        }
+    }
+}
+
+impl VText {
+    /// Concatenates multiple virtual text nodes into a single one.
+    ///
+    /// A single part is returned as is, multiple parts are joined into one string. The result is
+    /// trusted if all parts are trusted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use yew::virtual_dom::VText;
+    ///
+    /// let text = VText::concat([VText::new("current count: "), VText::new("3")]);
+    ///
+    /// assert_eq!(&*text.text, "current count: 3");
+    /// ```
+    pub fn concat(parts: impl IntoIterator<Item = VText>) -> Self {
+        let mut parts = parts.into_iter();
+
+        let first = match parts.next() {
+            Some(m) => m,
+            None => return VText::new(""),
+        };
+
+        let mut rest = parts.peekable();
+        if rest.peek().is_none() {
+            return first;
+        }
+
+        let mut trusted = first.trusted;
+        let mut text = first.text.to_string();
+        for part in rest {
+            trusted = trusted && part.trusted;
+            text.push_str(&part.text);
+        }
+
+        VText {
+            text: text.into(),
+            trusted,
+        }
     }
 }
 
@@ -35,21 +110,83 @@ impl PartialEq for VText {
 #[cfg(feature = "ssr")]
 mod feat_ssr {
 
+    use std::borrow::Cow;
     use std::fmt::Write;
 
     use super::*;
-    use crate::html::AnyScope;
     use crate::platform::fmt::BufWriter;
+    use crate::virtual_dom::SsrContext;
+
+    /// Writes the text of a raw text element verbatim, only sequences that would close the
+    /// element, e.g.: `</script`, are written as `<\/script`.
+    fn write_raw_text(w: &mut BufWriter, text: &str, tag: &str) {
+        let mut rest = text;
+
+        while let Some(pos) = rest.find("</") {
+            let (before, after) = (&rest[..pos], &rest[pos + 2..]);
+            let closes_element = after
+                .get(..tag.len())
+                .map_or(false, |m| m.eq_ignore_ascii_case(tag));
+
+            let _ = w.write_str(before);
+            let _ = w.write_str(if closes_element { "<\\/" } else { "</" });
+            rest = after;
+        }
+
+        let _ = w.write_str(rest);
+    }
+
+    /// Collapses every run of ASCII whitespace in `text` into a single space, like the browser
+    /// does for elements with `white-space: normal`.
+    fn collapse_whitespace(text: &str) -> Cow<'_, str> {
+        let bytes = text.as_bytes();
+        let is_collapsed = bytes.iter().enumerate().all(|(i, m)| {
+            !m.is_ascii_whitespace()
+                || (*m == b' ' && !bytes.get(i + 1).map_or(false, u8::is_ascii_whitespace))
+        });
+        if is_collapsed {
+            return Cow::Borrowed(text);
+        }
+
+        let mut s = String::with_capacity(text.len());
+        let mut in_whitespace = false;
+        for c in text.chars() {
+            if c.is_ascii_whitespace() {
+                if !in_whitespace {
+                    s.push(' ');
+                }
+                in_whitespace = true;
+            } else {
+                s.push(c);
+                in_whitespace = false;
+            }
+        }
+
+        Cow::Owned(s)
+    }
 
     impl VText {
-        pub(crate) async fn render_into_stream(
-            &self,
-            w: &mut BufWriter,
-            _parent_scope: &AnyScope,
-            _hydratable: bool,
-        ) {
-            let s = html_escape::encode_text(&self.text);
-            let _ = w.write_str(&s);
+        /// Writes the text, escaped unless it is the text of a raw text element.
+        ///
+        /// Runs of whitespace are collapsed if enabled, except in raw text elements.
+        pub(crate) fn write_into(&self, w: &mut BufWriter, ctx: &SsrContext) {
+            if let Some(tag) = ctx.raw_text_element {
+                write_raw_text(w, &self.text, tag);
+                return;
+            }
+
+            let text = if ctx.collapse_whitespace {
+                collapse_whitespace(&self.text)
+            } else {
+                Cow::Borrowed(&*self.text)
+            };
+
+            if self.trusted {
+                let _ = w.write_str(&text);
+            } else {
+                let s = html_escape::encode_text(&text);
+                let _ = w.write_str(&s);
+            }
         }
     }
 }
@@ -77,4 +214,99 @@ mod ssr_tests {
 
         assert_eq!(s, r#"abc"#);
     }
+
+    #[test]
+    async fn test_trusted_str() {
+        use crate::virtual_dom::VText;
+
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <ul>
+                    { for (0..3).map(|i| html! { <li>{VText::new_trusted(i.to_string())}</li> }) }
+                    <li>{VText::new("a < b")}</li>
+                </ul>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<ul><li>0</li><li>1</li><li>2</li><li>a &lt; b</li></ul>"
+        );
+    }
+
+    #[test]
+    async fn test_cow_str() {
+        use std::borrow::Cow;
+
+        use crate::html::IntoPropValue;
+        use crate::virtual_dom::{AttrValue, VText};
+
+        #[function_component]
+        fn Comp() -> Html {
+            let parts: [Cow<'static, str>; 2] = [Cow::Borrowed("a < "), Cow::Owned(2.to_string())];
+
+            html! {
+                <p title={Cow::<'static, str>::Borrowed("title")}>
+                    { for parts.into_iter().map(|m| {
+                        VText::new(IntoPropValue::<AttrValue>::into_prop_value(m))
+                    }) }
+                </p>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, r#"<p title="title">a &lt; 2</p>"#);
+    }
+
+    #[test]
+    async fn test_collapse_whitespace() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <p>{"  a   b  "}</p>
+                    <pre>{"  a   b  "}</pre>
+                    <textarea value={"  a\n\n  b"} />
+                    <span>{"\ta\n"}</span>
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .collapse_whitespace(true)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            "<div><p> a b </p><pre>  a   b  </pre><textarea>  a\n\n  b</textarea><span> a \
+             </span></div>"
+        );
+
+        // whitespace is kept by default.
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert!(s.contains("<p>  a   b  </p>"));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "trusted text contains characters that need to be escaped")]
+    async fn test_trusted_str_with_special_characters() {
+        crate::virtual_dom::VText::new_trusted("a < b");
+    }
 }
@@ -0,0 +1,95 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{obtain_result, output_element};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use web_sys::{CustomEvent, CustomEventInit, Event, MouseEvent};
+use yew::html::{oncustomevent, CustomEventError};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Serialize, Deserialize)]
+struct ColorDetail {
+    color: String,
+}
+
+fn dispatch(event: &Event) {
+    gloo::utils::document()
+        .get_element_by_id("picker")
+        .unwrap()
+        .dispatch_event(event)
+        .unwrap();
+}
+
+fn dispatch_custom(detail: &JsValue) {
+    let mut init = CustomEventInit::new();
+    init.detail(detail);
+    let event = CustomEvent::new_with_event_init_dict("color-change", &init).unwrap();
+    dispatch(&event);
+}
+
+#[wasm_bindgen_test]
+async fn oncustomevent_deserializes_detail() {
+    #[function_component]
+    fn App() -> Html {
+        let result = use_state(String::new);
+        let onchange = {
+            let result = result.clone();
+            Callback::from(
+                move |detail: Result<ColorDetail, CustomEventError>| match detail {
+                    Ok(detail) => result.set(detail.color),
+                    Err(CustomEventError::NotCustomEvent) => result.set("not custom".to_owned()),
+                    Err(CustomEventError::Detail(_)) => result.set("mismatch".to_owned()),
+                },
+            )
+        };
+
+        Html::list()
+            .child(
+                Html::tag("color-picker")
+                    .attr("id", "picker")
+                    .listener(oncustomevent("color-change", onchange)),
+            )
+            .child(
+                Html::tag("div")
+                    .attr("id", "result")
+                    .child(Html::text((*result).clone())),
+            )
+            .build()
+    }
+
+    yew::Renderer::<App>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+
+    let detail = serde_wasm_bindgen::to_value(&ColorDetail {
+        color: "red".to_owned(),
+    })
+    .unwrap();
+    dispatch_custom(&detail);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "red");
+
+    dispatch_custom(&JsValue::from(42));
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "mismatch");
+
+    // a `MouseEvent` has a `detail` as well, but is not a `CustomEvent`.
+    dispatch(&MouseEvent::new("color-change").unwrap());
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "not custom");
+
+    dispatch_custom(&detail);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "red");
+
+    dispatch(&Event::new("color-change").unwrap());
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "not custom");
+}
@@ -0,0 +1,120 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+#[cfg(panic = "unwind")]
+use common::obtain_result_by_id;
+#[cfg(panic = "unwind")]
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+#[cfg(panic = "unwind")]
+use web_sys::HtmlElement;
+use yew::html::ErrorBoundary;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn error_boundary_renders_children() {
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <ErrorBoundary fallback={html! { {"failed"} }}>
+                    <span>{"child"}</span>
+                </ErrorBoundary>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    // Without a panic, which would abort the module with `panic = "abort"`, an error boundary
+    // renders its children like a fragment.
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result().as_str(), "<span>child</span>");
+}
+
+// Recovering from a panic requires unwinding.
+#[cfg(panic = "unwind")]
+#[wasm_bindgen_test]
+async fn error_boundary_keeps_siblings_alive() {
+    #[function_component]
+    fn Faulty() -> Html {
+        let fail = use_state(|| false);
+
+        if *fail {
+            panic!("faulty component failed");
+        }
+
+        let onclick = {
+            let fail = fail.clone();
+            Callback::from(move |_| fail.set(true))
+        };
+
+        html! { <button class="fail" {onclick}>{"fail"}</button> }
+    }
+
+    #[function_component]
+    fn Counter() -> Html {
+        let ctr = use_state(|| 0);
+
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_| ctr.set(*ctr + 1))
+        };
+
+        html! {
+            <>
+                <div id="counter">{*ctr}</div>
+                <button class="increase" {onclick}>{"increase"}</button>
+            </>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <>
+                <div id="result">
+                    <ErrorBoundary fallback={html! { {"failed"} }}>
+                        <Faulty />
+                    </ErrorBoundary>
+                </div>
+                <Counter />
+            </>
+        }
+    }
+
+    fn click(selector: &str) {
+        gloo::utils::document()
+            .query_selector(selector)
+            .unwrap()
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap()
+            .click();
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!(
+        obtain_result().as_str(),
+        r#"<button class="fail">fail</button>"#
+    );
+
+    click(".fail");
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result().as_str(), "failed");
+
+    click(".increase");
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("counter").as_str(), "1");
+}
@@ -1,6 +1,7 @@
 #![cfg(feature = "hydration")]
 #![cfg(target_arch = "wasm32")]
 
+use std::cell::RefCell;
 use std::ops::Range;
 use std::rc::Rc;
 use std::time::Duration;
@@ -15,7 +16,7 @@ use web_sys::{HtmlElement, HtmlTextAreaElement};
 use yew::platform::time::sleep;
 use yew::prelude::*;
 use yew::suspense::{use_future, Suspension, SuspensionResult};
-use yew::{Renderer, ServerRenderer};
+use yew::{HydrationError, HydrationReport, Renderer, ServerRenderer};
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
@@ -1028,3 +1029,670 @@ async fn hydrate_empty() {
     let result = obtain_result_by_id("output");
     assert_eq!(result.as_str(), r#"<div>after</div><div>after</div>"#);
 }
+
+#[wasm_bindgen_test]
+async fn hydrate_static() {
+    #[function_component]
+    fn Updating() -> Html {
+        let trigger = use_state(|| false);
+        {
+            let trigger = trigger.clone();
+            use_effect_with_deps(
+                move |_| {
+                    trigger.set(true);
+                    || {}
+                },
+                (),
+            );
+        }
+        if *trigger {
+            html! { <div>{"after"}</div> }
+        } else {
+            html! { <div>{"before"}</div> }
+        }
+    }
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <>
+                <Updating />
+                <yew::html::Static>
+                    <Updating />
+                </yew::html::Static>
+            </>
+        }
+    }
+    let s = ServerRenderer::<App>::new().render().await;
+
+    let output_element = gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap();
+
+    output_element.set_inner_html(&s);
+
+    Renderer::<App>::with_root(output_element).hydrate();
+    sleep(Duration::from_millis(50)).await;
+
+    // The static region keeps the server-rendered content.
+    let result = obtain_result_by_id("output");
+    assert_eq!(result.as_str(), r#"<div>after</div><div>before</div>"#);
+}
+
+#[wasm_bindgen_test]
+async fn hydration_with_deferred_suspense() {
+    #[function_component]
+    fn Content() -> HtmlResult {
+        let s = use_state(|| Suspension::from_future(sleep(Duration::from_millis(50))));
+
+        if !s.resumed() {
+            return Err((*s).clone());
+        }
+
+        Ok(html! { <div class="content-area">{"content"}</div> })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .defer_suspense(true)
+        .render()
+        .await;
+
+    // the children are not rendered on the server side.
+    assert!(s.contains("<!--<?defer>-->"));
+    assert!(!s.contains("content-area"));
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    // the server rendered fallback is shown while the children are suspended.
+    let result = obtain_result();
+    assert!(result.contains("<div>wait...</div>"));
+    assert!(!result.contains("content-area"));
+
+    sleep(Duration::from_millis(100)).await;
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<div class="content-area">content</div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydrate_with_report() {
+    #[function_component]
+    fn App() -> Html {
+        html! { <div id="result"><span>{"a"}</span>{"b"}</div> }
+    }
+
+    let output = gloo::utils::document().get_element_by_id("output").unwrap();
+
+    let s = ServerRenderer::<App>::new().render().await;
+    output.set_inner_html(&s);
+
+    let report: Rc<RefCell<Option<HydrationReport>>> = Rc::default();
+    Renderer::<App>::with_root(output.clone()).hydrate_with_report({
+        let report = report.clone();
+        Callback::from(move |m| {
+            *report.borrow_mut() = Some(m);
+        })
+    });
+
+    sleep(Duration::ZERO).await;
+
+    // a clean hydration does not recreate any nodes.
+    let clean = report.borrow_mut().take().expect("no report");
+    assert_eq!(clean.hydrated_nodes, 4);
+    assert_eq!(clean.recreated_nodes, 0);
+    assert_eq!(obtain_result(), "<span>a</span>b");
+
+    #[function_component]
+    fn Merged() -> Html {
+        // The texts are rendered as a single text node by the server, the second text node is
+        // created during hydration.
+        html! { <div id="result">{"a"}<>{"b"}</></div> }
+    }
+
+    let s = ServerRenderer::<Merged>::new().render().await;
+    output.set_inner_html(&s);
+
+    Renderer::<Merged>::with_root(output).hydrate_with_report({
+        let report = report.clone();
+        Callback::from(move |m| {
+            *report.borrow_mut() = Some(m);
+        })
+    });
+
+    sleep(Duration::ZERO).await;
+
+    let merged = report.borrow_mut().take().expect("no report");
+    assert_eq!(merged.hydrated_nodes, 2);
+    assert_eq!(merged.recreated_nodes, 1);
+    assert_eq!(obtain_result(), "ab");
+}
+
+#[wasm_bindgen_test]
+async fn hydrate_island() {
+    #[derive(Properties, PartialEq)]
+    struct CounterProps {
+        name: AttrValue,
+    }
+
+    #[function_component]
+    fn Counter(props: &CounterProps) -> Html {
+        let ctr = use_state_eq(|| 0);
+
+        let onclick = {
+            let ctr = ctr.clone();
+
+            Callback::from(move |_| {
+                ctr.set(*ctr + 1);
+            })
+        };
+
+        html! {
+            <div>
+                {props.name.clone()}{": "}{*ctr}
+                <button {onclick} class={format!("increase-{}", props.name)}>{"+1"}</button>
+            </div>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <>
+                <div id="island-a"><Counter name="a" /></div>
+                <p>{"static"}</p>
+                <div id="island-b"><span>{"before"}</span><Counter name="b" /><span>{"after"}</span></div>
+            </>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    // only the second island is hydrated.
+    Renderer::<Counter>::with_root_and_props(
+        gloo::utils::document()
+            .get_element_by_id("island-b")
+            .unwrap(),
+        CounterProps { name: "b".into() },
+    )
+    .hydrate_island();
+
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(
+        obtain_result_by_id("island-b"),
+        r#"<span>before</span><div>b: 0<button class="increase-b">+1</button></div><span>after</span>"#
+    );
+
+    for class in [".increase-a", ".increase-b"] {
+        gloo::utils::document()
+            .query_selector(class)
+            .unwrap()
+            .unwrap()
+            .dyn_into::<HtmlElement>()
+            .unwrap()
+            .click();
+    }
+
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(
+        obtain_result_by_id("island-b"),
+        r#"<span>before</span><div>b: 1<button class="increase-b">+1</button></div><span>after</span>"#
+    );
+
+    // the first island and the rest of the page are left untouched.
+    let island_a = obtain_result_by_id("island-a");
+    assert!(island_a.starts_with("<!--<["));
+    assert!(island_a.contains("<div>a: 0<button"));
+    assert!(obtain_result_by_id("output").contains("<p>static</p>"));
+}
+
+// Release builds do not write the names of components into the hydration markers, the first
+// component of the root element is hydrated instead.
+#[cfg(debug_assertions)]
+#[wasm_bindgen_test]
+async fn hydrate_island_after_other_component() {
+    #[function_component]
+    fn Label() -> Html {
+        html! { <span>{"label"}</span> }
+    }
+
+    #[function_component]
+    fn Counter() -> Html {
+        let ctr = use_state_eq(|| 0);
+
+        let onclick = {
+            let ctr = ctr.clone();
+
+            Callback::from(move |_| {
+                ctr.set(*ctr + 1);
+            })
+        };
+
+        html! {
+            <button {onclick} class="increase">{*ctr}</button>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div id="island"><Label /><Counter /></div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    // the markers of Label are skipped.
+    Renderer::<Counter>::with_root(gloo::utils::document().get_element_by_id("island").unwrap())
+        .hydrate_island();
+
+    sleep(Duration::ZERO).await;
+
+    gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+
+    let island = obtain_result_by_id("island");
+    assert!(island.contains("<span>label</span>"));
+    assert!(island.contains(r#"<button class="increase">1</button>"#));
+}
+
+#[wasm_bindgen_test]
+async fn hydrate_within_root_marker() {
+    #[function_component]
+    fn App() -> Html {
+        let ctr = use_state_eq(|| 0);
+
+        let onclick = {
+            let ctr = ctr.clone();
+            Callback::from(move |_| ctr.set(*ctr + 1))
+        };
+
+        html! {
+            <div>
+                {"Counter: "}{*ctr}
+                <button {onclick} class="increase">{"+1"}</button>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .root_marker(true)
+        .render()
+        .await;
+
+    // the rendered result is surrounded by unrelated markup of the page.
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&format!(
+            "<header>header</header><!--page-->{}<footer>footer</footer>",
+            s
+        ));
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::ZERO).await;
+
+    gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(
+        obtain_result_by_id("output"),
+        r#"<header>header</header><!--page--><!--<yew-root>--><div>Counter: 1<button class="increase">+1</button></div><!--</yew-root>--><footer>footer</footer>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn try_hydrate_without_markers() {
+    #[function_component]
+    fn App() -> Html {
+        html! { <div id="result">{"content"}</div> }
+    }
+
+    let output = gloo::utils::document().get_element_by_id("output").unwrap();
+
+    let s = ServerRenderer::<App>::new()
+        .hydratable(false)
+        .render()
+        .await;
+    output.set_inner_html(&s);
+
+    let result = Renderer::<App>::with_root(output.clone()).try_hydrate();
+    assert_eq!(result.err(), Some(HydrationError::MissingHydrationMarkers));
+
+    // the root element is left untouched.
+    assert_eq!(obtain_result(), "content");
+
+    let s = ServerRenderer::<App>::new().render().await;
+    output.set_inner_html(&s);
+
+    let handle = Renderer::<App>::with_root(output).try_hydrate();
+    assert!(handle.is_ok());
+
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "content");
+}
+
+#[wasm_bindgen_test]
+async fn try_hydrate_into_document_without_window() {
+    #[function_component]
+    fn App() -> Html {
+        html! { <div>{"content"}</div> }
+    }
+
+    // a document created by `createHTMLDocument` is not displayed in a window.
+    let document = gloo::utils::document()
+        .implementation()
+        .unwrap()
+        .create_html_document()
+        .unwrap();
+    let root: web_sys::Element = document.body().unwrap().into();
+
+    let s = ServerRenderer::<App>::new().render().await;
+    root.set_inner_html(&s);
+
+    let result = Renderer::<App>::with_root(root.clone()).try_hydrate();
+    assert_eq!(result.err(), Some(HydrationError::RootNotInDocument));
+
+    // the root element is left untouched.
+    assert_eq!(root.inner_html(), s);
+}
+
+#[wasm_bindgen_test]
+async fn hydration_keyed_list_in_different_order() {
+    #[derive(Properties, PartialEq, Clone)]
+    struct ItemProps {
+        number: u32,
+    }
+
+    #[function_component]
+    fn Item(props: &ItemProps) -> Html {
+        let clicked = use_state(|| false);
+        let onclick = {
+            let clicked = clicked.clone();
+            Callback::from(move |_| clicked.set(true))
+        };
+
+        html! {
+            <button class={format!("item-{}", props.number)} {onclick}>
+                {props.number}{if *clicked { "!" } else { "" }}
+            </button>
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct AppProps {
+        order: Vec<u32>,
+    }
+
+    #[function_component]
+    fn App(props: &AppProps) -> Html {
+        html! {
+            <div id="result">
+                { for props.order.iter().map(|&number| html! { <Item key={number} {number} /> }) }
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::with_props(|| AppProps {
+        order: vec![1, 2, 3],
+    })
+    .render()
+    .await;
+
+    let output = gloo::utils::document().get_element_by_id("output").unwrap();
+    output.set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root_and_props(
+        output,
+        AppProps {
+            order: vec![3, 1, 2],
+        },
+    )
+    .hydrate();
+
+    sleep(Duration::ZERO).await;
+
+    // the nodes rendered on the server side are moved into the order of the client side.
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<button class="item-3">3</button><button class="item-1">1</button><button class="item-2">2</button>"#
+    );
+
+    // listeners are attached to the nodes of the component with the same key.
+    gloo::utils::document()
+        .query_selector(".item-1")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<button class="item-3">3</button><button class="item-1">1!</button><button class="item-2">2</button>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydration_with_ssr_fallback() {
+    #[function_component]
+    fn Chart() -> Html {
+        html! { <canvas class="chart"></canvas> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <Chart ssr_fallback={html! { <p>{"Loading..."}</p> }} />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    // the fallback is hydrated and replaced by the component afterwards.
+    sleep(Duration::ZERO).await;
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<canvas class="chart"></canvas>"#);
+}
+
+#[wasm_bindgen_test]
+async fn render_with_ssr_fallback() {
+    #[function_component]
+    fn Chart() -> Html {
+        html! { <canvas class="chart"></canvas> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <Chart ssr_fallback={html! { <p>{"Loading..."}</p> }} />
+            </div>
+        }
+    }
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<canvas class="chart"></canvas>"#);
+}
+
+#[wasm_bindgen_test]
+async fn hydrate_static_with_sibling_inserted_before() {
+    #[function_component]
+    fn App() -> Html {
+        let show = use_state(|| false);
+        {
+            let show = show.clone();
+            use_effect_with_deps(
+                move |_| {
+                    show.set(true);
+                    || {}
+                },
+                (),
+            );
+        }
+
+        html! {
+            <>
+                if *show {
+                    <div>{"inserted"}</div>
+                }
+                <yew::html::Static>
+                    <div>{"static"}</div>
+                </yew::html::Static>
+                <div>{"after"}</div>
+            </>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    let output_element = gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap();
+
+    output_element.set_inner_html(&s);
+
+    Renderer::<App>::with_root(output_element).hydrate();
+    sleep(Duration::ZERO).await;
+
+    // The sibling is inserted before the adopted nodes of the static component.
+    let result = obtain_result_by_id("output");
+    assert_eq!(
+        result.as_str(),
+        r#"<div>inserted</div><div>static</div><div>after</div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydrate_static_with_props_changed() {
+    #[function_component]
+    fn App() -> Html {
+        let ctr = use_state(|| 0);
+        {
+            let ctr = ctr.clone();
+            use_effect_with_deps(
+                move |_| {
+                    ctr.set(1);
+                    || {}
+                },
+                (),
+            );
+        }
+
+        html! {
+            <>
+                <yew::html::Static>
+                    <div>{*ctr}</div>
+                </yew::html::Static>
+                <div>{"after"}</div>
+            </>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    let output_element = gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap();
+
+    output_element.set_inner_html(&s);
+
+    Renderer::<App>::with_root(output_element).hydrate();
+    sleep(Duration::ZERO).await;
+
+    // The adopted nodes are replaced by the children rendered on the client.
+    let result = obtain_result_by_id("output");
+    assert_eq!(result.as_str(), r#"<div>1</div><div>after</div>"#);
+}
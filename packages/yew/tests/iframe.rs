@@ -0,0 +1,234 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use js_sys::Reflect;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{Document, Element, HtmlIFrameElement, Node};
+use yew::html::{oncustomevent, CustomEventError};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns whether `node` was created by `document`.
+///
+/// Inserting a node into another document changes its owner document, but the prototype of the
+/// node stays the one of the window of the document that created it.
+fn is_created_by(node: &Node, document: &Document) -> bool {
+    let expected: Node = match node.node_type() {
+        Node::TEXT_NODE => document.create_text_node("").into(),
+        _ => {
+            let element = node.unchecked_ref::<Element>();
+            document
+                .create_element_ns(element.namespace_uri().as_deref(), &element.local_name())
+                .unwrap()
+                .into()
+        }
+    };
+
+    JsValue::from(js_sys::Object::get_prototype_of(node))
+        == JsValue::from(js_sys::Object::get_prototype_of(&expected))
+}
+
+#[wasm_bindgen_test]
+async fn render_into_iframe() {
+    #[function_component]
+    fn App() -> Html {
+        let counter = use_state(|| 0);
+        let onclick = {
+            let counter = counter.clone();
+            Callback::from(move |_| counter.set(*counter + 1))
+        };
+
+        html! {
+            <div id="content">
+                <button {onclick}>{*counter}</button>
+                <svg><circle r="1" /></svg>
+                <Suspense fallback={html! { "loading" }}>
+                    <p>{"suspense"}</p>
+                </Suspense>
+            </div>
+        }
+    }
+
+    let iframe: HtmlIFrameElement = gloo::utils::document()
+        .create_element("iframe")
+        .unwrap()
+        .unchecked_into();
+    gloo::utils::document()
+        .body()
+        .unwrap()
+        .append_child(&iframe)
+        .unwrap();
+    let iframe_document = iframe.content_document().unwrap();
+    let root: Element = iframe_document.body().unwrap().into();
+
+    let app = yew::Renderer::<App>::with_root(root).render();
+    sleep(Duration::ZERO).await;
+
+    assert!(gloo::utils::document()
+        .get_element_by_id("content")
+        .is_none());
+    let content = iframe_document.get_element_by_id("content").unwrap();
+    assert_eq!(
+        content.inner_html(),
+        r#"<button>0</button><svg><circle r="1"></circle></svg><p>suspense</p>"#
+    );
+
+    // all nodes are created by the document of the iframe.
+    let mut nodes = vec![Node::from(content)];
+    while let Some(node) = nodes.pop() {
+        assert!(is_created_by(&node, &iframe_document));
+        assert!(!is_created_by(&node, &gloo::utils::document()));
+
+        let children = node.child_nodes();
+        nodes.extend((0..children.length()).filter_map(|i| children.item(i)));
+    }
+
+    // listeners are attached in the iframe.
+    iframe_document
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::ZERO).await;
+
+    let button = iframe_document.query_selector("button").unwrap().unwrap();
+    assert_eq!(button.inner_html(), "1");
+
+    app.destroy();
+    iframe.remove();
+}
+
+#[wasm_bindgen_test]
+async fn use_style_in_iframe() {
+    const CSS: &str = "& { color: rgb(0, 0, 255); }";
+
+    #[function_component]
+    fn App() -> Html {
+        let style = use_style(CSS);
+
+        html! { <span class={style}>{"label"}</span> }
+    }
+
+    let selector = format!(
+        r#"style[data-yew-style="{}"]"#,
+        yew::html::Style::new(CSS).class_name()
+    );
+    let count_styles =
+        |document: &Document| document.query_selector_all(&selector).unwrap().length();
+
+    let iframe: HtmlIFrameElement = gloo::utils::document()
+        .create_element("iframe")
+        .unwrap()
+        .unchecked_into();
+    gloo::utils::document()
+        .body()
+        .unwrap()
+        .append_child(&iframe)
+        .unwrap();
+    let iframe_document = iframe.content_document().unwrap();
+    let root: Element = iframe_document.body().unwrap().into();
+
+    let iframe_app = yew::Renderer::<App>::with_root(root).render();
+    sleep(Duration::ZERO).await;
+
+    // the style is added to the document of the iframe only.
+    assert_eq!(count_styles(&iframe_document), 1);
+    assert_eq!(count_styles(&gloo::utils::document()), 0);
+
+    let app = yew::Renderer::<App>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+
+    // each document has its own copy of the style.
+    assert_eq!(count_styles(&iframe_document), 1);
+    assert_eq!(count_styles(&gloo::utils::document()), 1);
+
+    app.destroy();
+    iframe_app.destroy();
+    iframe.remove();
+}
+
+#[wasm_bindgen_test]
+async fn custom_event_from_iframe() {
+    #[function_component]
+    fn App() -> Html {
+        let result = use_state(String::new);
+        let onchange = {
+            let result = result.clone();
+            Callback::from(
+                move |detail: Result<String, CustomEventError>| match detail {
+                    Ok(detail) => result.set(detail),
+                    Err(CustomEventError::NotCustomEvent) => result.set("not custom".to_owned()),
+                    Err(CustomEventError::Detail(_)) => result.set("mismatch".to_owned()),
+                },
+            )
+        };
+
+        Html::list()
+            .child(
+                Html::tag("color-picker")
+                    .attr("id", "picker")
+                    .listener(oncustomevent("color-change", onchange)),
+            )
+            .child(
+                Html::tag("div")
+                    .attr("id", "result")
+                    .child(Html::text((*result).clone())),
+            )
+            .build()
+    }
+
+    let iframe: HtmlIFrameElement = gloo::utils::document()
+        .create_element("iframe")
+        .unwrap()
+        .unchecked_into();
+    gloo::utils::document()
+        .body()
+        .unwrap()
+        .append_child(&iframe)
+        .unwrap();
+    let iframe_document = iframe.content_document().unwrap();
+    let root: Element = iframe_document.body().unwrap().into();
+
+    let app = yew::Renderer::<App>::with_root(root).render();
+    sleep(Duration::ZERO).await;
+
+    // the event is created with the `CustomEvent` of the iframe.
+    let constructor = Reflect::get(
+        &iframe.content_window().unwrap(),
+        &JsValue::from_str("CustomEvent"),
+    )
+    .unwrap();
+    let init = js_sys::Object::new();
+    Reflect::set(
+        &init,
+        &JsValue::from_str("detail"),
+        &JsValue::from_str("red"),
+    )
+    .unwrap();
+    let event = Reflect::construct(
+        constructor.unchecked_ref(),
+        &js_sys::Array::of2(&JsValue::from_str("color-change"), &init),
+    )
+    .unwrap();
+
+    iframe_document
+        .get_element_by_id("picker")
+        .unwrap()
+        .dispatch_event(event.unchecked_ref())
+        .unwrap();
+    sleep(Duration::ZERO).await;
+
+    let result = iframe_document.get_element_by_id("result").unwrap();
+    assert_eq!(result.inner_html(), "red");
+
+    app.destroy();
+    iframe.remove();
+}
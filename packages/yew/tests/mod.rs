@@ -40,3 +40,335 @@ async fn props_are_passed() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "done");
 }
+
+#[wasm_bindgen_test]
+async fn props_are_created_from_root() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Properties, Clone, PartialEq)]
+    struct UserProps {
+        user: Option<String>,
+    }
+
+    #[function_component]
+    fn User(props: &UserProps) -> Html {
+        html! {
+            <div id="result">{props.user.clone().unwrap_or_default()}</div>
+        }
+    }
+
+    let root = gloo::utils::document().get_element_by_id("output").unwrap();
+    root.set_attribute("data-user", "ferris").unwrap();
+
+    let calls = Rc::new(Cell::new(0));
+    let renderer = {
+        let calls = calls.clone();
+        yew::Renderer::<User>::with_root_and_props_fn(root.clone(), move |root| {
+            calls.set(calls.get() + 1);
+            UserProps {
+                user: root.get_attribute("data-user"),
+            }
+        })
+    };
+    // props are not created before the application is rendered.
+    assert_eq!(calls.get(), 0);
+
+    let handle = renderer.render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(calls.get(), 1);
+    assert_eq!(obtain_result(), "ferris");
+
+    handle.destroy();
+    root.remove_attribute("data-user").unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn render_with_callback_fires_after_first_rendered() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Properties, Clone)]
+    struct LogProps {
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl PartialEq for LogProps {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.log, &other.log)
+        }
+    }
+
+    #[function_component]
+    fn Child(props: &LogProps) -> Html {
+        let log = props.log.clone();
+        use_effect(move || {
+            log.borrow_mut().push("child rendered");
+        });
+
+        html! {}
+    }
+
+    struct Root;
+
+    impl Component for Root {
+        type Message = ();
+        type Properties = LogProps;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            html! {
+                <div id="result">
+                    <Child log={ctx.props().log.clone()} />
+                    {"done"}
+                </div>
+            }
+        }
+
+        fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+            if first_render {
+                ctx.props().log.borrow_mut().push("root rendered");
+            }
+        }
+    }
+
+    let log: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+
+    let cb_log = log.clone();
+    yew::Renderer::<Root>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        LogProps { log: log.clone() },
+    )
+    .render_with_callback(Callback::from(move |_| {
+        cb_log.borrow_mut().push("first paint");
+    }));
+
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result().as_str(), "done");
+    assert_eq!(
+        *log.borrow(),
+        vec!["child rendered", "root rendered", "first paint"]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn keyed_tag_keeps_focus() {
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+
+    #[function_component]
+    fn Comp() -> Html {
+        let show_notice = use_state(|| false);
+
+        let onclick = {
+            let show_notice = show_notice.clone();
+            Callback::from(move |_| show_notice.set(true))
+        };
+
+        let mut children = Vec::new();
+        if *show_notice {
+            children.push(html! { <p key="notice">{"Notice"}</p> });
+        }
+        children.push(html! { <input key="input" id="keyed-input" /> });
+        children.push(html! { <button key="button" id="show-notice" {onclick}>{"Show"}</button> });
+
+        html! {
+            <div id="result">
+                {for children}
+            </div>
+        }
+    }
+
+    yew::Renderer::<Comp>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    let document = gloo::utils::document();
+    let input = document
+        .get_element_by_id("keyed-input")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    input.focus().unwrap();
+
+    // Clicking does not move the focus away from the input.
+    document
+        .get_element_by_id("show-notice")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+
+    assert!(obtain_result().starts_with("<p>Notice</p>"));
+
+    let active = document.active_element().unwrap();
+    assert!(input.is_same_node(Some(&active)));
+}
+
+#[wasm_bindgen_test]
+async fn render_into_detached_root() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlElement;
+
+    #[derive(Properties, Clone)]
+    struct CounterProps {
+        rendered: Rc<Cell<bool>>,
+    }
+
+    impl PartialEq for CounterProps {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.rendered, &other.rendered)
+        }
+    }
+
+    #[function_component]
+    fn Counter(props: &CounterProps) -> Html {
+        let counter = use_state(|| 0);
+
+        {
+            let rendered = props.rendered.clone();
+            use_effect_with_deps(move |_| rendered.set(true), ());
+        }
+
+        let onclick = {
+            let counter = counter.clone();
+            Callback::from(move |_| counter.set(*counter + 1))
+        };
+
+        html! {
+            <button id="result" {onclick}>{*counter}</button>
+        }
+    }
+
+    let document = gloo::utils::document();
+    let root = document.create_element("div").unwrap();
+    let rendered = Rc::new(Cell::new(false));
+
+    yew::Renderer::<Counter>::with_root_and_props(
+        root.clone(),
+        CounterProps {
+            rendered: rendered.clone(),
+        },
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+
+    // the application is rendered while the root is detached.
+    assert!(rendered.get());
+    assert!(document.get_element_by_id("result").is_none());
+    assert_eq!(root.inner_html(), r#"<button id="result">0</button>"#);
+
+    document
+        .get_element_by_id("output")
+        .unwrap()
+        .append_child(&root)
+        .unwrap();
+
+    document
+        .get_element_by_id("result")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "1");
+}
+
+#[wasm_bindgen_test]
+async fn try_render_into_document_without_window() {
+    #[function_component]
+    fn App() -> Html {
+        html! { <div id="result">{"content"}</div> }
+    }
+
+    // a document created by `createHTMLDocument` is not displayed in a window.
+    let document = gloo::utils::document()
+        .implementation()
+        .unwrap()
+        .create_html_document()
+        .unwrap();
+    let root: web_sys::Element = document.body().unwrap().into();
+    root.set_inner_html("<p>static</p>");
+
+    let result = yew::Renderer::<App>::with_root(root.clone()).try_render();
+    assert_eq!(result.err(), Some(yew::MountError::RootNotInDocument));
+
+    // the root element is left untouched.
+    assert_eq!(root.inner_html(), "<p>static</p>");
+
+    let root = gloo::utils::document().get_element_by_id("output").unwrap();
+    let handle = yew::Renderer::<App>::with_root(root).try_render();
+    assert!(handle.is_ok());
+
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "content");
+}
+
+#[wasm_bindgen_test]
+async fn skip_eq_props_do_not_re_render() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CHILD_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Properties)]
+    struct ChildProps {
+        label: String,
+        #[prop(skip_eq)]
+        tick: u32,
+    }
+
+    #[function_component]
+    fn Child(props: &ChildProps) -> Html {
+        CHILD_RENDERS.fetch_add(1, Ordering::SeqCst);
+
+        html! { <div id="result">{format!("{}-{}", props.label, props.tick)}</div> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let tick = use_state(|| 0);
+        let label = use_state(|| "a".to_string());
+
+        {
+            let tick = tick.clone();
+            let label = label.clone();
+            use_effect_with_deps(
+                move |_| {
+                    if *tick < 3 {
+                        tick.set(*tick + 1);
+                    } else if *label == "a" {
+                        label.set("b".to_string());
+                    }
+                    || {}
+                },
+                *tick,
+            );
+        }
+
+        html! { <Child label={(*label).clone()} tick={*tick} /> }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    // changing only the tick does not re-render the child, the new tick is passed along with the
+    // next label.
+    assert_eq!(CHILD_RENDERS.load(Ordering::SeqCst), 2);
+    assert_eq!(obtain_result(), "b-3");
+}
@@ -0,0 +1,46 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::Renderer;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn disabled_panic_hook_leaves_existing_hook() {
+    /// Records when the panic hook it belongs to is replaced.
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! { <div>{"app"}</div> }
+    }
+
+    let replaced = Arc::new(AtomicBool::new(false));
+    let flag = DropFlag(replaced.clone());
+    std::panic::set_hook(Box::new(move |_| {
+        let _ = &flag;
+    }));
+
+    let root = gloo::utils::document().create_element("div").unwrap();
+    let handle = Renderer::<App>::with_root(root)
+        .install_panic_hook(false)
+        .render();
+    assert!(!replaced.load(Ordering::SeqCst));
+    handle.destroy();
+
+    // the default panic hook is installed otherwise.
+    let root = gloo::utils::document().create_element("div").unwrap();
+    let handle = Renderer::<App>::with_root(root).render();
+    assert!(replaced.load(Ordering::SeqCst));
+    handle.destroy();
+}
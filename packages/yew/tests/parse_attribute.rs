@@ -0,0 +1,31 @@
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::*;
+use yew::html::{parse_attribute, try_parse_attribute, ParseAttributeError};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn parse_attributes_of_element() {
+    let element = gloo::utils::document().create_element("div").unwrap();
+    element.set_attribute("page-size", "20").unwrap();
+    element.set_attribute("compact", "").unwrap();
+    element.set_attribute("ratio", "one").unwrap();
+    let attributes = element.attributes();
+
+    assert_eq!(parse_attribute::<i32>(&attributes, "page-size"), 20);
+    assert!(parse_attribute::<bool>(&attributes, "compact"));
+    assert!(!parse_attribute::<bool>(&attributes, "disabled"));
+    assert_eq!(parse_attribute::<Option<i32>>(&attributes, "offset"), None);
+
+    // invalid values fall back to the default.
+    assert_eq!(parse_attribute::<f64>(&attributes, "ratio"), 0.0);
+    assert!(matches!(
+        try_parse_attribute::<f64>(&attributes, "ratio"),
+        Err(ParseAttributeError::Invalid { .. })
+    ));
+    assert_eq!(
+        try_parse_attribute::<String>(&attributes, "title"),
+        Err(ParseAttributeError::Missing)
+    );
+}
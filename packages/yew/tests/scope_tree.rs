@@ -0,0 +1,84 @@
+#![cfg(all(target_arch = "wasm32", feature = "debug-tooling"))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+use yew::ScopeInfo;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[function_component]
+fn Leaf() -> Html {
+    html! { <span>{"leaf"}</span> }
+}
+
+#[function_component]
+fn Item() -> Html {
+    html! { <li><Leaf /></li> }
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    items: Vec<&'static str>,
+}
+
+#[function_component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <div>
+            <ul>
+                { for props.items.iter().map(|&m| html! { <Item key={m} /> }) }
+            </ul>
+            <Leaf />
+        </div>
+    }
+}
+
+fn info(type_name: &'static str, key: Option<&str>, children: Vec<ScopeInfo>) -> ScopeInfo {
+    ScopeInfo {
+        type_name,
+        key: key.map(Key::from),
+        children,
+    }
+}
+
+fn item(key: &str) -> ScopeInfo {
+    let leaf = info(std::any::type_name::<Leaf>(), None, Vec::new());
+    info(std::any::type_name::<Item>(), Some(key), vec![leaf])
+}
+
+#[wasm_bindgen_test]
+async fn scope_tree_matches_hierarchy() {
+    let mut handle = yew::Renderer::<App>::with_root_and_props(
+        output_element(),
+        AppProps {
+            items: vec!["a", "b"],
+        },
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    let leaf = info(std::any::type_name::<Leaf>(), None, Vec::new());
+    assert_eq!(
+        handle.scope_tree(),
+        info(
+            std::any::type_name::<App>(),
+            None,
+            vec![item("a"), item("b"), leaf.clone()]
+        )
+    );
+
+    handle.update(AppProps { items: vec!["b"] });
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(
+        handle.scope_tree(),
+        info(std::any::type_name::<App>(), None, vec![item("b"), leaf])
+    );
+}
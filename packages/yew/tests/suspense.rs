@@ -13,7 +13,9 @@ use web_sys::{HtmlElement, HtmlTextAreaElement};
 use yew::platform::spawn_local;
 use yew::platform::time::sleep;
 use yew::prelude::*;
-use yew::suspense::{use_future, use_future_with_deps, Suspension, SuspensionResult};
+use yew::suspense::{
+    use_future, use_future_with_deps, Suspension, SuspensionHandle, SuspensionResult,
+};
 use yew::UseStateHandle;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -786,3 +788,153 @@ async fn resume_after_unmount() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "<div>Content replacement</div>");
 }
+
+#[wasm_bindgen_test]
+async fn fallback_delay_hides_short_suspensions() {
+    thread_local! {
+        static HANDLE: RefCell<Option<SuspensionHandle>> = RefCell::default();
+    }
+
+    /// Suspends until the handle is resumed by the test.
+    #[function_component(Content)]
+    fn content() -> HtmlResult {
+        let suspension = use_memo(
+            |_| {
+                let (s, handle) = Suspension::new();
+                HANDLE.with(|m| *m.borrow_mut() = Some(handle));
+                s
+            },
+            (),
+        );
+
+        if !suspension.resumed() {
+            return Err((*suspension).clone().into());
+        }
+
+        Ok(html! {
+            <div>
+                {"Content"}
+            </div>
+        })
+    }
+
+    #[derive(PartialEq, Properties)]
+    struct AppProps {
+        fallback_delay: Duration,
+    }
+
+    #[function_component(App)]
+    fn app(AppProps { fallback_delay }: &AppProps) -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Suspense {fallback} fallback_delay={*fallback_delay}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    let resume = || HANDLE.with(|m| m.borrow_mut().take().unwrap().resume());
+
+    // The suspension resumes before the delay elapses, the fallback is never shown.
+    let handle = yew::Renderer::<App>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        AppProps {
+            fallback_delay: Duration::from_secs(3600),
+        },
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "");
+
+    resume();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>Content</div>");
+
+    handle.destroy();
+
+    // The suspension lasts longer than the delay, the fallback is shown after the delay.
+    let fallback_delay = Duration::from_millis(10);
+    yew::Renderer::<App>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        AppProps { fallback_delay },
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "");
+
+    // This timer is started after the one of the delay, so it elapses after it.
+    sleep(fallback_delay).await;
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>wait...</div>");
+
+    resume();
+    sleep(Duration::ZERO).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>Content</div>");
+}
+
+#[wasm_bindgen_test]
+async fn async_function_component_works() {
+    #[derive(PartialEq, Properties, Clone)]
+    struct ContentProps {
+        delay_millis: u64,
+    }
+
+    #[function_component(fallback = html! { <div>{"wait..."}</div> })]
+    async fn Content(ContentProps { delay_millis }: &ContentProps) -> Html {
+        sleep(Duration::from_millis(*delay_millis)).await;
+
+        html! { <div>{"waited "}{*delay_millis}</div> }
+    }
+
+    #[function_component(App)]
+    fn app() -> Html {
+        let delay_millis = use_state(|| 50);
+        let onclick = {
+            let delay_millis = delay_millis.clone();
+            Callback::from(move |_| delay_millis.set(100))
+        };
+
+        html! {
+            <>
+                <button class="increase" {onclick}>{"+"}</button>
+                <div id="result">
+                    <Content delay_millis={*delay_millis} />
+                </div>
+            </>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(obtain_result(), "<div>wait...</div>");
+
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(obtain_result(), "<div>waited 50</div>");
+
+    // the fallback is shown again until the function resolves for the new properties.
+    gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(obtain_result(), "<div>wait...</div>");
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(obtain_result(), "<div>waited 100</div>");
+}
@@ -0,0 +1,147 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use common::{obtain_result, output_element};
+use js_sys::Promise;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::Renderer;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const ITEMS: u32 = 20;
+
+thread_local! {
+    static RENDERS: Cell<u32> = Cell::new(0);
+}
+
+async fn wait_for_renders(renders: u32) {
+    for _ in 0..200 {
+        if RENDERS.with(|m| m.get()) == renders {
+            return;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    panic!("the items were not rendered in time");
+}
+
+/// Waits until the pending microtasks have run, without letting the browser run another task.
+async fn flush_microtasks() {
+    JsFuture::from(Promise::resolve(&JsValue::UNDEFINED))
+        .await
+        .unwrap();
+}
+
+#[derive(Properties, PartialEq)]
+struct ItemProps {
+    value: u32,
+}
+
+#[function_component]
+fn Item(props: &ItemProps) -> Html {
+    RENDERS.with(|m| m.set(m.get() + 1));
+
+    html! { <li>{props.value}</li> }
+}
+
+#[function_component]
+fn App() -> Html {
+    let offset = use_state(|| 0);
+    let onclick = {
+        let offset = offset.clone();
+        Callback::from(move |_| offset.set(*offset + 1))
+    };
+
+    html! {
+        <>
+            <button id="update" {onclick}>{"update"}</button>
+            <ul id="result">
+                { for (0..ITEMS).map(|i| html! { <Item value={i + *offset} /> }) }
+            </ul>
+        </>
+    }
+}
+
+fn click_update() {
+    gloo::utils::document()
+        .get_element_by_id("update")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+}
+
+#[wasm_bindgen_test]
+async fn long_updates_yield_to_the_browser() {
+    RENDERS.with(|m| m.set(0));
+
+    let app = Renderer::<App>::with_root(output_element())
+        .time_budget(Duration::ZERO)
+        .render();
+
+    wait_for_renders(ITEMS).await;
+    RENDERS.with(|m| m.set(0));
+
+    click_update();
+
+    // Without yielding, the whole update would be processed in the microtask the scheduler
+    // is started in. The remaining updates are processed in later tasks.
+    flush_microtasks().await;
+    assert!(RENDERS.with(|m| m.get()) < ITEMS);
+
+    wait_for_renders(ITEMS).await;
+    sleep(Duration::from_millis(10)).await;
+
+    let expected = (1..=ITEMS)
+        .map(|i| format!("<li>{}</li>", i))
+        .collect::<String>();
+    assert_eq!(obtain_result(), expected);
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn first_renders_are_not_interrupted() {
+    RENDERS.with(|m| m.set(0));
+
+    let app = Renderer::<App>::with_root(output_element())
+        .time_budget(Duration::ZERO)
+        .render();
+
+    // The scheduler does not yield before all created components are rendered.
+    sleep(Duration::ZERO).await;
+    assert_eq!(RENDERS.with(|m| m.get()), ITEMS);
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn destroy_removes_the_time_budget() {
+    Renderer::<App>::with_root(output_element())
+        .time_budget(Duration::ZERO)
+        .render()
+        .destroy();
+
+    sleep(Duration::ZERO).await;
+
+    let app = Renderer::<App>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+    RENDERS.with(|m| m.set(0));
+
+    click_update();
+
+    // The budget of the destroyed application no longer applies, the update is not interrupted.
+    sleep(Duration::ZERO).await;
+    assert_eq!(RENDERS.with(|m| m.get()), ITEMS);
+
+    app.destroy();
+}
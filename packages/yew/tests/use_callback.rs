@@ -70,3 +70,67 @@ async fn use_callback_works() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "Hello, Yew!");
 }
+
+#[wasm_bindgen_test]
+async fn weak_callback_does_not_keep_component_alive() {
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+
+    use yew::callback::WeakCallback;
+
+    thread_local! {
+        // An external event bus, it outlives the component.
+        static SUBSCRIBERS: RefCell<Vec<WeakCallback<u32>>> = RefCell::default();
+        static STATE: RefCell<Weak<RefCell<State>>> = RefCell::default();
+    }
+
+    #[derive(Default)]
+    struct State {
+        received: Vec<u32>,
+        // The callback of the subscription refers to this state, which refers to the callback.
+        subscription: Option<WeakCallback<u32>>,
+    }
+
+    #[function_component]
+    fn Subscriber() -> Html {
+        let state = use_mut_ref(State::default);
+        let callback = {
+            let state = state.clone();
+            use_callback(move |value, _| state.borrow_mut().received.push(value), ())
+        };
+
+        use_effect_with_deps(
+            move |_| {
+                state.borrow_mut().subscription = Some(callback.downgrade());
+                SUBSCRIBERS.with(|m| m.borrow_mut().push(callback.downgrade()));
+                STATE.with(|m| *m.borrow_mut() = Rc::downgrade(&state));
+                // the callback is not unsubscribed on purpose.
+                || ()
+            },
+            (),
+        );
+
+        html! {}
+    }
+
+    let handle = yew::Renderer::<Subscriber>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+
+    let emitted = SUBSCRIBERS.with(|m| m.borrow().iter().map(|m| m.emit(1)).collect::<Vec<_>>());
+    assert_eq!(emitted, vec![Some(())]);
+    let state = STATE.with(|m| m.borrow().upgrade()).unwrap();
+    assert_eq!(state.borrow().received, vec![1]);
+    drop(state);
+
+    handle.destroy();
+    sleep(Duration::ZERO).await;
+
+    // the state of the component is dropped, emitting to its subscription is a no-op.
+    assert!(STATE.with(|m| m.borrow().upgrade()).is_none());
+    let emitted = SUBSCRIBERS.with(|m| m.borrow().iter().map(|m| m.emit(2)).collect::<Vec<_>>());
+    assert_eq!(emitted, vec![None]);
+}
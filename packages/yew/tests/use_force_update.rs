@@ -0,0 +1,105 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::functional::UseForceUpdateHandle;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, Clone)]
+struct StoreProps {
+    // an external store, which is not a state of the component.
+    value: Rc<Cell<u32>>,
+    handle: Rc<RefCell<Option<UseForceUpdateHandle>>>,
+    renders: Rc<Cell<u32>>,
+}
+
+impl PartialEq for StoreProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+#[function_component]
+fn Store(props: &StoreProps) -> Html {
+    let trigger = use_force_update();
+    *props.handle.borrow_mut() = Some(trigger);
+    props.renders.set(props.renders.get() + 1);
+
+    html! {
+        <div id="result">{props.value.get()}</div>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_force_update_works() {
+    let props = StoreProps {
+        value: Rc::default(),
+        handle: Rc::default(),
+        renders: Rc::default(),
+    };
+
+    yew::Renderer::<Store>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        props.clone(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "0");
+    assert_eq!(props.renders.get(), 1);
+
+    // the component is re-rendered, although none of its states changed.
+    props.value.set(1);
+    props.handle.borrow().as_ref().unwrap().force_update();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "1");
+    assert_eq!(props.renders.get(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn use_force_update_is_batched() {
+    let props = StoreProps {
+        value: Rc::default(),
+        handle: Rc::default(),
+        renders: Rc::default(),
+    };
+
+    let app = yew::Renderer::<Store>::with_root_and_props(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+        props.clone(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(props.renders.get(), 1);
+
+    let handle = props.handle.borrow().clone().unwrap();
+    props.value.set(2);
+    handle.force_update();
+    handle.force_update();
+    handle.force_update();
+    sleep(Duration::ZERO).await;
+
+    // the calls are rendered once.
+    assert_eq!(obtain_result(), "2");
+    assert_eq!(props.renders.get(), 2);
+
+    app.destroy();
+    sleep(Duration::ZERO).await;
+
+    // calling it after the component is destroyed does nothing.
+    handle.force_update();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(props.renders.get(), 2);
+}
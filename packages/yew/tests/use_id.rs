@@ -0,0 +1,117 @@
+#![cfg(target_arch = "wasm32")]
+#![cfg(feature = "hydration")]
+
+use std::time::Duration;
+
+mod common;
+
+use common::obtain_result_by_id;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::{Renderer, ServerRenderer};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_id_matches_server_side_rendering() {
+    #[function_component]
+    fn Field() -> Html {
+        let label_id = use_id();
+        let description_id = use_id();
+
+        html! {
+            <span id={label_id} aria-describedby={description_id}></span>
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct GroupProps {
+        children: Children,
+    }
+
+    #[function_component]
+    fn Group(props: &GroupProps) -> Html {
+        let id = use_id();
+
+        html! {
+            <div id={id}>{props.children.clone()}</div>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div>
+                <Field />
+                <Group>
+                    <Field />
+                    <Field />
+                </Group>
+                <Field />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .hydratable(false)
+        .render()
+        .await;
+
+    assert_eq!(
+        s,
+        r#"<div><span id="yew-0-0" aria-describedby="yew-0-1"></span><div id="yew-1-0"><span id="yew-1-0-0" aria-describedby="yew-1-0-1"></span><span id="yew-1-1-0" aria-describedby="yew-1-1-1"></span></div><span id="yew-2-0" aria-describedby="yew-2-1"></span></div>"#
+    );
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result_by_id("output");
+
+    assert_eq!(result, s);
+}
+
+#[wasm_bindgen_test]
+async fn use_id_with_id_prefix() {
+    #[function_component]
+    fn App() -> Html {
+        let id = use_id();
+
+        html! {
+            <input {id} />
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .id_prefix("first")
+        .render()
+        .await;
+
+    let document = gloo::utils::document();
+    let body = document.body().unwrap();
+    let first = document.create_element("div").unwrap();
+    let second = document.create_element("div").unwrap();
+    first.set_inner_html(&s);
+    body.append_child(&first).unwrap();
+    body.append_child(&second).unwrap();
+
+    let first_app = Renderer::<App>::with_root(first.clone())
+        .id_prefix("first")
+        .hydrate();
+    let second_app = Renderer::<App>::with_root(second.clone())
+        .id_prefix("second")
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    // two roots on the same page generate distinct ids.
+    assert_eq!(first.inner_html(), r#"<input id="first-0">"#);
+    assert_eq!(second.inner_html(), r#"<input id="second-0">"#);
+
+    first_app.destroy();
+    second_app.destroy();
+    first.remove();
+    second.remove();
+}
@@ -0,0 +1,80 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{obtain_result, output_element};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement, InputEvent, InputEventInit};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn type_into(id: &str, value: &str) {
+    let element = gloo::utils::document().get_element_by_id(id).unwrap();
+    match element.dyn_ref::<HtmlTextAreaElement>() {
+        Some(m) => m.set_value(value),
+        None => element.unchecked_ref::<HtmlInputElement>().set_value(value),
+    }
+
+    let mut init = InputEventInit::new();
+    init.bubbles(true);
+    let event = InputEvent::new_with_event_init_dict("input", &init).unwrap();
+    element.dispatch_event(&event).unwrap();
+}
+
+fn input_value(id: &str) -> String {
+    gloo::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .unchecked_into::<HtmlInputElement>()
+        .value()
+}
+
+#[wasm_bindgen_test]
+async fn use_input_state_works() {
+    #[function_component]
+    fn App() -> Html {
+        let name = use_input_state(|| "World");
+        let notes = use_input_state(|| "");
+        let onclick = {
+            let name = name.clone();
+            Callback::from(move |_| name.set("Yew"))
+        };
+
+        html! {
+            <>
+                <input id="name" value={name.value()} oninput={name.oninput()} />
+                <textarea id="notes" value={notes.value()} oninput={notes.oninput()}></textarea>
+                <button id="reset" {onclick}>{"reset"}</button>
+                <div id="result">{&*name}{" "}{&*notes}</div>
+            </>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "World ");
+    assert_eq!(input_value("name"), "World");
+
+    type_into("name", "Rust");
+    type_into("notes", "typed");
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "Rust typed");
+
+    // the value of the control reflects the state after it has been set.
+    gloo::utils::document()
+        .get_element_by_id("reset")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "Yew typed");
+    assert_eq!(input_value("name"), "Yew");
+}
@@ -2,6 +2,7 @@
 #![cfg(feature = "hydration")]
 #![cfg_attr(nightly_yew, feature(async_closure))]
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 mod common;
@@ -113,3 +114,168 @@ async fn use_prepared_state_with_suspension_works() {
     // no placeholders, hydration is successful and state 12345 is preserved.
     assert_eq!(result, r#"<div><div>12345</div></div>"#);
 }
+
+#[wasm_bindgen_test]
+async fn use_prepared_state_is_not_run_during_hydration() {
+    static FETCHES: AtomicUsize = AtomicUsize::new(0);
+
+    #[function_component]
+    fn Comp() -> HtmlResult {
+        let ctr = use_prepared_state!(
+            async move |_| -> u32 {
+                FETCHES.fetch_add(1, Ordering::SeqCst);
+                12345
+            },
+            ()
+        )?
+        .unwrap_or_default();
+
+        Ok(html! {
+            <div>
+                {*ctr}
+            </div>
+        })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <Suspense fallback={Html::default()}>
+                <div>
+                    <Comp />
+                </div>
+            </Suspense>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(100)).await;
+
+    let result = obtain_result_by_id("output");
+
+    // the state is read from the server-side rendered result instead of being fetched again.
+    assert_eq!(result, r#"<div><div>12345</div></div>"#);
+    assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+}
+
+#[wasm_bindgen_test]
+async fn use_prepared_state_with_hydration_data_works() {
+    #[function_component]
+    fn Comp() -> HtmlResult {
+        let ctr = use_prepared_state!(|_| -> u32 { 12345 }, ())?.unwrap_or_default();
+
+        Ok(html! {
+            <div>
+                {*ctr}
+            </div>
+        })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <Suspense fallback={Html::default()}>
+                <div>
+                    <Comp />
+                </div>
+            </Suspense>
+        }
+    }
+
+    let (s, data) = ServerRenderer::<App>::new()
+        .render_with_hydration_data()
+        .await;
+
+    // The state is referred to by its id instead of being embedded.
+    assert!(s.contains(r#"<script type="application/x-yew-comp-state" data-id="0"></script>"#));
+    assert_eq!(data.len(), 1);
+    assert_eq!(data.get(0), Some("ATkwAAAB"));
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate_with_data(data);
+
+    sleep(Duration::from_millis(100)).await;
+
+    let result = obtain_result_by_id("output");
+
+    // no placeholders, hydration is successful and state 12345 is preserved.
+    assert_eq!(result, r#"<div><div>12345</div></div>"#);
+}
+
+#[wasm_bindgen_test]
+async fn use_prepared_state_does_not_suspend_during_hydration() {
+    static SUSPENSIONS: AtomicUsize = AtomicUsize::new(0);
+
+    #[function_component]
+    fn Comp() -> HtmlResult {
+        let ctr = use_prepared_state!(async move |_| -> u32 { 12345 }, ())
+            .map_err(|e| {
+                SUSPENSIONS.fetch_add(1, Ordering::SeqCst);
+                e
+            })?
+            .unwrap_or_default();
+
+        Ok(html! {
+            <div>
+                {*ctr}
+            </div>
+        })
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <Suspense fallback={Html::default()}>
+                <div>
+                    <Comp />
+                </div>
+            </Suspense>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+    // the suspense was resolved on the server side.
+    assert!(s.contains("<!--<?>-->"));
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    SUSPENSIONS.store(0, Ordering::SeqCst);
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result_by_id("output");
+
+    // the state is decoded before the first render, the suspense is not suspended again.
+    assert_eq!(result, r#"<div><div>12345</div></div>"#);
+    assert_eq!(SUSPENSIONS.load(Ordering::SeqCst), 0);
+}
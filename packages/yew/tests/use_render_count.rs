@@ -0,0 +1,44 @@
+#![cfg(target_arch = "wasm32")]
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::tests::use_render_count;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_render_count_counts_committed_renders() {
+    #[function_component]
+    fn Comp() -> Html {
+        let renders = use_render_count();
+        let counter = use_state(|| 0);
+
+        {
+            let counter = counter.clone();
+            use_effect(move || {
+                if *counter < 3 {
+                    counter.set(*counter + 1);
+                }
+            });
+        }
+
+        html! {
+            <div id="result">{renders}</div>
+        }
+    }
+
+    yew::Renderer::<Comp>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    sleep(Duration::ZERO).await;
+
+    // the component is rendered 4 times, 3 renders were committed before the last one.
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "3");
+}
@@ -0,0 +1,84 @@
+#![cfg(target_arch = "wasm32")]
+#![cfg(feature = "hydration")]
+
+use std::time::Duration;
+
+mod common;
+
+use common::obtain_result_by_id;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::{Renderer, ServerRenderer};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_render_mode_works() {
+    #[function_component]
+    fn Comp() -> Html {
+        let mode = use_render_mode();
+
+        // hydration expects the same content as rendered on the server side.
+        let content = match mode {
+            RenderMode::Server | RenderMode::Hydrating => "server",
+            RenderMode::Client => "client",
+        };
+
+        html! {
+            <div>{content}</div>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div id="result">
+                <Comp />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    assert_eq!(
+        s,
+        r#"<!--<[use_render_mode::use_render_mode_works::{{closure}}::App]>--><div id="result"><!--<[use_render_mode::use_render_mode_works::{{closure}}::Comp]>--><div>server</div><!--</[use_render_mode::use_render_mode_works::{{closure}}::Comp]>--></div><!--</[use_render_mode::use_render_mode_works::{{closure}}::App]>-->"#
+    );
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    let result = obtain_result_by_id("result");
+    assert_eq!(result, "<div>client</div>");
+}
+
+#[wasm_bindgen_test]
+async fn use_render_mode_client_side_rendering() {
+    #[function_component]
+    fn App() -> Html {
+        let mode = use_render_mode();
+
+        html! {
+            <div id="result">{format!("{:?}", mode)}</div>
+        }
+    }
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result_by_id("output");
+    assert_eq!(result, r#"<div id="result">Client</div>"#);
+}
@@ -0,0 +1,64 @@
+#![cfg(target_arch = "wasm32")]
+#![cfg(feature = "hydration")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+mod common;
+
+use common::obtain_result_by_id;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::{Renderer, ServerRenderer};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_server_effect_only_runs_during_ssr() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    #[function_component]
+    fn App() -> Html {
+        let counter = use_state(|| 0);
+
+        use_server_effect(|| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        {
+            let counter = counter.clone();
+            use_effect_with_deps(
+                move |_| {
+                    if *counter < 3 {
+                        counter.set(*counter + 1);
+                    }
+                    || {}
+                },
+                *counter,
+            );
+        }
+
+        html! {
+            <div id="result">{*counter}</div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    let result = obtain_result_by_id("result");
+    assert_eq!(result, "3");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
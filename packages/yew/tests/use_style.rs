@@ -0,0 +1,53 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::time::Duration;
+
+mod common;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::html::Style;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_style_adds_style_once() {
+    const CSS: &str = "& { color: rgb(255, 0, 0); }";
+
+    #[function_component]
+    fn Label() -> Html {
+        let style = use_style(CSS);
+
+        html! { <span class={style}>{"label"}</span> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! { <div id="result"><Label /><Label /></div> }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::ZERO).await;
+
+    let style = Style::new(CSS);
+    let result = obtain_result();
+    assert_eq!(
+        result,
+        format!(
+            r#"<span class="{0}">label</span><span class="{0}">label</span>"#,
+            style.class_name()
+        )
+    );
+
+    let elements = gloo::utils::document()
+        .query_selector_all(&format!(
+            r#"style[data-yew-style="{}"]"#,
+            style.class_name()
+        ))
+        .unwrap();
+    assert_eq!(elements.length(), 1);
+}
@@ -0,0 +1,80 @@
+#![cfg(target_arch = "wasm32")]
+#![cfg(feature = "hydration")]
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+mod common;
+
+use common::obtain_result_by_id;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::{Renderer, ServerRenderer};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn transferred_state_hydrates_with_server_value() {
+    static INITIAL: AtomicU32 = AtomicU32::new(0);
+
+    #[function_component]
+    fn Counter() -> Html {
+        #[transfer]
+        let counter = use_state(|| INITIAL.load(Ordering::SeqCst));
+
+        let onclick = {
+            let counter = counter.clone();
+            Callback::from(move |_| counter.set(*counter + 1))
+        };
+
+        html! {
+            <button {onclick}>{*counter}</button>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div>
+                <Counter />
+            </div>
+        }
+    }
+
+    // the value is only computed on the server side.
+    INITIAL.store(41, Ordering::SeqCst);
+    let s = ServerRenderer::<App>::new().render().await;
+    INITIAL.store(0, Ordering::SeqCst);
+
+    gloo::utils::document()
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .set_inner_html(&s);
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .hydrate();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result_by_id("output");
+    assert_eq!(result, r#"<div><button>41</button></div>"#);
+
+    gloo::utils::document()
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::ZERO).await;
+
+    let result = obtain_result_by_id("output");
+    assert_eq!(result, r#"<div><button>42</button></div>"#);
+}
@@ -1,6 +1,8 @@
+use std::alloc::{GlobalAlloc, Layout};
 use std::collections::HashMap;
 use std::fs::File;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
 use average::Variance;
@@ -13,8 +15,51 @@ use tokio::task::{spawn_local, LocalSet};
 use yew::platform::time::sleep;
 use yew::prelude::*;
 
+/// Counts the allocations and reallocations of the wrapped allocator.
+struct CountingAlloc<A> {
+    inner: A,
+    allocations: AtomicUsize,
+}
+
+impl<A> CountingAlloc<A> {
+    /// Returns the result of `f` and the number of allocations and reallocations it made.
+    ///
+    /// Allocations of other threads are counted as well.
+    fn count<R>(&self, f: impl FnOnce() -> R) -> (R, usize) {
+        let start = self.allocations.load(Ordering::SeqCst);
+        let result = f();
+        let end = self.allocations.load(Ordering::SeqCst);
+
+        (result, end - start)
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAlloc<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.inner.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.inner.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.inner.realloc(ptr, layout, new_size)
+    }
+}
+
 #[global_allocator]
-static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
+static GLOBAL: CountingAlloc<jemallocator::Jemalloc> = CountingAlloc {
+    inner: jemallocator::Jemalloc,
+    allocations: AtomicUsize::new(0),
+};
 
 #[derive(Parser)]
 struct Args {
@@ -82,6 +127,239 @@ async fn bench_router_app() -> Duration {
     start_time.elapsed()
 }
 
+async fn bench_large_list() -> Duration {
+    static TOTAL: usize = 10;
+    static ROWS: usize = 50_000;
+
+    #[function_component]
+    fn App() -> Html {
+        let mut rows = yew::virtual_dom::VList::with_capacity(ROWS);
+        rows.extend((0..ROWS).map(|i| {
+            html! {
+                <tr key={i}>
+                    <td>{i}</td>
+                    <td>{"Row"}</td>
+                </tr>
+            }
+        }));
+
+        html! {<table><tbody>{rows}</tbody></table>}
+    }
+
+    let start_time = Instant::now();
+
+    for _ in 0..TOTAL {
+        yew::LocalServerRenderer::<App>::new().render().await;
+    }
+
+    start_time.elapsed()
+}
+
+async fn bench_static_attributes() -> Duration {
+    static TOTAL: usize = 10;
+    static ROWS: usize = 10_000;
+
+    #[function_component]
+    fn App() -> Html {
+        let rows = (0..ROWS).map(|i| {
+            html! {
+                <tr key={i}>
+                    <td class="cell cell-index" align="right" data-column="index">{i}</td>
+                    <td class="cell cell-label" align="left" data-column="label">{"Row"}</td>
+                </tr>
+            }
+        });
+
+        html! {<table><tbody>{for rows}</tbody></table>}
+    }
+
+    let start_time = Instant::now();
+
+    for _ in 0..TOTAL {
+        yew::LocalServerRenderer::<App>::new().render().await;
+    }
+
+    start_time.elapsed()
+}
+
+async fn bench_numeric_text(trusted: bool) -> Duration {
+    static TOTAL: usize = 10;
+    static NODES: usize = 100_000;
+
+    #[derive(PartialEq, Properties)]
+    struct AppProps {
+        trusted: bool,
+    }
+
+    #[function_component]
+    fn App(props: &AppProps) -> Html {
+        let trusted = props.trusted;
+        let nodes = (0..NODES).map(|i| {
+            if trusted {
+                yew::virtual_dom::VText::new_trusted(i.to_string())
+            } else {
+                yew::virtual_dom::VText::new(i.to_string())
+            }
+        });
+
+        html! {<div>{for nodes}</div>}
+    }
+
+    let start_time = Instant::now();
+
+    for _ in 0..TOTAL {
+        yew::LocalServerRenderer::<App>::with_props(AppProps { trusted })
+            .render()
+            .await;
+    }
+
+    start_time.elapsed()
+}
+
+async fn bench_component_tree() -> Duration {
+    static TOTAL: usize = 10;
+    static ITEMS: usize = 10_000;
+
+    #[derive(PartialEq, Properties)]
+    struct ItemProps {
+        value: usize,
+    }
+
+    #[function_component]
+    fn Item(props: &ItemProps) -> Html {
+        html! {<li>{props.value}</li>}
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        let items = (0..ITEMS).map(|i| {
+            html! {
+                <Suspense key={i}>
+                    <Item value={i} />
+                </Suspense>
+            }
+        });
+
+        html! {<ul>{for items}</ul>}
+    }
+
+    let start_time = Instant::now();
+
+    for _ in 0..TOTAL {
+        yew::LocalServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+    }
+
+    start_time.elapsed()
+}
+
+async fn bench_cached_component(cached: bool) -> Duration {
+    static TOTAL: usize = 1_000;
+    static LINKS: usize = 1_000;
+
+    #[derive(PartialEq, Hash, Properties)]
+    struct NavProps {
+        active: usize,
+    }
+
+    #[function_component]
+    fn Nav(props: &NavProps) -> Html {
+        let links = (0..LINKS).map(|i| {
+            html! {
+                <li class={classes!("nav-item", (i == props.active).then(|| "active"))}>
+                    <a href={format!("/page/{}", i)}>{"Page "}{i}</a>
+                </li>
+            }
+        });
+
+        html! {<nav><ul>{for links}</ul></nav>}
+    }
+
+    #[derive(PartialEq, Properties)]
+    struct AppProps {
+        cached: bool,
+    }
+
+    #[function_component]
+    fn App(props: &AppProps) -> Html {
+        let nav = yew::virtual_dom::VChild::<Nav>::new(NavProps { active: 0 }, None);
+        let nav = if props.cached {
+            nav.ssr_cached()
+        } else {
+            nav.into()
+        };
+
+        html! {<>{nav}<main>{"Hello, World!"}</main></>}
+    }
+
+    let cache = yew::SsrCache::new(1);
+    let start_time = Instant::now();
+
+    for _ in 0..TOTAL {
+        yew::LocalServerRenderer::<App>::with_props(AppProps { cached })
+            .hydratable(false)
+            .cache(cache.clone())
+            .render()
+            .await;
+    }
+
+    start_time.elapsed()
+}
+
+#[derive(Debug, Tabled, Serialize, Deserialize)]
+struct Allocations {
+    #[tabled(rename = "Benchmark")]
+    name: String,
+    #[tabled(rename = "Allocations")]
+    allocations: usize,
+}
+
+/// Counts the allocations of building a list of 50k rows, with and without reserving the capacity
+/// of the list upfront.
+///
+/// The rows are created before they are counted, so only the allocations of the list itself are
+/// compared.
+fn count_large_list_allocations() -> [Allocations; 2] {
+    static ROWS: usize = 50_000;
+
+    let rows = || -> Vec<Html> { (0..ROWS).map(|i| html! { <td key={i}>{i}</td> }).collect() };
+
+    let children = rows();
+    let (list, pushed) = GLOBAL.count(|| {
+        let mut list = yew::virtual_dom::VList::new();
+        for child in children {
+            list.add_child(child);
+        }
+        list
+    });
+    drop(list);
+
+    let children = rows();
+    let (list, presized) = GLOBAL.count(|| {
+        let mut list = yew::virtual_dom::VList::with_capacity(ROWS);
+        list.extend(children);
+        list
+    });
+    drop(list);
+
+    // A presized list is allocated once, a list that grows is reallocated as it grows.
+    assert_eq!(presized, 1);
+    assert!(presized < pushed);
+
+    [
+        Allocations {
+            name: "Large List (add_child)".to_owned(),
+            allocations: pushed,
+        },
+        Allocations {
+            name: "Large List (with_capacity + extend)".to_owned(),
+            allocations: presized,
+        },
+    ]
+}
+
 async fn bench_concurrent_task() -> Duration {
     static TOTAL: usize = 100;
 
@@ -215,12 +493,19 @@ async fn main() {
     let args = Args::parse();
 
     // Tests in each round.
-    static TESTS: usize = 4;
+    static TESTS: usize = 11;
 
     let mut baseline_results = Vec::with_capacity(args.rounds);
     let mut hello_world_results = Vec::with_capacity(args.rounds);
     let mut function_router_results = Vec::with_capacity(args.rounds);
     let mut concurrent_tasks_results = Vec::with_capacity(args.rounds);
+    let mut large_list_results = Vec::with_capacity(args.rounds);
+    let mut static_attributes_results = Vec::with_capacity(args.rounds);
+    let mut numeric_text_results = Vec::with_capacity(args.rounds);
+    let mut trusted_numeric_text_results = Vec::with_capacity(args.rounds);
+    let mut component_tree_results = Vec::with_capacity(args.rounds);
+    let mut uncached_component_results = Vec::with_capacity(args.rounds);
+    let mut cached_component_results = Vec::with_capacity(args.rounds);
 
     let bar = (!args.no_term).then(|| create_progress(TESTS, args.rounds));
 
@@ -267,6 +552,62 @@ async fn main() {
                         bar.inc(1);
                     }
                 }
+
+                let dur = bench_large_list().await;
+                if i > 0 {
+                    large_list_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
+
+                let dur = bench_static_attributes().await;
+                if i > 0 {
+                    static_attributes_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
+
+                let dur = bench_numeric_text(false).await;
+                if i > 0 {
+                    numeric_text_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
+
+                let dur = bench_numeric_text(true).await;
+                if i > 0 {
+                    trusted_numeric_text_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
+
+                let dur = bench_component_tree().await;
+                if i > 0 {
+                    component_tree_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
+
+                let dur = bench_cached_component(false).await;
+                if i > 0 {
+                    uncached_component_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
+
+                let dur = bench_cached_component(true).await;
+                if i > 0 {
+                    cached_component_results.push(dur);
+                    if let Some(ref bar) = bar {
+                        bar.inc(1);
+                    }
+                }
             }
         })
         .await;
@@ -281,10 +622,28 @@ async fn main() {
         Statistics::from_results("Hello World", args.rounds, hello_world_results),
         Statistics::from_results("Function Router", args.rounds, function_router_results),
         Statistics::from_results("Concurrent Task", args.rounds, concurrent_tasks_results),
+        Statistics::from_results("Large List", args.rounds, large_list_results),
+        Statistics::from_results("Static Attributes", args.rounds, static_attributes_results),
+        Statistics::from_results("Numeric Text", args.rounds, numeric_text_results),
+        Statistics::from_results(
+            "Trusted Numeric Text",
+            args.rounds,
+            trusted_numeric_text_results,
+        ),
+        Statistics::from_results("Component Tree", args.rounds, component_tree_results),
+        Statistics::from_results(
+            "Uncached Component",
+            args.rounds,
+            uncached_component_results,
+        ),
+        Statistics::from_results("Cached Component", args.rounds, cached_component_results),
     ];
 
     println!("{}", output.as_ref().table().with(Style::rounded()));
 
+    let allocations = count_large_list_allocations();
+    println!("{}", allocations.as_ref().table().with(Style::rounded()));
+
     if let Some(ref p) = args.output_path {
         let mut f = File::create(p).expect("failed to write output.");
         serde_json::to_writer_pretty(&mut f, &output).expect("failed to write output.");